@@ -23,6 +23,8 @@ pub mod results {
     pub use crate::api::outputs::SatisfactionResultUnderAssumptions;
     pub use crate::api::outputs::SolutionReference;
     pub use crate::basic_types::Solution;
+    pub use crate::engine::conflict_analysis::DerivationGraph;
+    pub use crate::engine::conflict_analysis::ResolutionStep;
     #[cfg(doc)]
     use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
     #[cfg(doc)]
@@ -71,6 +73,7 @@ pub mod options {
     //! - The learned clause database management approach
     //! - The proof logging
     pub use crate::basic_types::sequence_generators::SequenceGeneratorType;
+    pub use crate::engine::conflict_analysis::ResolutionMode;
     pub use crate::engine::LearnedClauseSortingStrategy;
     pub use crate::engine::LearningOptions;
     pub use crate::engine::RestartOptions;
@@ -139,10 +142,44 @@ pub mod predicates {
     use crate::variables::Literal;
 }
 
+pub mod propagation {
+    //! Contains the building blocks for implementing custom [`Propagator`]s, which can be added to
+    //! the [`Solver`] using [`Solver::add_propagator`] (or as a [`Constraint`] using
+    //! [`Solver::add_constraint`]).
+    //!
+    //! A [`Propagator`] registers the variables it is interested in with the
+    //! [`PropagatorInitialisationContext`], and reads and updates the domains of those variables
+    //! through the [`PropagationContextMut`] (see [`ReadDomains`]). Every update requires a reason,
+    //! which is a [`PropositionalConjunction`](crate::predicates::PropositionalConjunction) of
+    //! predicates which imply the update.
+    //!
+    //! Note that this API is not yet stable, and it is therefore likely to change in future
+    //! versions.
+    pub use crate::basic_types::Inconsistency;
+    pub use crate::basic_types::PropagationStatusCP;
+    #[cfg(doc)]
+    use crate::constraints::Constraint;
+    pub use crate::engine::cp::domain_events::DomainEvents;
+    pub use crate::engine::cp::opaque_domain_event::OpaqueDomainEvent;
+    pub use crate::engine::cp::propagation::EnqueueDecision;
+    pub use crate::engine::cp::propagation::LocalId;
+    pub use crate::engine::cp::propagation::PropagationContext;
+    pub use crate::engine::cp::propagation::PropagationContextMut;
+    pub use crate::engine::cp::propagation::Propagator;
+    pub use crate::engine::cp::propagation::PropagatorInitialisationContext;
+    pub use crate::engine::cp::propagation::ReadDomains;
+    pub use crate::engine::cp::BooleanDomainEvent;
+    pub use crate::engine::cp::EmptyDomain;
+    pub use crate::engine::cp::IntDomainEvent;
+    #[cfg(doc)]
+    use crate::Solver;
+}
+
 pub mod encodings {
     //! Contains structures which encode pseudo-boolean constraints via the
     //! [`PseudoBooleanConstraintEncoder`].
     pub use crate::basic_types::Function;
+    pub use crate::basic_types::NegativeTermError;
     pub use crate::encoders::PseudoBooleanConstraintEncoder;
     pub use crate::encoders::PseudoBooleanEncoding;
 }
@@ -56,3 +56,16 @@ pub enum OptimisationResult {
     /// [`TerminationCondition`] triggering.
     Unknown,
 }
+
+impl OptimisationResult {
+    /// Returns the objective value of the (best) solution which was found, or [`None`] if no
+    /// solution was found.
+    pub fn objective_value(&self) -> Option<i64> {
+        match self {
+            OptimisationResult::Optimal(solution) | OptimisationResult::Satisfiable(solution) => {
+                solution.objective_value()
+            }
+            OptimisationResult::Unsatisfiable | OptimisationResult::Unknown => None,
+        }
+    }
+}
@@ -1,10 +1,16 @@
+use std::io::Write;
 use std::num::NonZero;
 
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
 use super::results::OptimisationResult;
 use super::results::SatisfactionResult;
 use super::results::SatisfactionResultUnderAssumptions;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
+use crate::basic_types::Function;
+use crate::basic_types::HashMap;
 use crate::basic_types::HashSet;
 use crate::basic_types::Solution;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
@@ -16,7 +22,10 @@ use crate::branching::Brancher;
 use crate::branching::PhaseSaving;
 use crate::branching::SolutionGuidedValueSelector;
 use crate::branching::Vsids;
+use crate::constraints;
 use crate::constraints::ConstraintPoster;
+use crate::constraints::NegatableConstraint;
+use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::propagation::Propagator;
 use crate::engine::termination::TerminationCondition;
@@ -27,13 +36,18 @@ use crate::engine::ConstraintSatisfactionSolver;
 use crate::options::LearningOptions;
 use crate::options::SolverOptions;
 use crate::predicate;
+use crate::propagators::objective_bound::ObjectiveBoundPropagator;
 use crate::pumpkin_assert_simple;
+use crate::results::solution_iterator::IteratedSolution;
 use crate::results::solution_iterator::SolutionIterator;
 use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
+use crate::results::DerivationGraph;
 use crate::results::SolutionCallbackArguments;
 use crate::statistics::statistic_logging::log_statistic;
 use crate::statistics::statistic_logging::log_statistic_postfix;
+use crate::statistics::PropagatorStatistics;
 use crate::variables::PropositionalVariable;
+use crate::variables::TransformableVariable;
 
 /// The main interaction point which allows the creation of variables, the addition of constraints,
 /// and solving problems.
@@ -90,6 +104,9 @@ pub struct Solver {
     /// The function is called whenever an optimisation function finds a solution; see
     /// [`Solver::with_solution_callback`].
     solution_callback: Box<dyn Fn(SolutionCallbackArguments)>,
+    /// The penalties of the soft constraints which have been added to the solver; see
+    /// [`Solver::add_soft_constraint`].
+    soft_constraint_penalties: Function,
 }
 
 impl Default for Solver {
@@ -97,6 +114,7 @@ impl Default for Solver {
         Self {
             satisfaction_solver: Default::default(),
             solution_callback: create_empty_function(),
+            soft_constraint_penalties: Function::default(),
         }
     }
 }
@@ -123,9 +141,89 @@ impl Solver {
                 solver_options,
             ),
             solution_callback: create_empty_function(),
+            soft_constraint_penalties: Function::default(),
         }
     }
 
+    /// Creates a solver with default options whose random number generator is seeded with the
+    /// provided `seed`.
+    ///
+    /// All randomised components which use the random number generator of the solver (e.g.
+    /// [`InDomainRandom`](crate::branching::InDomainRandom)) make the same choices
+    /// across runs which use the same seed; it is equivalent to providing
+    /// [`SolverOptions::random_generator`] to [`Solver::with_options`].
+    pub fn with_seed(seed: u64) -> Self {
+        Solver::with_options(
+            LearningOptions::default(),
+            SolverOptions {
+                random_generator: SmallRng::seed_from_u64(seed),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Creates an independent copy of the [`Solver`] containing the variables and constraints
+    /// which have been added to it. The copy does not share any mutable state with this
+    /// [`Solver`], which is left unchanged, e.g. to run a portfolio of differently seeded solvers
+    /// with different branchers on the same model.
+    ///
+    /// Note that the copy is not [`Send`], i.e. it is solved on the thread on which it is created.
+    ///
+    /// Returns `None` if one of the propagators of the [`Solver`] does not support being copied.
+    /// A propagator opts in to being copied by implementing [`Propagator::clone_boxed`]; since the
+    /// [`Solver`] cannot create a copy of a propagator whose type it does not know, any
+    /// propagator which does not implement it (e.g. a propagator added using
+    /// [`Solver::add_propagator`] which relies on the default implementation) results in `None`.
+    /// The propagators of this crate support being copied, with the exception of the time-table
+    /// propagators for the cumulative constraint which share their tasks between their internal
+    /// structures. Similarly, `None` is returned if the reason for one of the root-level
+    /// propagations does not support being copied.
+    ///
+    /// The random number generator of the copy is seeded with the provided `seed`. Note that the
+    /// copy does not log a proof and does not have a solution call-back (see
+    /// [`Solver::with_solution_callback`]); the learned clauses and the statistics of this
+    /// [`Solver`] are copied.
+    ///
+    /// The [`Solver`] should be at the root level, which is the case outside of the solving
+    /// methods (e.g. [`Solver::satisfy`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// solver
+    ///     .add_constraint(constraints::all_different([x, y]))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    ///
+    /// let mut clone = solver
+    ///     .clone_with_state(42)
+    ///     .expect("all propagators can be copied");
+    ///
+    /// let mut brancher = clone.default_brancher_over_all_propositional_variables();
+    /// match clone.satisfy(&mut brancher, &mut Indefinite) {
+    ///     SatisfactionResult::Satisfiable(solution) => {
+    ///         assert_ne!(solution.get_integer_value(x), solution.get_integer_value(y));
+    ///     }
+    ///     _ => panic!("the copy should find a solution"),
+    /// }
+    /// ```
+    pub fn clone_with_state(&self, seed: u64) -> Option<Solver> {
+        Some(Solver {
+            satisfaction_solver: self
+                .satisfaction_solver
+                .clone_with_state(SmallRng::seed_from_u64(seed))?,
+            solution_callback: create_empty_function(),
+            soft_constraint_penalties: self.soft_constraint_penalties.clone(),
+        })
+    }
+
     /// Adds a call-back to the [`Solver`] which is called every time that a solution is found when
     /// optimising using [`Solver::maximise`] or [`Solver::minimise`].
     ///
@@ -205,6 +303,163 @@ impl Solver {
     pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
         self.satisfaction_solver.get_upper_bound(variable)
     }
+
+    /// Sets the value which should preferably be assigned to `variable` during search; this
+    /// replaces any previous preference for `variable`.
+    ///
+    /// The preferences are available to the [`Brancher`] through
+    /// [`SelectionContext::preferred_value`](crate::branching::SelectionContext::preferred_value)
+    /// and are used by the
+    /// [`PreferredValueSelector`](crate::branching::PreferredValueSelector), which assigns the
+    /// preferred value if it is still in the domain of the variable.
+    pub fn set_preferred_value(&mut self, variable: DomainId, value: i32) {
+        self.satisfaction_solver
+            .set_preferred_value(variable, value)
+    }
+
+    /// Provides a (partial) solution which the search should try first, e.g. a solution found by
+    /// a heuristic; every `(variable, value)` pair is set as the preferred value of `variable`
+    /// (see [`Solver::set_preferred_value`]).
+    ///
+    /// The hint does not constrain the search. It is taken into account by the brancher created by
+    /// [`Solver::default_brancher_over_all_propositional_variables`] afterwards, which initially
+    /// assigns the literals of the hinted variables in accordance with the hint.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 6))
+    ///     .post();
+    ///
+    /// solver.set_solution_hint(&[(x, 4), (y, 2)]);
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// if let SatisfactionResult::Satisfiable(solution) = solver.satisfy(&mut brancher, &mut Indefinite) {
+    ///     assert_eq!(4, solution.get_integer_value(x));
+    ///     assert_eq!(2, solution.get_integer_value(y));
+    /// } else {
+    ///     panic!("the hint is a solution");
+    /// }
+    /// ```
+    pub fn set_solution_hint(&mut self, assignments: &[(DomainId, i32)]) {
+        for &(variable, value) in assignments {
+            self.set_preferred_value(variable, value);
+        }
+    }
+
+    /// Returns the value which should preferably be assigned to `variable` during search (see
+    /// [`Solver::set_preferred_value`]), if any.
+    pub fn preferred_value(&self, variable: DomainId) -> Option<i32> {
+        self.satisfaction_solver.get_preferred_value(variable)
+    }
+
+    /// Returns pairs of integer variables which are proven to be equal by propagation at the root
+    /// level.
+    ///
+    /// Two variables are proven to be equal if both are fixed to the same value, or if one of them
+    /// is unfixed and propagating any value in its domain fixes the other variable to that same
+    /// value. The latter is detected by probing the values of the unfixed variables; the probes of
+    /// a variable stop as soon as no other variable is fixed along with it, and nothing is learned
+    /// from them.
+    ///
+    /// Equality is transitive, so rather than reporting every pair of variables in a group of
+    /// equal variables, every variable in a group is paired with the first created variable of
+    /// that group. The pairs are ordered by the order in which the variables were created.
+    ///
+    /// If the [`Solver`] is in an infeasible state, then no equalities are reported.
+    pub fn implied_equalities(&mut self) -> Vec<(DomainId, DomainId)> {
+        if self.satisfaction_solver.get_state().is_infeasible() {
+            return vec![];
+        }
+
+        let domains = self
+            .satisfaction_solver
+            .assignments_integer
+            .get_domains()
+            .collect::<Vec<_>>();
+        let mut representatives: HashMap<DomainId, DomainId> = HashMap::default();
+
+        let mut fixed_representatives: HashMap<i32, DomainId> = HashMap::default();
+        let mut unfixed_domains = Vec::new();
+        for &domain_id in domains.iter() {
+            let assignments = &self.satisfaction_solver.assignments_integer;
+            if assignments.is_domain_assigned(domain_id) {
+                let value = assignments.get_assigned_value(domain_id);
+                let representative = *fixed_representatives.entry(value).or_insert(domain_id);
+                if representative != domain_id {
+                    let _ = representatives.insert(domain_id, representative);
+                }
+            } else {
+                unfixed_domains.push(domain_id);
+            }
+        }
+
+        for (index, &domain_id) in unfixed_domains.iter().enumerate() {
+            if representatives.contains_key(&domain_id) {
+                continue;
+            }
+
+            let assignments = &self.satisfaction_solver.assignments_integer;
+            let values = (assignments.get_lower_bound(domain_id)
+                ..=assignments.get_upper_bound(domain_id))
+                .filter(|&value| assignments.is_value_in_domain(domain_id, value))
+                .collect::<Vec<_>>();
+
+            // The variables which are fixed to every probed value so far
+            let mut candidates = unfixed_domains[index + 1..]
+                .iter()
+                .copied()
+                .filter(|other| !representatives.contains_key(other))
+                .collect::<Vec<_>>();
+            // A value which leads to a conflict cannot be taken in any solution, so it does not
+            // rule out any candidate; however, at least one value should be consistent
+            let mut is_consistent = false;
+            for value in values {
+                if candidates.is_empty() {
+                    break;
+                }
+
+                let fixed_candidates =
+                    self.satisfaction_solver
+                        .probe(predicate![domain_id == value], |assignments| {
+                            candidates
+                                .iter()
+                                .copied()
+                                .filter(|&other| {
+                                    assignments.is_domain_assigned_to_value(other, value)
+                                })
+                                .collect::<Vec<_>>()
+                        });
+                if let Some(fixed_candidates) = fixed_candidates {
+                    candidates = fixed_candidates;
+                    is_consistent = true;
+                }
+            }
+
+            if is_consistent {
+                for other in candidates {
+                    let _ = representatives.insert(other, domain_id);
+                }
+            }
+        }
+
+        domains
+            .into_iter()
+            .filter_map(|domain_id| {
+                representatives
+                    .get(&domain_id)
+                    .map(|&representative| (representative, domain_id))
+            })
+            .collect()
+    }
 }
 
 /// Functions to create and retrieve integer and propositional variables.
@@ -267,6 +522,11 @@ impl Solver {
 
     /// Create a new integer variable with the given bounds.
     ///
+    /// Variables can also be created in between calls to solve (e.g. [`Solver::satisfy`]), after
+    /// which constraints over the new variable can be added and the enlarged model can be solved
+    /// again; the nogoods which were learned so far remain valid and are kept. Note that a
+    /// [`Brancher`] created before the variable does not know about it.
+    ///
     /// # Example
     /// ```rust
     /// # use pumpkin_solver::Solver;
@@ -373,6 +633,89 @@ impl Solver {
         }
     }
 
+    /// Returns the decisions (as opposed to propagations) which were on the trail when the last
+    /// solution was found, in the order in which they were made. Assumptions are not included.
+    ///
+    /// If no solution has been found yet, the returned path is empty.
+    pub fn last_solution_decision_path(&self) -> Vec<Predicate> {
+        self.satisfaction_solver
+            .get_last_solution_decision_path()
+            .to_vec()
+    }
+
+    /// Returns the derivation of the clause which was learned during the last conflict analysis,
+    /// which describes the resolution steps which were applied to the conflict (see
+    /// [`DerivationGraph`]).
+    ///
+    /// The derivations are only recorded if [`SolverOptions::record_nogood_derivations`] is
+    /// enabled; otherwise, or if no conflict has been analysed yet, [`None`] is returned.
+    pub fn last_learned_nogood_derivation(&self) -> Option<DerivationGraph> {
+        self.satisfaction_solver
+            .get_last_learned_nogood_derivation()
+            .cloned()
+    }
+
+    /// Writes the nogoods which have been learned by the solver (and which have not been removed
+    /// from the learned clause database) to `writer` as clauses in the DIMACS format.
+    ///
+    /// The learned nogoods are stored as clauses over [`Literal`]s, where the literals for
+    /// predicates over integer variables can be retrieved using [`Solver::get_literal`]; the
+    /// DIMACS variable `i` corresponds to the propositional variable with index `i`. This can be
+    /// used to analyse the learned nogoods with external SAT tools, or to compare the learning
+    /// behaviour of different runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::Solver;
+    /// let solver = Solver::default();
+    ///
+    /// let mut dimacs = Vec::new();
+    /// solver
+    ///     .dump_learned_nogoods_dimacs(&mut dimacs)
+    ///     .expect("writing to a vector does not fail");
+    ///
+    /// // Nothing has been learned yet
+    /// assert_eq!(String::from_utf8(dimacs).unwrap(), "p cnf 0 0\n");
+    /// ```
+    pub fn dump_learned_nogoods_dimacs(&self, writer: impl Write) -> std::io::Result<()> {
+        self.satisfaction_solver
+            .write_learned_clauses_dimacs(writer)
+    }
+
+    /// Returns the name of every propagator in the solver together with its
+    /// [`PropagatorStatistics`] (e.g. the number of times it was called to propagate), in the order
+    /// in which the propagators were added.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 3))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    ///
+    /// let statistics = solver.propagator_statistics();
+    /// assert_eq!(1, statistics.len());
+    /// assert!(statistics[0].1.num_propagations > 0);
+    /// ```
+    pub fn propagator_statistics(&self) -> Vec<(String, PropagatorStatistics)> {
+        self.satisfaction_solver.get_propagator_statistics()
+    }
+
+    /// Returns the name of every propagator in the solver together with the total time (in
+    /// milliseconds) spent in its propagation and notification methods, in the order in which the
+    /// propagators were added.
+    ///
+    /// Only available when the `profiling` feature is enabled; otherwise, no timing is done.
+    #[cfg(feature = "profiling")]
+    pub fn propagator_time_ms(&self) -> Vec<(String, u128)> {
+        self.satisfaction_solver.get_propagator_time_ms()
+    }
+
     pub fn get_solution_iterator<
         'this,
         'brancher,
@@ -387,6 +730,49 @@ impl Solver {
         SolutionIterator::new(self, brancher, termination)
     }
 
+    /// Enumerates the solutions of the model one at a time, calling `on_solution` for every
+    /// solution which is found; between solutions, a nogood is added which blocks the previous
+    /// solution from being found again (see [`Solver::get_solution_iterator`]).
+    ///
+    /// The enumeration stops when `on_solution` returns false, when there are no more solutions,
+    /// or when the [`TerminationCondition`] is triggered. Returns the number of solutions which
+    /// were passed to `on_solution`.
+    ///
+    /// Note that the blocking nogoods remain part of the model after this method returns.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// solver.add_constraint(constraints::binary_not_equals(x, y)).post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |_| true);
+    /// assert_eq!(6, num_solutions);
+    /// ```
+    pub fn enumerate<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+        mut on_solution: impl FnMut(&Solution) -> bool,
+    ) -> usize {
+        let mut solution_iterator = self.get_solution_iterator(brancher, termination);
+        let mut num_solutions = 0;
+
+        while let IteratedSolution::Solution(solution) = solution_iterator.next_solution() {
+            num_solutions += 1;
+            if !on_solution(&solution) {
+                break;
+            }
+        }
+
+        num_solutions
+    }
+
     /// Solves the current model in the [`Solver`] until it finds a solution (or is indicated to
     /// terminate by the provided [`TerminationCondition`]) and returns a [`SatisfactionResult`]
     /// which can be used to obtain the found solution or find other solutions.
@@ -422,6 +808,10 @@ impl Solver {
                     .state
                     .is_infeasible_under_assumptions()
                 {
+                    // The core is extracted eagerly such that it can be retrieved using
+                    // `Solver::get_core` after the result has been dropped
+                    let _ = self.satisfaction_solver.extract_clausal_core(brancher);
+
                     // The state is automatically reset when we return this result
                     SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
                         UnsatisfiableUnderAssumptions::new(&mut self.satisfaction_solver, brancher),
@@ -440,6 +830,150 @@ impl Solver {
         }
     }
 
+    /// Returns the unsatisfiable core of the last call to [`Solver::satisfy_under_assumptions`],
+    /// i.e. the subset of the assumptions which is present in the final conflict; this is the set
+    /// of assumptions in the nogood which is derived from the conflict using the all-decision
+    /// learning scheme.
+    ///
+    /// If the provided assumptions contained both a literal and its negation, then the core
+    /// consists of these two literals. Returns [`None`] if the last call did not conclude that
+    /// the problem is unsatisfiable under the assumptions.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// solver.add_constraint(constraints::not_equals(vec![x, y], 0)).post();
+    ///
+    /// let assumptions = [
+    ///     solver.get_literal(predicate![x <= 0]),
+    ///     solver.get_literal(predicate![y <= 0]),
+    /// ];
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let _ = solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions);
+    ///
+    /// let core = solver.get_core().expect("the assumptions are conflicting");
+    /// assert!(assumptions.iter().all(|literal| core.contains(literal)));
+    /// ```
+    pub fn get_core(&self) -> Option<Vec<Literal>> {
+        self.satisfaction_solver
+            .get_last_core()
+            .map(|core| match core {
+                CoreExtractionResult::ConflictingAssumption(literal) => vec![*literal, !*literal],
+                CoreExtractionResult::Core(core) => core.clone(),
+            })
+    }
+
+    /// Greedily extends the partial assignment given by the provided `assumptions` to a solution,
+    /// without performing any search. After propagating the assumptions, the remaining variables
+    /// are assigned one by one to their lower bound (and literals to false), propagating after
+    /// every assignment.
+    ///
+    /// Returns the solution if no conflict arises, and [`None`] if an assumption is violated or a
+    /// conflict is encountered; note that this does not imply that the partial assignment cannot
+    /// be extended to a solution. This is a cheap feasibility heuristic, e.g. to generate an
+    /// initial solution to improve upon.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 3);
+    /// let y = solver.new_bounded_integer(0, 3);
+    /// solver
+    ///     .add_constraint(constraints::binary_not_equals(x, y))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    ///
+    /// let assumptions = [solver.get_literal(predicate![x == 2])];
+    /// let solution = solver.greedy_complete(&assumptions).expect("y can be completed");
+    /// assert_eq!(2, solution.get_integer_value(x));
+    /// assert_eq!(0, solution.get_integer_value(y));
+    /// ```
+    pub fn greedy_complete(&mut self, assumptions: &[Literal]) -> Option<Solution> {
+        self.satisfaction_solver.greedy_complete(assumptions)
+    }
+
+    /// Extracts a minimal unsatisfiable subset (MUS) of the provided tagged constraints; i.e. a
+    /// subset of the constraints which together (with the constraints which are not tagged) are
+    /// unsatisfiable but for which removing any single constraint makes them satisfiable.
+    ///
+    /// Every constraint is identified by its tag and the [`Literal`] which activates it; the
+    /// constraints are expected to have been posted as `literal -> constraint` (e.g. using
+    /// [`ConstraintPoster::implied_by`]). The MUS is computed using the deletion-based algorithm;
+    /// every constraint is tentatively dropped and the remaining constraints are solved under
+    /// assumptions, if they are still unsatisfiable then the constraint is removed permanently
+    /// (together with all constraints which are not part of the extracted core).
+    ///
+    /// Returns [`None`] if the provided constraints are not unsatisfiable together or if this
+    /// could not be established before the [`TerminationCondition`] triggered. Note that if the
+    /// [`TerminationCondition`] triggers during the deletion phase, then the returned subset is
+    /// unsatisfiable but not necessarily minimal.
+    pub fn extract_mus<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+        tagged_constraints: &[(NonZero<u32>, Literal)],
+    ) -> Option<Vec<NonZero<u32>>> {
+        let mut candidates = tagged_constraints.to_vec();
+
+        let assumptions = candidates
+            .iter()
+            .map(|&(_, literal)| literal)
+            .collect::<Vec<_>>();
+        match self.satisfy_under_assumptions(brancher, termination, &assumptions) {
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut result) => {
+                let core = result.extract_core();
+                candidates.retain(|(_, literal)| core.contains(literal));
+            }
+            SatisfactionResultUnderAssumptions::Unsatisfiable => return Some(vec![]),
+            SatisfactionResultUnderAssumptions::Satisfiable(_)
+            | SatisfactionResultUnderAssumptions::Unknown => return None,
+        }
+
+        let mut index = 0;
+        while index < candidates.len() {
+            let assumptions = candidates
+                .iter()
+                .enumerate()
+                .filter(|&(other_index, _)| other_index != index)
+                .map(|(_, &(_, literal))| literal)
+                .collect::<Vec<_>>();
+
+            match self.satisfy_under_assumptions(brancher, termination, &assumptions) {
+                SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut result) => {
+                    // The constraint at `index` is not necessary; the constraints before it are
+                    // necessary and are thus part of every core of the remaining constraints.
+                    let core = result.extract_core();
+                    candidates = candidates
+                        .into_iter()
+                        .enumerate()
+                        .filter(|&(other_index, (_, literal))| {
+                            other_index != index && core.contains(&literal)
+                        })
+                        .map(|(_, candidate)| candidate)
+                        .collect();
+                }
+                SatisfactionResultUnderAssumptions::Unsatisfiable => return Some(vec![]),
+                SatisfactionResultUnderAssumptions::Satisfiable(_)
+                | SatisfactionResultUnderAssumptions::Unknown => {
+                    // Either the constraint is necessary, or we do not know whether it is
+                    index += 1;
+                }
+            }
+        }
+
+        Some(candidates.into_iter().map(|(tag, _)| tag).collect())
+    }
+
     /// Solves the model currently in the [`Solver`] to optimality where the provided
     /// `objective_variable` is minimised (or is indicated to terminate by the provided
     /// [`TerminationCondition`]).
@@ -456,112 +990,479 @@ impl Solver {
     }
 
     /// Solves the model currently in the [`Solver`] to optimality where the provided
-    /// `objective_variable` is maximised (or is indicated to terminate by the provided
-    /// [`TerminationCondition`]).
+    /// `objective_variable` is minimised, given that the optimal objective value is known to lie
+    /// in `[lower_bound, upper_bound]` (e.g. from a relaxation and a heuristic solution).
+    ///
+    /// The search only considers solutions with an objective value of at most `upper_bound`, and
+    /// it stops as soon as a solution with an objective value of at most `lower_bound` is found,
+    /// without searching for better solutions. The bounds only apply to this call; they are not
+    /// added to the [`Solver`] as constraints.
+    ///
+    /// Reaching `lower_bound` only results in an [`OptimisationResult::Optimal`] if the bound is
+    /// implied by the model (i.e. the objective variable cannot take a smaller value at the root);
+    /// otherwise, the solution is reported as [`OptimisationResult::Satisfiable`] since better
+    /// solutions may exist.
     ///
     /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
-    /// it exists.
-    pub fn maximise(
+    /// it exists; if there is no solution within the bounds then
+    /// [`OptimisationResult::Unsatisfiable`] is returned.
+    ///
+    /// Note that proof logging is not supported: since the bounds are imposed as assumptions, no
+    /// conclusion is logged, i.e. the proof (if one is logged) is incomplete.
+    pub fn minimise_with_bounds<Var: IntegerVariable>(
         &mut self,
         brancher: &mut impl Brancher,
         termination: &mut impl TerminationCondition,
-        objective_variable: impl IntegerVariable,
+        objective_variable: Var,
+        lower_bound: i32,
+        upper_bound: i32,
     ) -> OptimisationResult {
-        self.minimise_internal(brancher, termination, objective_variable.scaled(-1), true)
+        self.minimise_with_bounds_internal(
+            brancher,
+            termination,
+            objective_variable,
+            lower_bound,
+            upper_bound,
+            false,
+            &[],
+        )
     }
 
-    /// The internal method which optimizes the objective function, this function takes an extra
-    /// argument (`is_maximising`) as compared to [`Solver::maximise`] and [`Solver::minimise`]
-    /// which determines whether the logged objective value should be scaled by `-1` or not.
-    ///
-    /// This is necessary due to the fact that [`Solver::maximise`] simply calls minimise with
-    /// the objective variable scaled with `-1` which would lead to incorrect statistic if not
-    /// scaled back.
-    fn minimise_internal(
+    /// The internal method of [`Solver::minimise_with_bounds`], this function takes two extra
+    /// arguments: `is_maximising` determines whether the reported objective value should be scaled
+    /// by `-1` (see [`Solver::minimise_internal`]), and `assumptions` are imposed alongside the
+    /// upper bound during every search.
+    #[allow(clippy::too_many_arguments)]
+    fn minimise_with_bounds_internal<Var: IntegerVariable>(
         &mut self,
         brancher: &mut impl Brancher,
         termination: &mut impl TerminationCondition,
-        objective_variable: impl IntegerVariable,
+        objective_variable: Var,
+        lower_bound: i32,
+        mut upper_bound: i32,
         is_maximising: bool,
+        assumptions: &[Literal],
     ) -> OptimisationResult {
-        // If we are maximising then when we simply scale the variable by -1, however, this will
-        // lead to the printed objective value in the statistics to be multiplied by -1; this
-        // objective_multiplier ensures that the objective is correctly logged.
-        let objective_multiplier = if is_maximising { -1 } else { 1 };
+        let objective_multiplier: i64 = if is_maximising { -1 } else { 1 };
 
-        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
-        match initial_solve {
-            CSPSolverExecutionFlag::Feasible => {}
-            CSPSolverExecutionFlag::Infeasible => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self.satisfaction_solver.conclude_proof_unsat();
-                return OptimisationResult::Unsatisfiable;
-            }
-            CSPSolverExecutionFlag::Timeout => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                return OptimisationResult::Unknown;
-            }
+        if self.satisfaction_solver.get_state().is_infeasible() {
+            return OptimisationResult::Unsatisfiable;
         }
-        let mut best_objective_value = Default::default();
-        let mut best_solution = Solution::default();
 
-        self.update_best_solution_and_process(
-            objective_multiplier,
-            &objective_variable,
-            &mut best_objective_value,
-            &mut best_solution,
-            brancher,
-        );
+        // Any solution has an objective value of at least the lower bound at the root, in which
+        // case the provided lower bound is valid
+        let is_lower_bound_valid = lower_bound <= self.lower_bound(&objective_variable);
 
+        let mut best_solution: Option<Solution> = None;
         loop {
-            self.satisfaction_solver.restore_state_at_root(brancher);
-
-            let objective_bound_predicate = if is_maximising {
-                predicate![objective_variable <= best_objective_value as i32]
-            } else {
-                predicate![objective_variable >= best_objective_value as i32]
-            };
-
-            let objective_bound_literal = self
+            // The upper bound is imposed as an assumption such that it is undone afterwards
+            let upper_bound_literal = self
                 .satisfaction_solver
-                .get_literal(objective_bound_predicate);
-
-            if self
-                .strengthen(
-                    &objective_variable,
-                    best_objective_value * objective_multiplier as i64,
-                )
-                .is_err()
-            {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self
-                    .satisfaction_solver
-                    .conclude_proof_optimal(objective_bound_literal);
-
-                return OptimisationResult::Optimal(best_solution);
-            }
+                .get_literal(objective_variable.upper_bound_predicate(upper_bound));
 
-            let solve_result = self.satisfaction_solver.solve(termination, brancher);
+            let solve_result = self.satisfaction_solver.solve_under_assumptions(
+                &assumptions
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(upper_bound_literal))
+                    .collect::<Vec<_>>(),
+                termination,
+                brancher,
+            );
             match solve_result {
                 CSPSolverExecutionFlag::Feasible => {
-                    self.debug_bound_change(
-                        &objective_variable,
-                        best_objective_value * objective_multiplier as i64,
-                    );
-                    self.update_best_solution_and_process(
-                        objective_multiplier,
-                        &objective_variable,
-                        &mut best_objective_value,
-                        &mut best_solution,
+                    let objective_value = self
+                        .satisfaction_solver
+                        .get_assigned_integer_value(&objective_variable)
+                        .expect("expected variable to be assigned");
+                    let reported_objective_value = objective_multiplier * objective_value as i64;
+                    let solution =
+                        Solution::from(self.satisfaction_solver.get_solution_reference())
+                            .with_objective_value(reported_objective_value);
+                    self.internal_process_solution(
+                        &solution,
                         brancher,
+                        Some(reported_objective_value),
                     );
-                }
-                CSPSolverExecutionFlag::Infeasible => {
-                    {
-                        // Reset the state whenever we return a result
+
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+
+                    if objective_value <= lower_bound {
+                        return if is_lower_bound_valid {
+                            OptimisationResult::Optimal(solution)
+                        } else {
+                            OptimisationResult::Satisfiable(solution)
+                        };
+                    }
+
+                    // No smaller objective value exists if the objective value is `i32::MIN`
+                    let Some(improved_upper_bound) = objective_value.checked_sub(1) else {
+                        return OptimisationResult::Optimal(solution);
+                    };
+                    upper_bound = improved_upper_bound;
+                    best_solution = Some(solution);
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+
+                    // There is no solution which improves on the best solution
+                    return match best_solution {
+                        Some(solution) => OptimisationResult::Optimal(solution),
+                        None => OptimisationResult::Unsatisfiable,
+                    };
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+
+                    return match best_solution {
+                        Some(solution) => OptimisationResult::Satisfiable(solution),
+                        None => OptimisationResult::Unknown,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is maximised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
+    /// it exists.
+    pub fn maximise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        self.minimise_internal(brancher, termination, objective_variable.scaled(-1), true)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided `objective`
+    /// [`Function`] is minimised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// The [`Function`] is linked to a new integer variable which is then minimised (see
+    /// [`Solver::new_function_variable`]); both the weighted integers and the weighted literals of
+    /// the [`Function`] are supported. If the weights, the constant term or the range of values of
+    /// the [`Function`] do not fit in an `i32`, then [`OptimisationResult::Unknown`] is returned and
+    /// the [`Solver`] is left unchanged.
+    ///
+    /// Additionally, a bounding propagator is posted which prunes every subtree in which the lower
+    /// bound of the [`Function`] over the partial assignment does not improve on the best solution
+    /// found so far.
+    ///
+    /// The link to the [`Function`], the bounding propagator and the bounds on the objective value
+    /// only apply to this call; afterwards, the model in the [`Solver`] has the same solutions as
+    /// before (though it may contain new variables), so the [`Solver`] can be used for subsequent
+    /// calls. As for [`Solver::minimise_with_bounds`], proof logging is not supported.
+    pub fn minimise_function(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective: &Function,
+    ) -> OptimisationResult {
+        self.optimise_function(brancher, termination, objective, false)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided `objective`
+    /// [`Function`] is maximised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// See [`Solver::minimise_function`] for how the [`Function`] is handled; the bounding
+    /// propagator prunes every subtree in which the upper bound of the [`Function`] does not
+    /// improve on the best solution found so far. The objective value is reported in the original
+    /// scale of the [`Function`].
+    pub fn maximise_function(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective: &Function,
+    ) -> OptimisationResult {
+        self.optimise_function(brancher, termination, objective, true)
+    }
+
+    /// The internal method of [`Solver::minimise_function`] and [`Solver::maximise_function`].
+    ///
+    /// The constraints linking the objective variable to the [`Function`] are posted under an
+    /// activation literal, which is assumed during the search together with the bound on the
+    /// objective value; afterwards, the activation literal is fixed to false such that these
+    /// constraints no longer affect the model.
+    fn optimise_function(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective: &Function,
+        is_maximising: bool,
+    ) -> OptimisationResult {
+        let (objective_variable, activation_literal) = match self
+            .new_function_variable_internal(objective, None, true)
+        {
+            Ok(created) => created,
+            Err(ConstraintOperationError::ValueOutOfRange) => return OptimisationResult::Unknown,
+            // Without explicit bounds, creating the variable otherwise only fails if the
+            // solver is infeasible
+            Err(_) => return OptimisationResult::Unsatisfiable,
+        };
+        let activation_literal =
+            activation_literal.expect("the function variable is created conditionally");
+
+        let result = if self
+            .add_constraint(ObjectiveBoundPropagator::new(
+                objective.clone(),
+                objective_variable,
+            ))
+            .implied_by(activation_literal)
+            .is_err()
+        {
+            OptimisationResult::Unsatisfiable
+        } else if is_maximising {
+            let objective_variable = objective_variable.scaled(-1);
+            let lower_bound = self.lower_bound(&objective_variable);
+            let upper_bound = self.upper_bound(&objective_variable);
+            self.minimise_with_bounds_internal(
+                brancher,
+                termination,
+                objective_variable,
+                lower_bound,
+                upper_bound,
+                true,
+                &[activation_literal],
+            )
+        } else {
+            let lower_bound = self.lower_bound(&objective_variable);
+            let upper_bound = self.upper_bound(&objective_variable);
+            self.minimise_with_bounds_internal(
+                brancher,
+                termination,
+                objective_variable,
+                lower_bound,
+                upper_bound,
+                false,
+                &[activation_literal],
+            )
+        };
+
+        // If the solver is infeasible, then there is nothing left to deactivate
+        let _ = self.add_clause([!activation_literal]);
+
+        result
+    }
+
+    /// Creates a new integer variable which is equal to the value of the provided [`Function`].
+    ///
+    /// By default, the domain of the created variable is the range of values which the
+    /// [`Function`] can take given the current bounds of its terms; this range can be large, which
+    /// slows down search on the objective. If tighter bounds `[lower_bound, upper_bound]` are
+    /// known (e.g. from a heuristic solution), they can be provided using `bounds`; the domain is
+    /// then given by the provided bounds.
+    ///
+    /// If the provided bounds are empty or do not lie within the implied range, then a
+    /// [`ConstraintOperationError::InfeasibleDomain`] is returned and the [`Solver`] is left
+    /// unchanged. Similarly, if a weight or the constant term of the [`Function`], a term scaled by
+    /// its weight or the range of values of the [`Function`] does not fit in an `i32`, then a
+    /// [`ConstraintOperationError::ValueOutOfRange`] is returned and the [`Solver`] is left
+    /// unchanged. If the [`Solver`] is already in an infeasible state, then a
+    /// [`ConstraintOperationError::InfeasibleState`] is returned. Otherwise, if linking the
+    /// created variable to the [`Function`] leads to infeasibility at the root, then the
+    /// corresponding [`ConstraintOperationError`] is returned and the [`Solver`] is in an
+    /// infeasible state.
+    pub fn new_function_variable(
+        &mut self,
+        function: &Function,
+        bounds: Option<(i32, i32)>,
+    ) -> Result<DomainId, ConstraintOperationError> {
+        self.new_function_variable_internal(function, bounds, false)
+            .map(|(function_variable, _)| function_variable)
+    }
+
+    /// The internal method of [`Solver::new_function_variable`]; if `is_conditional` is true, then
+    /// the created variable is only linked to the [`Function`] if the returned activation literal
+    /// is true.
+    ///
+    /// The clauses linking the weighted literals to their indicator variables are always posted;
+    /// they only define the (new) indicator variables and do not restrict the model.
+    fn new_function_variable_internal(
+        &mut self,
+        function: &Function,
+        bounds: Option<(i32, i32)>,
+        is_conditional: bool,
+    ) -> Result<(DomainId, Option<Literal>), ConstraintOperationError> {
+        if self.satisfaction_solver.get_state().is_infeasible() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+
+        fn to_i32(value: impl TryInto<i32>) -> Result<i32, ConstraintOperationError> {
+            value
+                .try_into()
+                .map_err(|_| ConstraintOperationError::ValueOutOfRange)
+        }
+
+        // The ranges are computed using `i64`s and validated before any variable or clause is
+        // created
+        let constant = to_i32(function.get_constant_term())?;
+        let (mut lower_bound, mut upper_bound) = (constant as i64, constant as i64);
+
+        let mut terms = Vec::new();
+        for (&domain_id, &weight) in function.get_weighted_integers() {
+            let weight = to_i32(weight)?;
+
+            // The weight is non-negative, so the bounds of the term are the scaled bounds of the
+            // variable; these should fit in an `i32` for the view over the variable
+            let term_lower_bound = weight as i64 * self.lower_bound(&domain_id) as i64;
+            let term_upper_bound = weight as i64 * self.upper_bound(&domain_id) as i64;
+            let _ = to_i32(term_lower_bound)?;
+            let _ = to_i32(term_upper_bound)?;
+
+            lower_bound += term_lower_bound;
+            upper_bound += term_upper_bound;
+            terms.push(domain_id.scaled(weight));
+        }
+
+        // The value of every weighted literal lies in [0, weight]
+        let mut weighted_literals = Vec::new();
+        for (&literal, &weight) in function.get_weighted_literals() {
+            let weight = to_i32(weight)?;
+
+            upper_bound += weight as i64;
+            weighted_literals.push((literal, weight));
+        }
+
+        let mut lower_bound = to_i32(lower_bound)?;
+        let mut upper_bound = to_i32(upper_bound)?;
+
+        if let Some((explicit_lower_bound, explicit_upper_bound)) = bounds {
+            if explicit_lower_bound > explicit_upper_bound
+                || explicit_lower_bound < lower_bound
+                || explicit_upper_bound > upper_bound
+            {
+                return Err(ConstraintOperationError::InfeasibleDomain);
+            }
+
+            lower_bound = explicit_lower_bound;
+            upper_bound = explicit_upper_bound;
+        }
+
+        for (literal, weight) in weighted_literals {
+            let indicator = self.new_bounded_integer(0, 1);
+            // literal <-> [indicator == 1]
+            let indicator_is_one = self.get_literal(predicate![indicator >= 1]);
+            self.add_clause([!literal, indicator_is_one])?;
+            self.add_clause([literal, !indicator_is_one])?;
+
+            terms.push(indicator.scaled(weight));
+        }
+
+        let objective_variable = self.new_bounded_integer(lower_bound, upper_bound);
+
+        terms.push(objective_variable.scaled(-1));
+        if is_conditional {
+            let activation_literal = self.new_literal();
+            self.add_constraint(constraints::equals(terms, -constant))
+                .implied_by(activation_literal)?;
+            Ok((objective_variable, Some(activation_literal)))
+        } else {
+            self.add_constraint(constraints::equals(terms, -constant))
+                .post()?;
+            Ok((objective_variable, None))
+        }
+    }
+
+    /// The internal method which optimizes the objective function, this function takes an extra
+    /// argument (`is_maximising`) as compared to [`Solver::maximise`] and [`Solver::minimise`]
+    /// which determines whether the logged objective value should be scaled by `-1` or not.
+    ///
+    /// This is necessary due to the fact that [`Solver::maximise`] simply calls minimise with
+    /// the objective variable scaled with `-1` which would lead to incorrect statistic if not
+    /// scaled back.
+    fn minimise_internal(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        is_maximising: bool,
+    ) -> OptimisationResult {
+        // If we are maximising then when we simply scale the variable by -1, however, this will
+        // lead to the printed objective value in the statistics to be multiplied by -1; this
+        // objective_multiplier ensures that the objective is correctly logged.
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
+        match initial_solve {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                let _ = self.satisfaction_solver.conclude_proof_unsat();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        self.update_best_solution_and_process(
+            objective_multiplier,
+            &objective_variable,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+        );
+
+        loop {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+
+            let objective_bound_predicate = if is_maximising {
+                predicate![objective_variable <= best_objective_value as i32]
+            } else {
+                predicate![objective_variable >= best_objective_value as i32]
+            };
+
+            let objective_bound_literal = self
+                .satisfaction_solver
+                .get_literal(objective_bound_predicate);
+
+            if self
+                .strengthen(
+                    &objective_variable,
+                    best_objective_value * objective_multiplier as i64,
+                )
+                .is_err()
+            {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                let _ = self
+                    .satisfaction_solver
+                    .conclude_proof_optimal(objective_bound_literal);
+
+                return OptimisationResult::Optimal(best_solution);
+            }
+
+            let solve_result = self.satisfaction_solver.solve(termination, brancher);
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.debug_bound_change(
+                        &objective_variable,
+                        best_objective_value * objective_multiplier as i64,
+                    );
+                    self.update_best_solution_and_process(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                    );
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    {
+                        // Reset the state whenever we return a result
                         self.satisfaction_solver.restore_state_at_root(brancher);
                         let _ = self
                             .satisfaction_solver
@@ -598,7 +1499,8 @@ impl Solver {
                 .satisfaction_solver
                 .get_assigned_integer_value(objective_variable)
                 .expect("expected variable to be assigned")) as i64;
-        *best_solution = self.satisfaction_solver.get_solution_reference().into();
+        *best_solution = Solution::from(self.satisfaction_solver.get_solution_reference())
+            .with_objective_value(*best_objective_value);
 
         self.internal_process_solution(best_solution, brancher, Some(*best_objective_value))
     }
@@ -685,11 +1587,89 @@ impl Solver {
         ConstraintPoster::new(self, constraint)
     }
 
+    /// Adds a soft constraint to the solver; i.e. a constraint which should hold, but which incurs
+    /// the provided `penalty` if it is violated.
+    ///
+    /// The constraint is reified with a new literal which is returned, and `penalty * !literal` is
+    /// added to the [`Function`] of soft constraint penalties (see
+    /// [`Solver::soft_constraint_penalties`]). Minimising this [`Function`] using
+    /// [`Solver::minimise_function`] finds the assignment with the least total penalty.
+    ///
+    /// This method returns a [`ConstraintOperationError`] if the reification of the constraint led
+    /// to a root-level conflict.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// let at_least_seven = solver
+    ///     .add_soft_constraint(constraints::less_than_or_equals([x.scaled(-1)], -7), 3)
+    ///     .expect("no root-level conflict");
+    /// let at_most_three = solver
+    ///     .add_soft_constraint(constraints::less_than_or_equals([x.scaled(1)], 3), 5)
+    ///     .expect("no root-level conflict");
+    ///
+    /// let penalties = solver.soft_constraint_penalties().clone();
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise_function(&mut brancher, &mut Indefinite, &penalties);
+    ///
+    /// // Violating the cheaper soft constraint is optimal
+    /// let OptimisationResult::Optimal(solution) = result else {
+    ///     panic!("the problem is feasible");
+    /// };
+    /// assert!(!solution.get_literal_value(at_least_seven));
+    /// assert!(solution.get_literal_value(at_most_three));
+    /// assert_eq!(3, penalties.evaluate_assignment(&solution));
+    /// ```
+    pub fn add_soft_constraint<Constraint: NegatableConstraint>(
+        &mut self,
+        constraint: Constraint,
+        penalty: u64,
+    ) -> Result<Literal, ConstraintOperationError> {
+        let literal = self.new_literal();
+        self.add_constraint(constraint).reify(literal)?;
+        self.soft_constraint_penalties
+            .add_weighted_literal(!literal, penalty);
+
+        Ok(literal)
+    }
+
+    /// Returns the [`Function`] consisting of the penalties of the soft constraints which have
+    /// been added using [`Solver::add_soft_constraint`].
+    pub fn soft_constraint_penalties(&self) -> &Function {
+        &self.soft_constraint_penalties
+    }
+
     /// Creates a clause from `literals` and adds it to the current formula.
     ///
+    /// The clause is propagated by the clausal propagator, which means that it can serve as the
+    /// reason for propagations (and conflicts) during conflict analysis like any other clause.
+    ///
     /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
     /// returned. Subsequent calls to this method will always return an error, and no
     /// modification of the solver will take place.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let a = solver.new_literal();
+    /// let b = solver.new_literal();
+    ///
+    /// solver.add_clause([a, b]).expect("the clause is not falsified");
+    /// solver.add_clause([!a]).expect("the clause is not falsified");
+    ///
+    /// // Since `a` is false, the clause forces `b` to be true
+    /// assert_eq!(solver.get_literal_value(b), Some(true));
+    /// ```
     pub fn add_clause(
         &mut self,
         clause: impl IntoIterator<Item = Literal>,
@@ -697,6 +1677,59 @@ impl Solver {
         self.satisfaction_solver.add_clause(clause)
     }
 
+    /// Fixes `variable` to `value` at the root; this is equivalent to adding the unit clause
+    /// `[variable == value]` using [`Solver::add_clause`].
+    ///
+    /// If `value` is not in the domain of `variable`, then a [`ConstraintOperationError`] is
+    /// returned and the solver is in an infeasible state.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// solver.fix_integer(x, 4).expect("4 is in the domain of x");
+    /// assert_eq!(4, solver.lower_bound(&x));
+    /// assert_eq!(4, solver.upper_bound(&x));
+    ///
+    /// assert!(solver.fix_integer(x, 5).is_err());
+    /// ```
+    pub fn fix_integer(
+        &mut self,
+        variable: impl IntegerVariable,
+        value: i32,
+    ) -> Result<(), ConstraintOperationError> {
+        self.add_clause([self.get_literal(predicate![variable == value])])
+    }
+
+    /// Tightens the lower bound of `variable` to `bound` at the root; this is equivalent to adding
+    /// the unit clause `[variable >= bound]` using [`Solver::add_clause`].
+    ///
+    /// If this empties the domain of `variable`, then a [`ConstraintOperationError`] is returned
+    /// and the solver is in an infeasible state.
+    pub fn add_lower_bound(
+        &mut self,
+        variable: impl IntegerVariable,
+        bound: i32,
+    ) -> Result<(), ConstraintOperationError> {
+        self.add_clause([self.get_literal(predicate![variable >= bound])])
+    }
+
+    /// Tightens the upper bound of `variable` to `bound` at the root; this is equivalent to adding
+    /// the unit clause `[variable <= bound]` using [`Solver::add_clause`].
+    ///
+    /// If this empties the domain of `variable`, then a [`ConstraintOperationError`] is returned
+    /// and the solver is in an infeasible state.
+    pub fn add_upper_bound(
+        &mut self,
+        variable: impl IntegerVariable,
+        bound: i32,
+    ) -> Result<(), ConstraintOperationError> {
+        self.add_clause([self.get_literal(predicate![variable <= bound])])
+    }
+
     /// Adds a propagator with a tag, which is used to identify inferences made by this propagator
     /// in the proof log.
     pub(crate) fn add_tagged_propagator(
@@ -711,21 +1744,64 @@ impl Solver {
     /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
     /// through propagation, this will return a [`ConstraintOperationError`].
     ///
-    /// The caller should ensure the solver is in the root state before calling this, either
-    /// because no call to [`Self::solve()`] has been made, or because
-    /// [`Self::restore_state_at_root()`] was called.
-    ///
     /// If the solver is already in a conflicting state, i.e. a previous call to this method
-    /// already returned `false`, calling this again will not alter the solver in any way, and
-    /// `false` will be returned again.
-    pub(crate) fn add_propagator(
-        &mut self,
-        propagator: impl Propagator + 'static,
-    ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver.add_propagator(propagator, None)
-    }
-}
-
+    /// already returned an error, calling this again will not alter the solver in any way, and
+    /// an error will be returned again.
+    ///
+    /// See the [`propagation`](crate::propagation) module for the building blocks of a custom
+    /// [`Propagator`].
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::conjunction;
+    /// # use pumpkin_solver::predicates::PropositionalConjunction;
+    /// # use pumpkin_solver::propagation::*;
+    /// # use pumpkin_solver::variables::DomainId;
+    /// # use pumpkin_solver::Solver;
+    /// /// A propagator which enforces `variable >= bound`.
+    /// struct AtLeast {
+    ///     variable: DomainId,
+    ///     bound: i32,
+    /// }
+    ///
+    /// impl Propagator for AtLeast {
+    ///     fn name(&self) -> &str {
+    ///         "AtLeast"
+    ///     }
+    ///
+    ///     fn initialise_at_root(
+    ///         &mut self,
+    ///         context: &mut PropagatorInitialisationContext,
+    ///     ) -> Result<(), PropositionalConjunction> {
+    ///         let _ = context.register(self.variable, DomainEvents::UPPER_BOUND, LocalId::from(0));
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn debug_propagate_from_scratch(
+    ///         &self,
+    ///         mut context: PropagationContextMut,
+    ///     ) -> PropagationStatusCP {
+    ///         context.set_lower_bound(&self.variable, self.bound, conjunction!())?;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// solver
+    ///     .add_propagator(AtLeast { variable: x, bound: 4 })
+    ///     .expect("the propagator does not cause a conflict");
+    /// assert_eq!(4, solver.lower_bound(&x));
+    /// ```
+    pub fn add_propagator(
+        &mut self,
+        propagator: impl Propagator + 'static,
+    ) -> Result<(), ConstraintOperationError> {
+        self.satisfaction_solver.add_propagator(propagator, None)
+    }
+}
+
 /// Default brancher implementation
 impl Solver {
     /// Creates a default [`IndependentVariableValueBrancher`] which uses [`Vsids`] as
@@ -775,3 +1851,1507 @@ pub type DefaultBrancher = IndependentVariableValueBrancher<
         PhaseSaving<PropositionalVariable, bool>,
     >,
 >;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::basic_types::SolutionReference;
+    use crate::branching::InDomainMax;
+    use crate::branching::InDomainMedian;
+    use crate::branching::InDomainMin;
+    use crate::branching::InDomainRandom;
+    use crate::branching::InDomainSplit;
+    use crate::branching::InputOrder;
+    use crate::branching::PreferredValueSelector;
+    use crate::branching::SelectionContext;
+    use crate::engine::propagation::PropagationContextMut;
+    use crate::engine::propagation::PropagatorInitialisationContext;
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
+
+    #[test]
+    fn decision_path_contains_decisions_but_not_propagations() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 2);
+        let y = solver.new_bounded_integer(0, 2);
+
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMin);
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        // Deciding [x <= 0] propagates [y != 0], after which [y <= 1] is decided.
+        assert_eq!(
+            solver.last_solution_decision_path(),
+            vec![predicate![x <= 0], predicate![y <= 1]]
+        );
+    }
+
+    #[test]
+    fn decision_path_contains_the_decided_predicate_of_a_shared_literal() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+
+        // [x == 1] shares its literal with [x >= 1]
+        let literal = solver.get_literal(predicate![x == 1]);
+        assert_eq!(literal, solver.get_literal(predicate![x >= 1]));
+        assert!(
+            solver
+                .satisfaction_solver
+                .variable_literal_mappings
+                .get_predicates(literal)
+                .count()
+                > 1
+        );
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainMedian);
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        assert_eq!(
+            solver.last_solution_decision_path(),
+            vec![predicate![x == 1]]
+        );
+    }
+
+    #[test]
+    fn extracted_mus_is_minimal_subset_of_conflicting_constraints() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let tags = (1..=4)
+            .map(|tag| NonZero::new(tag).unwrap())
+            .collect::<Vec<_>>();
+        let literals = solver.new_literals().take(4).collect::<Vec<_>>();
+
+        // [x >= 5], [x <= 3], [x <= 8] and [x != 7]; only the first two conflict
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x.scaled(-1)], -5))
+            .with_tag(tags[0])
+            .implied_by(literals[0]);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 3))
+            .with_tag(tags[1])
+            .implied_by(literals[1]);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 8))
+            .with_tag(tags[2])
+            .implied_by(literals[2]);
+        let _ = solver
+            .add_constraint(constraints::not_equals(vec![x], 7))
+            .with_tag(tags[3])
+            .implied_by(literals[3]);
+
+        let tagged_constraints = tags
+            .iter()
+            .copied()
+            .zip(literals.iter().copied())
+            .collect::<Vec<_>>();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let mus = solver.extract_mus(&mut brancher, &mut Indefinite, &tagged_constraints);
+
+        assert_eq!(mus, Some(vec![tags[0], tags[1]]));
+    }
+
+    #[test]
+    fn no_mus_is_extracted_from_satisfiable_constraints() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let literal = solver.new_literal();
+        let tag = NonZero::new(1).unwrap();
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 3))
+            .with_tag(tag)
+            .implied_by(literal);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let mus = solver.extract_mus(&mut brancher, &mut Indefinite, &[(tag, literal)]);
+
+        assert_eq!(mus, None);
+    }
+
+    #[test]
+    fn decision_path_is_empty_before_solving() {
+        let solver = Solver::default();
+        assert!(solver.last_solution_decision_path().is_empty());
+    }
+
+    #[test]
+    fn maximising_a_function_over_a_single_integer() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(2, 10);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 7))
+            .post();
+
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x, 1)
+            .expect("the term is non-negative");
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.maximise_function(&mut brancher, &mut Indefinite, &objective);
+
+        match result {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(5, solution.get_integer_value(x));
+                assert_eq!(5, objective.evaluate_assignment(&solution));
+            }
+            _ => panic!("the problem has an optimal solution"),
+        }
+    }
+
+    #[test]
+    fn maximising_a_function_with_weighted_literals() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let literal = solver.new_literal();
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 7))
+            .post();
+        let x_at_most_five = solver.get_literal(predicate![x <= 5]);
+        let _ = solver.add_clause([!literal, x_at_most_five]);
+
+        // Maximise 2x + 3l + 1, where setting l to true restricts x to at most 5
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        objective.add_weighted_literal(literal, 3);
+        objective.add_constant_term(1);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.maximise_function(&mut brancher, &mut Indefinite, &objective);
+
+        match result {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(7, solution.get_integer_value(x));
+                assert!(!solution.get_literal_value(literal));
+                assert_eq!(15, objective.evaluate_assignment(&solution));
+            }
+            _ => panic!("the problem has an optimal solution"),
+        }
+    }
+
+    #[test]
+    fn minimising_a_function_with_scaled_integer_term() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(-1), y.scaled(-1)],
+                -6,
+            ))
+            .post();
+
+        // Minimise 2 * (3x + 1) + y, which is 6x + y + 2, subject to x + y >= 6
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x.scaled(3).offset(1), 2)
+            .expect("the term is non-negative");
+        objective
+            .add_weighted_integer(y, 1)
+            .expect("the term is non-negative");
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_function(&mut brancher, &mut Indefinite, &objective);
+
+        match result {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(0, solution.get_integer_value(x));
+                assert_eq!(6, solution.get_integer_value(y));
+                assert_eq!(8, objective.evaluate_assignment(&solution));
+            }
+            _ => panic!("the problem has an optimal solution"),
+        }
+    }
+
+    #[test]
+    fn optimising_a_function_over_an_infeasible_model_is_unsatisfiable() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let x_is_small = solver.get_literal(predicate![x <= 5]);
+        assert!(solver.add_clause([x_is_small]).is_ok());
+        assert!(solver.add_clause([!x_is_small]).is_err());
+
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x, 1)
+            .expect("the term is non-negative");
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_function(&mut brancher, &mut Indefinite, &objective);
+        assert!(matches!(result, OptimisationResult::Unsatisfiable));
+
+        let result = solver.maximise_function(&mut brancher, &mut Indefinite, &objective);
+        assert!(matches!(result, OptimisationResult::Unsatisfiable));
+    }
+
+    #[test]
+    fn optimising_a_function_does_not_constrain_subsequent_calls() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        // x + y >= 5
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(-1), y.scaled(-1)],
+                -5,
+            ))
+            .post();
+
+        // 2x + y
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        objective
+            .add_weighted_integer(y, 1)
+            .expect("the term is non-negative");
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        for _ in 0..2 {
+            match solver.minimise_function(&mut brancher, &mut Indefinite, &objective) {
+                OptimisationResult::Optimal(solution) => {
+                    assert_eq!(Some(5), solution.objective_value());
+                }
+                _ => panic!("the problem has an optimal solution"),
+            }
+        }
+
+        // The bounds found while minimising do not prevent finding the maximum, and vice versa
+        match solver.maximise_function(&mut brancher, &mut Indefinite, &objective) {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(Some(30), solution.objective_value());
+            }
+            _ => panic!("the problem has an optimal solution"),
+        }
+        match solver.minimise_function(&mut brancher, &mut Indefinite, &objective) {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(Some(5), solution.objective_value());
+            }
+            _ => panic!("the problem has an optimal solution"),
+        }
+
+        // The solutions of the model are unaffected; x = 10 and y = 10 is still a solution
+        let x_is_ten = solver.get_literal(predicate![x >= 10]);
+        let y_is_ten = solver.get_literal(predicate![y >= 10]);
+        assert!(matches!(
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[x_is_ten, y_is_ten]),
+            SatisfactionResultUnderAssumptions::Satisfiable(_)
+        ));
+    }
+
+    #[test]
+    fn preferred_values_guide_the_search() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+
+        solver.set_preferred_value(x, 3);
+        solver.set_preferred_value(y, 3);
+        assert_eq!(Some(3), solver.preferred_value(x));
+
+        let mut brancher = IndependentVariableValueBrancher::new(
+            InputOrder::new(&[x, y]),
+            PreferredValueSelector::new(InDomainMin),
+        );
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                // The preferred value of y is no longer in its domain after x is assigned to 3
+                assert_eq!(3, solution.get_integer_value(x));
+                assert_eq!(0, solution.get_integer_value(y));
+            }
+            _ => panic!("the problem is satisfiable"),
+        }
+    }
+
+    #[test]
+    fn variables_fixed_to_same_value_at_root_are_implied_equal() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(3, 3);
+        let _z = solver.new_bounded_integer(0, 5);
+        let _w = solver.new_bounded_integer(4, 4);
+
+        let _ = solver
+            .add_constraint(constraints::equals([x.scaled(1), y.scaled(-1)], 0))
+            .post();
+
+        // z is not fixed and w is the only variable fixed to 4
+        assert_eq!(vec![(x, y)], solver.implied_equalities());
+    }
+
+    #[test]
+    fn unfixed_variables_constrained_equal_are_implied_equal() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let z = solver.new_bounded_integer(0, 5);
+        let w = solver.new_bounded_integer(0, 5);
+
+        let _ = solver
+            .add_constraint(constraints::binary_equals(x, y))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::binary_equals(y, z))
+            .post();
+        // w is at most x but not equal to it
+        let _ = solver
+            .add_constraint(constraints::binary_less_than_or_equals(w, x))
+            .post();
+
+        // None of the variables are fixed at the root
+        assert_eq!(0, solver.lower_bound(&x));
+        assert_eq!(5, solver.upper_bound(&x));
+        assert_eq!(vec![(x, y), (x, z)], solver.implied_equalities());
+    }
+
+    #[test]
+    fn variables_added_after_solving_are_part_of_the_model() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let _ = solver
+            .add_constraint(constraints::binary_less_than(x, y))
+            .post();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMin);
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        // Adding z > y forces x, y and z to be strictly increasing
+        let z = solver.new_bounded_integer(0, 2);
+        let _ = solver
+            .add_constraint(constraints::binary_less_than(y, z))
+            .post();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y, z]), InDomainMin);
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_eq!(0, solution.get_integer_value(x));
+                assert_eq!(1, solution.get_integer_value(y));
+                assert_eq!(2, solution.get_integer_value(z));
+            }
+            _ => panic!("the enlarged model should be satisfiable"),
+        }
+
+        // Restricting the new variable further makes the enlarged model infeasible
+        let _ = solver
+            .add_constraint(constraints::binary_less_than(z, x))
+            .post();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    }
+
+    #[test]
+    fn explicit_bounds_of_function_variable_are_used() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let literal = solver.new_literal();
+
+        // The value of 2x + 3l + 1 lies in [1, 24]
+        let mut function = Function::default();
+        function
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        function.add_weighted_literal(literal, 3);
+        function.add_constant_term(1);
+
+        let function_variable = solver
+            .new_function_variable(&function, Some((5, 12)))
+            .expect("the bounds lie within the implied range");
+
+        assert_eq!(5, solver.lower_bound(&function_variable));
+        assert_eq!(12, solver.upper_bound(&function_variable));
+        assert_eq!(5, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn inconsistent_bounds_of_function_variable_are_an_error() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let literal = solver.new_literal();
+
+        let mut function = Function::default();
+        function
+            .add_weighted_integer(x, 1)
+            .expect("the term is non-negative");
+        function.add_weighted_literal(literal, 1);
+        let num_domains = solver.satisfaction_solver.assignments_integer.num_domains();
+
+        assert!(matches!(
+            solver.new_function_variable(&function, Some((12, 20))),
+            Err(ConstraintOperationError::InfeasibleDomain)
+        ));
+        assert!(matches!(
+            solver.new_function_variable(&function, Some((4, 3))),
+            Err(ConstraintOperationError::InfeasibleDomain)
+        ));
+        // Bounds which exceed the implied range are not silently intersected with it
+        assert!(matches!(
+            solver.new_function_variable(&function, Some((5, 20))),
+            Err(ConstraintOperationError::InfeasibleDomain)
+        ));
+        assert_eq!(10, solver.upper_bound(&x));
+        // No indicator or function variable was created
+        assert_eq!(
+            num_domains,
+            solver.satisfaction_solver.assignments_integer.num_domains()
+        );
+    }
+
+    #[test]
+    fn function_variable_whose_values_do_not_fit_in_an_i32_is_an_error() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let literal = solver.new_literal();
+        let num_domains = solver.satisfaction_solver.assignments_integer.num_domains();
+
+        let too_large_weight = i32::MAX as u64 + 1;
+        let mut functions = vec![Function::default(); 4];
+        // The weight of a literal does not fit in an i32
+        functions[0].add_weighted_literal(literal, too_large_weight);
+        // The constant term does not fit in an i32
+        functions[1].add_constant_term(too_large_weight);
+        // The scaled bounds of a term do not fit in an i32
+        functions[2]
+            .add_weighted_integer(x, i32::MAX as u64 / 5)
+            .expect("the term is non-negative");
+        // Every term fits in an i32, but the range of the sum does not
+        for variable in [x, y] {
+            functions[3]
+                .add_weighted_integer(variable, i32::MAX as u64 / 10)
+                .expect("the term is non-negative");
+        }
+
+        for function in functions {
+            assert!(matches!(
+                solver.new_function_variable(&function, None),
+                Err(ConstraintOperationError::ValueOutOfRange)
+            ));
+            assert!(matches!(
+                solver.minimise_function(
+                    &mut solver.default_brancher_over_all_propositional_variables(),
+                    &mut Indefinite,
+                    &function
+                ),
+                OptimisationResult::Unknown
+            ));
+        }
+
+        // No indicator or function variable was created
+        assert_eq!(
+            num_domains,
+            solver.satisfaction_solver.assignments_integer.num_domains()
+        );
+    }
+
+    #[test]
+    fn recorded_derivation_resolves_to_learned_nogood() {
+        let mut solver = Solver::with_options(
+            LearningOptions::default(),
+            SolverOptions {
+                learning_clause_minimisation: false,
+                record_nogood_derivations: true,
+                ..Default::default()
+            },
+        );
+
+        let n = 6;
+        let queens = (0..n)
+            .map(|_| solver.new_bounded_integer(0, n - 1))
+            .collect::<Vec<_>>();
+        for i in 0..queens.len() {
+            for j in i + 1..queens.len() {
+                let distance = (j - i) as i32;
+                let _ = solver
+                    .add_constraint(constraints::binary_not_equals(queens[i], queens[j]))
+                    .post();
+                let _ = solver
+                    .add_constraint(constraints::binary_not_equals(
+                        queens[i].scaled(1),
+                        queens[j].offset(distance),
+                    ))
+                    .post();
+                let _ = solver
+                    .add_constraint(constraints::binary_not_equals(
+                        queens[i].scaled(1),
+                        queens[j].offset(-distance),
+                    ))
+                    .post();
+            }
+        }
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&queens), InDomainMin);
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        let derivation = solver
+            .last_learned_nogood_derivation()
+            .expect("a conflict was analysed");
+        assert!(!derivation.steps.is_empty());
+
+        // Replaying the resolution steps on the conflict results in the learned nogood, apart
+        // from the literals which are assigned at the root
+        let mut resolvent = derivation.conflict.iter().copied().collect::<HashSet<_>>();
+        for step in derivation.steps.iter() {
+            assert!(resolvent.remove(&!step.resolved_literal));
+            resolvent.extend(step.reason.iter().copied());
+        }
+
+        let assignments = &solver.satisfaction_solver.assignments_propositional;
+        let learned_clause = derivation
+            .learned_clause
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>();
+        assert!(learned_clause.is_subset(&resolvent));
+        assert!(resolvent
+            .difference(&learned_clause)
+            .all(|&literal| assignments.is_literal_root_assignment(literal)));
+    }
+
+    #[test]
+    fn clause_forces_literal_after_other_literal_is_falsified() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let b = solver.new_literal();
+
+        let x_at_least_five = solver.get_literal(predicate![x >= 5]);
+        let result = solver.add_clause([x_at_least_five, b]);
+        assert!(result.is_ok());
+        assert_eq!(solver.get_literal_value(b), None);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x], 3))
+            .post();
+
+        assert_eq!(solver.get_literal_value(b), Some(true));
+    }
+
+    #[test]
+    fn clause_falsified_at_root_is_an_error() {
+        let mut solver = Solver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        assert!(solver.add_clause([a]).is_ok());
+        assert!(solver.add_clause([!a]).is_err());
+
+        // The solver is in an inconsistent state, so subsequent clauses are rejected
+        assert!(solver.add_clause([b]).is_err());
+    }
+
+    #[test]
+    fn learned_nogoods_are_dumped_as_valid_dimacs() {
+        // The pigeonhole problem with 5 pigeons and 4 holes
+        let mut solver = Solver::default();
+        let pigeons = (0..5)
+            .map(|_| solver.new_literals().take(4).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for pigeon in pigeons.iter() {
+            let _ = solver.add_clause(pigeon.iter().copied());
+        }
+        for hole in 0..4 {
+            for (index, first) in pigeons.iter().enumerate() {
+                for second in pigeons[index + 1..].iter() {
+                    let _ = solver.add_clause([!first[hole], !second[hole]]);
+                }
+            }
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+
+        let mut dimacs = Vec::new();
+        solver
+            .dump_learned_nogoods_dimacs(&mut dimacs)
+            .expect("writing to a vector does not fail");
+        let dimacs = String::from_utf8(dimacs).expect("valid utf-8");
+
+        let mut lines = dimacs.lines();
+        let header = lines
+            .next()
+            .expect("there is a header")
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        assert_eq!(&header[..2], &["p", "cnf"]);
+        let num_variables = header[2].parse::<i64>().unwrap();
+        let num_clauses = header[3].parse::<usize>().unwrap();
+        assert!(num_clauses > 0);
+
+        let clauses = lines
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|literal| literal.parse::<i64>().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(num_clauses, clauses.len());
+        for clause in clauses {
+            assert_eq!(Some(&0), clause.last());
+            assert!(clause[..clause.len() - 1]
+                .iter()
+                .all(|&literal| literal != 0 && literal.abs() <= num_variables));
+        }
+    }
+
+    #[test]
+    fn greedy_completion_follows_lower_bounds() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let z = solver.new_bounded_integer(0, 3);
+        let result = solver
+            .add_constraint(constraints::all_different([x, y, z]))
+            .post();
+        assert!(result.is_ok());
+
+        let assumptions = [solver.get_literal(predicate![y == 0])];
+        let solution = solver
+            .greedy_complete(&assumptions)
+            .expect("the greedy completion succeeds");
+
+        assert_eq!(1, solution.get_integer_value(x));
+        assert_eq!(0, solution.get_integer_value(y));
+        assert_eq!(2, solution.get_integer_value(z));
+        // The solver is restored to the root
+        assert_eq!(0, solver.lower_bound(&x));
+        assert_eq!(3, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn greedy_completion_fails_without_search() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let z = solver.new_bounded_integer(0, 1);
+        // The sum is 2; this is only propagated when all but one of the variables are fixed
+        for rhs in [0, 1, 3] {
+            let result = solver
+                .add_constraint(constraints::not_equals([x, y, z], rhs))
+                .post();
+            assert!(result.is_ok());
+        }
+
+        // Assigning x and y to their lower bounds leaves no value for z
+        assert!(solver.greedy_complete(&[]).is_none());
+
+        // The solver can still find a solution after the failed completion
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+    }
+
+    #[test]
+    fn core_contains_only_conflicting_assumptions() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 2);
+        let y = solver.new_bounded_integer(0, 2);
+        let z = solver.new_bounded_integer(0, 5);
+        let result = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+        assert!(result.is_ok());
+
+        let x_is_zero = solver.get_literal(predicate![x <= 0]);
+        let z_is_large = solver.get_literal(predicate![z >= 3]);
+        let y_is_zero = solver.get_literal(predicate![y <= 0]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy_under_assumptions(
+            &mut brancher,
+            &mut Indefinite,
+            &[x_is_zero, z_is_large, y_is_zero],
+        );
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+        ));
+        drop(result);
+
+        let mut core = solver
+            .get_core()
+            .expect("a core should have been extracted");
+        core.sort_by_key(|literal| literal.to_u32());
+        let mut expected = vec![x_is_zero, y_is_zero];
+        expected.sort_by_key(|literal| literal.to_u32());
+        assert_eq!(expected, core);
+
+        // The core is reset by the next call to solve
+        let result =
+            solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[z_is_large]);
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::Satisfiable(_)
+        ));
+        drop(result);
+        assert!(solver.get_core().is_none());
+    }
+
+    #[test]
+    fn sparse_integer_respects_holes_from_the_start() {
+        let mut solver = Solver::default();
+        let x = solver.new_sparse_integer([1, 3, 5]);
+
+        assert_eq!(1, solver.lower_bound(&x));
+        assert_eq!(5, solver.upper_bound(&x));
+        for (value, is_in_domain) in [(1, true), (2, false), (3, true), (4, false), (5, true)] {
+            let literal = solver.get_literal(predicate![x == value]);
+            assert_eq!(
+                if is_in_domain { None } else { Some(false) },
+                solver.get_literal_value(literal),
+                "unexpected membership of {value}"
+            );
+        }
+
+        // Removing the upper bound skips over the hole at 4
+        let result = solver
+            .add_constraint(constraints::not_equals([x], 5))
+            .post();
+        assert!(result.is_ok());
+        assert_eq!(3, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn reified_linear_constraint_reflects_satisfiability() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(3, 5);
+
+        let [satisfied, violated, undecided] = [(); 3].map(|_| solver.new_literal());
+        for (rhs, literal) in [(10, satisfied), (2, violated), (7, undecided)] {
+            solver
+                .add_constraint(constraints::less_than_or_equals([x, y], rhs))
+                .reify(literal)
+                .expect("no root-level conflict");
+        }
+
+        assert_eq!(Some(true), solver.get_literal_value(satisfied));
+        assert_eq!(Some(false), solver.get_literal_value(violated));
+        assert_eq!(None, solver.get_literal_value(undecided));
+
+        // Setting the reification literal enforces the constraint
+        solver
+            .add_clause([undecided])
+            .expect("no root-level conflict");
+        assert_eq!(4, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn half_reified_linear_constraint_only_propagates_when_enforced() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(3, 5);
+        let literal = solver.new_literal();
+
+        solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 2))
+            .implied_by(literal)
+            .expect("no root-level conflict");
+
+        // The violated constraint falsifies the literal, but a satisfied constraint would not
+        // imply the literal to be true
+        assert_eq!(Some(false), solver.get_literal_value(literal));
+        assert_eq!(5, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn propagator_statistics_are_maintained_during_search() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let z = solver.new_bounded_integer(0, 1);
+        for (a, b) in [(x, y), (x, z), (y, z)] {
+            solver
+                .add_constraint(constraints::binary_not_equals(a, b))
+                .post()
+                .expect("no root-level conflict");
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+
+        let statistics = solver.propagator_statistics();
+        assert_eq!(3, statistics.len());
+        assert!(statistics
+            .iter()
+            .all(|(_, statistics)| statistics.num_enqueues > 0
+                && statistics.num_propagations >= statistics.num_enqueues));
+        assert!(statistics
+            .iter()
+            .any(|(_, statistics)| statistics.num_conflicts > 0));
+    }
+
+    #[test]
+    fn soft_constraint_with_lowest_penalty_is_violated() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        // x + y <= 4 (penalty 7) conflicts with x >= 3 and y >= 3 (penalty 2 each)
+        let sum = solver
+            .add_soft_constraint(constraints::less_than_or_equals([x, y], 4), 7)
+            .expect("no root-level conflict");
+        let x_large = solver
+            .add_soft_constraint(constraints::less_than_or_equals([x.scaled(-1)], -3), 2)
+            .expect("no root-level conflict");
+        let y_large = solver
+            .add_soft_constraint(constraints::less_than_or_equals([y.scaled(-1)], -3), 2)
+            .expect("no root-level conflict");
+
+        let penalties = solver.soft_constraint_penalties().clone();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_function(&mut brancher, &mut Indefinite, &penalties);
+
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected an optimal solution");
+        };
+        assert_eq!(2, penalties.evaluate_assignment(&solution));
+        assert!(solution.get_literal_value(sum));
+        assert_ne!(
+            solution.get_literal_value(x_large),
+            solution.get_literal_value(y_large)
+        );
+    }
+
+    #[test]
+    fn optimisation_result_stores_objective_value() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let b = solver.new_literal();
+        solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(-1), y.scaled(-1)],
+                -4,
+            ))
+            .post()
+            .expect("no root-level conflict");
+
+        let mut objective = Function::default();
+        objective
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        objective
+            .add_weighted_integer(y, 3)
+            .expect("the term is non-negative");
+        objective.add_weighted_literal(b, 4);
+        objective.add_constant_term(1);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_function(&mut brancher, &mut Indefinite, &objective);
+
+        let OptimisationResult::Optimal(ref solution) = result else {
+            panic!("expected an optimal solution");
+        };
+        let expected = objective.evaluate_solution(solution.as_reference());
+        assert_eq!(9, expected);
+        assert_eq!(Some(expected as i64), solution.objective_value());
+        assert_eq!(Some(expected as i64), result.objective_value());
+    }
+
+    #[test]
+    fn maximisation_result_stores_objective_value_in_original_scale() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 7))
+            .post()
+            .expect("no root-level conflict");
+        let objective = solver.new_bounded_integer(0, 20);
+        solver
+            .add_constraint(constraints::equals(
+                [x.scaled(1), y.scaled(2), objective.scaled(-1)],
+                0,
+            ))
+            .post()
+            .expect("no root-level conflict");
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.maximise(&mut brancher, &mut Indefinite, objective);
+
+        assert_eq!(Some(12), result.objective_value());
+    }
+
+    #[test]
+    fn solvers_with_the_same_seed_make_the_same_random_decisions() {
+        let decision_path = |seed: u64| {
+            let mut solver = Solver::with_seed(seed);
+            let variables = (0..5)
+                .map(|_| solver.new_bounded_integer(0, 20))
+                .collect::<Vec<_>>();
+            let _ = solver
+                .add_constraint(constraints::all_different(variables.clone()))
+                .post();
+
+            let mut brancher =
+                IndependentVariableValueBrancher::new(InputOrder::new(&variables), InDomainRandom);
+            let result = solver.satisfy(&mut brancher, &mut Indefinite);
+            assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+            solver.last_solution_decision_path()
+        };
+
+        assert_eq!(decision_path(7), decision_path(7));
+        assert_ne!(decision_path(7), decision_path(8));
+    }
+
+    #[test]
+    fn solving_under_different_assumptions_gives_independent_results() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(1), y.scaled(1)],
+                5,
+            ))
+            .post();
+        assert!(result.is_ok());
+
+        let x_is_small = solver.get_literal(predicate![x <= 1]);
+        let x_is_large = solver.get_literal(predicate![x >= 4]);
+        let y_is_large = solver.get_literal(predicate![y >= 3]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        match solver.satisfy_under_assumptions(
+            &mut brancher,
+            &mut Indefinite,
+            &[x_is_small, y_is_large],
+        ) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                assert!(solution.get_integer_value(x) <= 1);
+                assert!(solution.get_integer_value(y) >= 3);
+            }
+            _ => panic!("expected a solution"),
+        }
+
+        let result = solver.satisfy_under_assumptions(
+            &mut brancher,
+            &mut Indefinite,
+            &[x_is_large, y_is_large],
+        );
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+        ));
+        drop(result);
+
+        // The assumptions of the previous calls do not persist
+        match solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[x_is_large]) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                assert!(solution.get_integer_value(x) >= 4);
+                assert!(solution.get_integer_value(y) <= 1);
+            }
+            _ => panic!("expected a solution"),
+        };
+    }
+
+    #[test]
+    fn at_most_one_forces_other_literals_false() {
+        let mut solver = Solver::default();
+        let literals = solver.new_literals().take(3).collect::<Vec<_>>();
+        let result = solver
+            .add_constraint(constraints::at_most_one(literals.clone()))
+            .post();
+        assert!(result.is_ok());
+
+        let result = solver.add_clause([literals[1]]);
+        assert!(result.is_ok());
+
+        assert_eq!(Some(false), solver.get_literal_value(literals[0]));
+        assert_eq!(Some(false), solver.get_literal_value(literals[2]));
+    }
+
+    #[test]
+    fn at_most_one_detects_two_true_literals() {
+        let mut solver = Solver::default();
+        let literals = solver.new_literals().take(3).collect::<Vec<_>>();
+        let result = solver
+            .add_constraint(constraints::at_most_one(literals.clone()))
+            .post();
+        assert!(result.is_ok());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy_under_assumptions(
+            &mut brancher,
+            &mut Indefinite,
+            &[literals[0], literals[2]],
+        );
+        assert!(matches!(
+            result,
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+        ));
+    }
+
+    #[test]
+    fn exactly_one_forces_the_last_literal_true() {
+        let mut solver = Solver::default();
+        let literals = solver.new_literals().take(3).collect::<Vec<_>>();
+        let result = solver
+            .add_constraint(constraints::exactly_one(literals.clone()))
+            .post();
+        assert!(result.is_ok());
+
+        let result = solver.add_clause([!literals[0]]);
+        assert!(result.is_ok());
+        assert_eq!(None, solver.get_literal_value(literals[2]));
+
+        let result = solver.add_clause([!literals[1]]);
+        assert!(result.is_ok());
+        assert_eq!(Some(true), solver.get_literal_value(literals[2]));
+    }
+
+    #[test]
+    fn fixed_integer_takes_its_value_in_the_solution() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let result = solver
+            .add_constraint(constraints::equals([x.scaled(1), y.scaled(1)], 10))
+            .post();
+        assert!(result.is_ok());
+
+        assert!(solver.fix_integer(x, 7).is_ok());
+        assert!(solver.add_upper_bound(y, 5).is_ok());
+        assert!(solver.add_lower_bound(y, 2).is_ok());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let SatisfactionResult::Satisfiable(solution) =
+            solver.satisfy(&mut brancher, &mut Indefinite)
+        else {
+            panic!("expected a solution")
+        };
+        assert_eq!(7, solution.get_integer_value(x));
+        assert_eq!(3, solution.get_integer_value(y));
+    }
+
+    #[test]
+    fn fixing_integer_outside_of_domain_is_an_error() {
+        let mut solver = Solver::default();
+        let x = solver.new_sparse_integer([1, 3, 5]);
+
+        assert!(matches!(
+            solver.fix_integer(x, 2),
+            Err(ConstraintOperationError::InfeasibleClause)
+        ));
+        assert!(matches!(
+            solver.add_lower_bound(x, 3),
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+    }
+
+    #[test]
+    fn splitting_the_domain_explores_both_halves() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 100);
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainSplit);
+        let mut values = vec![];
+        let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |solution| {
+            values.push(solution.get_integer_value(x));
+            true
+        });
+
+        // The lower half of every split is explored first, and the upper half is explored after
+        // backtracking.
+        assert_eq!(101, num_solutions);
+        assert_eq!((0..=100).collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn predicate_literal_is_channeled_to_its_predicate() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let x_at_least_five = solver.get_literal(predicate![x >= 5]);
+        assert_eq!(x_at_least_five, solver.get_literal(predicate![x >= 5]));
+        assert_eq!(None, solver.get_literal_value(x_at_least_five));
+
+        // Satisfying the predicate sets the literal to true
+        solver.fix_integer(x, 7).expect("no root-level conflict");
+        assert_eq!(Some(true), solver.get_literal_value(x_at_least_five));
+
+        // Setting the literal to true enforces the predicate
+        let y_at_least_five = solver.get_literal(predicate![y >= 5]);
+        solver
+            .add_clause([y_at_least_five])
+            .expect("no root-level conflict");
+        assert_eq!(5, solver.lower_bound(&y));
+    }
+
+    #[test]
+    fn first_solution_follows_a_feasible_solution_hint() {
+        let mut solver = Solver::default();
+        let variables = [0; 4].map(|_| solver.new_bounded_integer(0, 3));
+        let result = solver
+            .add_constraint(constraints::all_different(variables))
+            .post();
+        assert!(result.is_ok());
+
+        let hint = [2, 0, 3, 1];
+        solver.set_solution_hint(&variables.iter().copied().zip(hint).collect::<Vec<_>>());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_eq!(
+                    hint,
+                    variables.map(|variable| solution.get_integer_value(variable))
+                );
+            }
+            _ => panic!("expected a solution"),
+        };
+    }
+
+    fn create_sum_minimisation(solver: &mut Solver) -> (DomainId, DomainId, DomainId) {
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let sum = solver.new_bounded_integer(0, 20);
+
+        let result = solver
+            .add_constraint(constraints::equals(
+                [x.scaled(1), y.scaled(1), sum.scaled(-1)],
+                0,
+            ))
+            .post();
+        assert!(result.is_ok());
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(-1), y.scaled(-1)],
+                -7,
+            ))
+            .post();
+        assert!(result.is_ok());
+
+        (x, y, sum)
+    }
+
+    #[test]
+    fn bounds_on_the_objective_reduce_the_number_of_solutions_found() {
+        let count_solutions = |bounds: Option<(i32, i32)>| {
+            let mut solver = Solver::default();
+            let (x, y, sum) = create_sum_minimisation(&mut solver);
+
+            let num_solutions = Rc::new(Cell::new(0));
+            let counter = Rc::clone(&num_solutions);
+            solver.with_solution_callback(move |_| counter.set(counter.get() + 1));
+
+            let mut brancher =
+                IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMax);
+            let result = match bounds {
+                Some((lower_bound, upper_bound)) => solver.minimise_with_bounds(
+                    &mut brancher,
+                    &mut Indefinite,
+                    sum,
+                    lower_bound,
+                    upper_bound,
+                ),
+                None => solver.minimise(&mut brancher, &mut Indefinite, sum),
+            };
+
+            // The lower bound is not implied by the model, so reaching it does not prove
+            // optimality
+            match (bounds, result) {
+                (None, OptimisationResult::Optimal(solution))
+                | (Some(_), OptimisationResult::Satisfiable(solution)) => {
+                    assert_eq!(7, solution.get_integer_value(sum))
+                }
+                (_, result) => panic!("unexpected result {result:?}"),
+            }
+
+            num_solutions.get()
+        };
+
+        let without_bounds = count_solutions(None);
+        let with_bounds = count_solutions(Some((7, 8)));
+
+        // The first solution is at the upper bound, and the search stops at the lower bound
+        assert_eq!(2, with_bounds);
+        assert!(with_bounds < without_bounds);
+    }
+
+    #[test]
+    fn infeasible_bounds_on_the_objective_are_unsatisfiable() {
+        let mut solver = Solver::default();
+        let (_, _, sum) = create_sum_minimisation(&mut solver);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_with_bounds(&mut brancher, &mut Indefinite, sum, 0, 6);
+
+        assert!(matches!(result, OptimisationResult::Unsatisfiable));
+    }
+
+    #[test]
+    fn invalid_lower_bound_on_the_objective_is_not_reported_as_optimal() {
+        let mut solver = Solver::default();
+        let (_, _, sum) = create_sum_minimisation(&mut solver);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_with_bounds(&mut brancher, &mut Indefinite, sum, 9, 12);
+
+        match result {
+            OptimisationResult::Satisfiable(solution) => {
+                assert!(solution.get_integer_value(sum) <= 9)
+            }
+            result => panic!("expected a non-optimal solution, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn bounds_on_the_objective_do_not_affect_later_calls() {
+        let mut solver = Solver::default();
+        let (_, _, sum) = create_sum_minimisation(&mut solver);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_with_bounds(&mut brancher, &mut Indefinite, sum, 0, 6);
+        assert!(matches!(result, OptimisationResult::Unsatisfiable));
+        assert_eq!(20, solver.upper_bound(&sum));
+
+        let result = solver.minimise(&mut brancher, &mut Indefinite, sum);
+        match result {
+            OptimisationResult::Optimal(solution) => {
+                assert_eq!(7, solution.get_integer_value(sum))
+            }
+            result => panic!("expected an optimal solution, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn linear_constraint_over_a_repeated_variable_is_propagated_to_a_fixpoint() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(2), x.scaled(-1)],
+                5,
+            ))
+            .post();
+        assert!(result.is_ok());
+
+        assert_eq!(5, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn clones_solve_the_same_model_independently() {
+        let mut solver = Solver::default();
+        let variables = [0; 4].map(|_| solver.new_bounded_integer(0, 3));
+        let result = solver
+            .add_constraint(constraints::all_different(variables))
+            .post();
+        assert!(result.is_ok());
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [variables[0].scaled(1), variables[1].scaled(-1)],
+                -1,
+            ))
+            .post();
+        assert!(result.is_ok());
+
+        let mut minimising_clone = solver.clone_with_state(0).expect("can be copied");
+        let mut maximising_clone = solver.clone_with_state(1).expect("can be copied");
+
+        // A constraint added to one clone is not seen by the original or the other clone
+        let result = minimising_clone.add_upper_bound(variables[3], 1);
+        assert!(result.is_ok());
+        assert_eq!(3, maximising_clone.upper_bound(&variables[3]));
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&variables), InDomainMin);
+        match minimising_clone.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => assert_eq!(
+                [0, 2, 3, 1],
+                variables.map(|variable| solution.get_integer_value(variable))
+            ),
+            result => panic!("expected a solution, got {result:?}"),
+        }
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&variables), InDomainMax);
+        match maximising_clone.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => assert_eq!(
+                [2, 3, 1, 0],
+                variables.map(|variable| solution.get_integer_value(variable))
+            ),
+            result => panic!("expected a solution, got {result:?}"),
+        }
+
+        assert_eq!(3, solver.upper_bound(&variables[3]));
+    }
+
+    #[test]
+    fn solver_with_propagator_which_cannot_be_copied_is_not_cloned() {
+        /// A propagator which does not implement [`Propagator::clone_boxed`].
+        struct NotCopyable;
+
+        impl Propagator for NotCopyable {
+            fn name(&self) -> &str {
+                "NotCopyable"
+            }
+
+            fn initialise_at_root(
+                &mut self,
+                _: &mut PropagatorInitialisationContext,
+            ) -> Result<(), PropositionalConjunction> {
+                Ok(())
+            }
+
+            fn debug_propagate_from_scratch(
+                &self,
+                _: PropagationContextMut,
+            ) -> PropagationStatusCP {
+                Ok(())
+            }
+        }
+
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let result = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+        assert!(result.is_ok());
+        assert!(solver.clone_with_state(0).is_some());
+
+        let result = solver.add_propagator(NotCopyable);
+        assert!(result.is_ok());
+        assert!(solver.clone_with_state(0).is_none());
+    }
+
+    #[test]
+    fn enumeration_finds_every_solution_once() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 2);
+        let y = solver.new_bounded_integer(0, 2);
+        let z = solver.new_bounded_integer(0, 2);
+        let result = solver
+            .add_constraint(constraints::all_different([x, y, z]))
+            .post();
+        assert!(result.is_ok());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let mut solutions: HashSet<[i32; 3]> = HashSet::default();
+        let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |solution| {
+            let values = [x, y, z].map(|variable| solution.get_integer_value(variable));
+            assert!(solutions.insert(values), "{values:?} was found twice");
+            true
+        });
+
+        assert_eq!(6, num_solutions);
+        assert_eq!(6, solutions.len());
+    }
+
+    #[test]
+    fn depth_gated_constraint_is_satisfied_by_every_solution() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let result = solver
+            .add_constraint(constraints::depth_gated(
+                LinearLessOrEqualPropagator::new([x, y].into(), 3),
+                0,
+            ))
+            .post();
+        assert!(result.is_ok());
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMin);
+        let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |solution| {
+            let sum = solution.get_integer_value(x) + solution.get_integer_value(y);
+            assert!(sum <= 3, "the solution violates x + y <= 3");
+            true
+        });
+
+        assert_eq!(10, num_solutions);
+    }
+
+    /// A [`Brancher`] which counts the number of times that [`Brancher::on_solution`] is called.
+    struct SolutionCountingBrancher<B> {
+        inner: B,
+        num_solutions: usize,
+    }
+
+    impl<B: Brancher> Brancher for SolutionCountingBrancher<B> {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            self.inner.next_decision(context)
+        }
+
+        fn on_solution(&mut self, solution: SolutionReference) {
+            self.num_solutions += 1;
+            self.inner.on_solution(solution)
+        }
+    }
+
+    #[test]
+    fn brancher_is_notified_of_every_enumerated_solution() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 2);
+        let y = solver.new_bounded_integer(0, 2);
+        let z = solver.new_bounded_integer(0, 2);
+        let result = solver
+            .add_constraint(constraints::all_different([x, y, z]))
+            .post();
+        assert!(result.is_ok());
+
+        let mut brancher = SolutionCountingBrancher {
+            inner: IndependentVariableValueBrancher::new(InputOrder::new(&[x, y, z]), InDomainMin),
+            num_solutions: 0,
+        };
+        let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |_| true);
+
+        assert_eq!(6, num_solutions);
+        assert_eq!(6, brancher.num_solutions);
+    }
+
+    #[test]
+    fn enumeration_stops_when_callback_returns_false() {
+        let mut solver = Solver::default();
+        let _ = solver.new_bounded_integer(0, 9);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let mut num_calls = 0;
+        let num_solutions = solver.enumerate(&mut brancher, &mut Indefinite, |_| {
+            num_calls += 1;
+            num_calls < 3
+        });
+
+        assert_eq!(3, num_solutions);
+        assert_eq!(3, num_calls);
+    }
+}
@@ -16,4 +16,11 @@ pub enum ConstraintOperationError {
     /// Error which indicate that adding a propagator led to infeasibility at the root.
     #[error("Adding the constraint failed because it is infeasible at the root")]
     InfeasiblePropagator,
+    /// Error which indicates that the provided bounds of a variable led to an empty domain.
+    #[error("Creating the variable failed because the provided bounds lead to an empty domain")]
+    InfeasibleDomain,
+    /// Error which indicates that a value (e.g. a weight or the range of values of a function)
+    /// does not fit in an `i32`.
+    #[error("The operation failed because a value does not fit in an i32")]
+    ValueOutOfRange,
 }
@@ -1,14 +1,26 @@
+use std::ops::Add;
+use std::ops::AddAssign;
+
+use thiserror::Error;
+
 use super::solution::ProblemSolution;
 use super::Solution;
 use crate::basic_types::HashMap;
 use crate::basic_types::SolutionReference;
 use crate::basic_types::WeightedLiteral;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::variables::AffineView;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::predicate;
 use crate::pumpkin_assert_moderate;
 use crate::Solver;
 
+/// Error which indicates that a term with a negative scale or offset was added to a [`Function`].
+#[derive(Debug, Error, Copy, Clone)]
+#[error("The scale and offset of a term in a function should be non-negative")]
+pub struct NegativeTermError;
+
 /// A struct which represents a weighted linear function over [`Literal`]s, [`DomainId`]s, and a
 /// constant term.
 #[derive(Clone, Default, Debug)]
@@ -19,6 +31,14 @@ pub struct Function {
 }
 
 impl Function {
+    /// Creates a [`Function`] consisting of the provided weighted literals; this is equivalent to
+    /// calling [`Function::add_weighted_literal`] for each of the terms in order.
+    pub fn from_weighted_literals(terms: impl IntoIterator<Item = (Literal, u64)>) -> Function {
+        let mut function = Function::default();
+        function.extend(terms);
+        function
+    }
+
     pub fn add_weighted_literal(&mut self, literal: Literal, weight: u64) {
         // we want to avoid the situation where both polarities of a variable have a weight
         //  in case that happens, we keep a weight for one of the two polarity, and factor in the
@@ -48,14 +68,57 @@ impl Function {
         }
     }
 
-    pub fn add_weighted_integer(&mut self, domain_id: DomainId, weight: u64) {
-        *self.weighted_integers.entry(domain_id).or_insert(0) += weight;
+    /// Adds the term `weight * variable` to the function, where the variable is either a
+    /// [`DomainId`] or an [`AffineView`] over a [`DomainId`].
+    ///
+    /// The scale and offset of an [`AffineView`] are folded into the weight of the underlying
+    /// [`DomainId`] and the constant term respectively; since the function only has non-negative
+    /// weights, a [`NegativeTermError`] is returned (and the function is left unchanged) if the
+    /// scale or the offset is negative.
+    pub fn add_weighted_integer(
+        &mut self,
+        variable: impl Into<AffineView<DomainId>>,
+        weight: u64,
+    ) -> Result<(), NegativeTermError> {
+        let (domain_id, scale, offset) = variable.into().into_parts();
+        if scale < 0 || offset < 0 {
+            return Err(NegativeTermError);
+        }
+
+        if scale > 0 {
+            *self.weighted_integers.entry(domain_id).or_insert(0) += weight * scale as u64;
+        }
+        self.constant_term += weight * offset as u64;
+
+        Ok(())
     }
 
     pub fn add_constant_term(&mut self, value: u64) {
         self.constant_term += value;
     }
 
+    /// Returns the [`Function`] in which every weight and the constant term are multiplied by
+    /// `factor`.
+    pub fn scale(&self, factor: u64) -> Function {
+        if factor == 0 {
+            return Function::default();
+        }
+
+        Function {
+            weighted_literals: self
+                .weighted_literals
+                .iter()
+                .map(|(&literal, &weight)| (literal, weight * factor))
+                .collect(),
+            weighted_integers: self
+                .weighted_integers
+                .iter()
+                .map(|(&domain_id, &weight)| (domain_id, weight * factor))
+                .collect(),
+            constant_term: self.constant_term * factor,
+        }
+    }
+
     pub fn get_weighted_literals(&self) -> std::collections::hash_map::Iter<Literal, u64> {
         self.weighted_literals.iter()
     }
@@ -108,6 +171,40 @@ impl Function {
         value
     }
 
+    /// Returns the lower bound of the function over the current (partial) assignment; every
+    /// integer term contributes its weight times its lower bound, and every weighted literal which
+    /// is assigned true contributes its weight.
+    pub(crate) fn lower_bound(&self, domains: &impl ReadDomains) -> i64 {
+        let literal_contribution = self
+            .get_weighted_literals()
+            .filter(|(&literal, _)| domains.is_literal_true(literal))
+            .map(|(_, &weight)| weight as i64)
+            .sum::<i64>();
+        let integer_contribution = self
+            .get_weighted_integers()
+            .map(|(domain_id, &weight)| weight as i64 * domains.lower_bound(domain_id) as i64)
+            .sum::<i64>();
+
+        self.constant_term as i64 + literal_contribution + integer_contribution
+    }
+
+    /// Returns the upper bound of the function over the current (partial) assignment; every
+    /// integer term contributes its weight times its upper bound, and every weighted literal which
+    /// is not assigned false contributes its weight.
+    pub(crate) fn upper_bound(&self, domains: &impl ReadDomains) -> i64 {
+        let literal_contribution = self
+            .get_weighted_literals()
+            .filter(|(&literal, _)| !domains.is_literal_false(literal))
+            .map(|(_, &weight)| weight as i64)
+            .sum::<i64>();
+        let integer_contribution = self
+            .get_weighted_integers()
+            .map(|(domain_id, &weight)| weight as i64 * domains.upper_bound(domain_id) as i64)
+            .sum::<i64>();
+
+        self.constant_term as i64 + literal_contribution + integer_contribution
+    }
+
     pub fn get_function_as_weighted_literals_vector(
         &self,
         solver: &Solver,
@@ -153,3 +250,192 @@ impl Function {
         weighted_literals
     }
 }
+
+impl Extend<(Literal, u64)> for Function {
+    /// Adds the provided weighted literals to the function; opposite polarities of the same
+    /// variable are cancelled into the constant term as in [`Function::add_weighted_literal`].
+    fn extend<T: IntoIterator<Item = (Literal, u64)>>(&mut self, terms: T) {
+        terms
+            .into_iter()
+            .for_each(|(literal, weight)| self.add_weighted_literal(literal, weight));
+    }
+}
+
+impl AddAssign for Function {
+    /// Adds the terms of `rhs` to the function; opposite polarities of the same variable are
+    /// cancelled into the constant term as in [`Function::add_weighted_literal`].
+    fn add_assign(&mut self, rhs: Function) {
+        self.extend(rhs.weighted_literals);
+        for (domain_id, weight) in rhs.weighted_integers {
+            *self.weighted_integers.entry(domain_id).or_insert(0) += weight;
+        }
+        self.constant_term += rhs.constant_term;
+    }
+}
+
+impl Add for Function {
+    type Output = Function;
+
+    fn add(mut self, rhs: Function) -> Function {
+        self += rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Function;
+    use crate::engine::variables::DomainId;
+    use crate::engine::variables::Literal;
+    use crate::engine::variables::PropositionalVariable;
+    use crate::engine::variables::TransformableVariable;
+
+    fn literals(index: u32) -> (Literal, Literal) {
+        let variable = PropositionalVariable::new(index);
+        (Literal::new(variable, true), Literal::new(variable, false))
+    }
+
+    #[test]
+    fn opposite_polarities_are_cancelled_into_the_constant_term() {
+        let (a, not_a) = literals(1);
+        let (b, not_b) = literals(2);
+        let (c, not_c) = literals(3);
+
+        let function = Function::from_weighted_literals([
+            (a, 5),
+            (not_a, 3),
+            (b, 2),
+            (not_b, 2),
+            (c, 1),
+            (not_c, 4),
+            (c, 6),
+        ]);
+
+        // a: 5 - 3 with 3 cancelled; b: fully cancelled; c: 4 - 1 on ~c, then 6 - 3 on c
+        assert_eq!(3 + 2 + 1 + 3, function.get_constant_term());
+        let mut weighted_literals = function
+            .get_weighted_literals()
+            .map(|(&literal, &weight)| (literal, weight))
+            .collect::<Vec<_>>();
+        weighted_literals.sort_by_key(|(literal, _)| literal.to_u32());
+        assert_eq!(vec![(a, 2), (c, 3)], weighted_literals);
+    }
+
+    #[test]
+    fn adding_functions_merges_opposite_polarities() {
+        let (a, not_a) = literals(1);
+        let (b, not_b) = literals(2);
+        let (c, _) = literals(3);
+
+        let mut first = Function::from_weighted_literals([(a, 5), (not_b, 2), (c, 1)]);
+        first.add_constant_term(3);
+        let mut second = Function::from_weighted_literals([(not_a, 2), (b, 2), (c, 4)]);
+        second.add_constant_term(1);
+
+        let sum = first + second;
+
+        // a: 5 - 2 with 2 cancelled; b: fully cancelled; c: 1 + 4
+        assert_eq!(3 + 1 + 2 + 2, sum.get_constant_term());
+        let mut weighted_literals = sum
+            .get_weighted_literals()
+            .map(|(&literal, &weight)| (literal, weight))
+            .collect::<Vec<_>>();
+        weighted_literals.sort_by_key(|(literal, _)| literal.to_u32());
+        assert_eq!(vec![(a, 3), (c, 5)], weighted_literals);
+    }
+
+    #[test]
+    fn adding_functions_sums_integer_weights() {
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+
+        let mut first = Function::default();
+        first
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        let mut second = Function::default();
+        second
+            .add_weighted_integer(x, 3)
+            .expect("the term is non-negative");
+        second
+            .add_weighted_integer(y, 1)
+            .expect("the term is non-negative");
+
+        let sum = first + second;
+
+        let mut weighted_integers = sum
+            .get_weighted_integers()
+            .map(|(&domain_id, &weight)| (domain_id, weight))
+            .collect::<Vec<_>>();
+        weighted_integers.sort_by_key(|(domain_id, _)| domain_id.id);
+        assert_eq!(vec![(x, 5), (y, 1)], weighted_integers);
+    }
+
+    #[test]
+    fn scaling_multiplies_every_weight_and_the_constant_term() {
+        let (a, _) = literals(1);
+        let x = DomainId::new(0);
+
+        let mut function = Function::from_weighted_literals([(a, 2)]);
+        function
+            .add_weighted_integer(x, 3)
+            .expect("the term is non-negative");
+        function.add_constant_term(4);
+
+        let scaled = function.scale(5);
+        assert_eq!(20, scaled.get_constant_term());
+        assert_eq!(
+            vec![(&a, &10)],
+            scaled.get_weighted_literals().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(&x, &15)],
+            scaled.get_weighted_integers().collect::<Vec<_>>()
+        );
+
+        assert!(function.scale(0).is_empty());
+    }
+
+    #[test]
+    fn extend_matches_repeated_additions() {
+        let (a, not_a) = literals(1);
+        let (b, not_b) = literals(2);
+        let terms = [(a, 4), (not_b, 7), (not_a, 9), (b, 1), (a, 2), (not_b, 3)];
+
+        let mut extended = Function::default();
+        extended.add_constant_term(10);
+        extended.extend(terms);
+
+        let mut added = Function::default();
+        added.add_constant_term(10);
+        terms
+            .iter()
+            .for_each(|&(literal, weight)| added.add_weighted_literal(literal, weight));
+
+        assert_eq!(added.get_constant_term(), extended.get_constant_term());
+        let mut extended_literals = extended.get_weighted_literals().collect::<Vec<_>>();
+        let mut added_literals = added.get_weighted_literals().collect::<Vec<_>>();
+        extended_literals.sort_by_key(|(literal, _)| literal.to_u32());
+        added_literals.sort_by_key(|(literal, _)| literal.to_u32());
+        assert_eq!(added_literals, extended_literals);
+    }
+
+    #[test]
+    fn terms_with_a_negative_scale_or_offset_are_rejected() {
+        let x = DomainId::new(0);
+
+        let mut function = Function::default();
+        assert!(function.add_weighted_integer(x.scaled(-1), 2).is_err());
+        assert!(function.add_weighted_integer(x.offset(-1), 2).is_err());
+        assert!(function.is_empty());
+
+        function
+            .add_weighted_integer(x.scaled(3).offset(1), 2)
+            .expect("the term is non-negative");
+        assert_eq!(2, function.get_constant_term());
+        assert_eq!(
+            vec![(&x, &6)],
+            function.get_weighted_integers().collect::<Vec<_>>()
+        );
+    }
+}
@@ -23,11 +23,12 @@ pub use constraint_operation_error::ConstraintOperationError;
 pub(crate) use constraint_reference::ConstraintReference;
 pub(crate) use csp_solver_execution_flag::CSPSolverExecutionFlag;
 pub use function::Function;
+pub use function::NegativeTermError;
 pub(crate) use hash_structures::*;
 pub(crate) use key_value_heap::KeyValueHeap;
 pub use keyed_vec::*;
-pub(crate) use propagation_status_cp::Inconsistency;
-pub(crate) use propagation_status_cp::PropagationStatusCP;
+pub use propagation_status_cp::Inconsistency;
+pub use propagation_status_cp::PropagationStatusCP;
 pub(crate) use propagation_status_cp_one_step::PropagationStatusOneStepCP;
 pub use propositional_conjunction::PropositionalConjunction;
 pub use random::*;
@@ -31,6 +31,10 @@ impl MovingAverage for CumulativeMovingAverage {
     fn adapt(&mut self, _interval_length: u64) {
         // do nothing
     }
+
+    fn clone_boxed(&self) -> Box<dyn MovingAverage> {
+        Box::new(*self)
+    }
 }
 
 #[cfg(test)]
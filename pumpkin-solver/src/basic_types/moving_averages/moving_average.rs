@@ -10,4 +10,13 @@ pub(crate) trait MovingAverage: Debug {
     /// makes sense for moving averages that consider the k previous points, e.g., windowed moving
     /// average
     fn adapt(&mut self, interval_length: u64);
+
+    /// Returns a boxed copy of the moving average containing the same terms.
+    fn clone_boxed(&self) -> Box<dyn MovingAverage>;
+}
+
+impl Clone for Box<dyn MovingAverage> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
 }
@@ -66,6 +66,10 @@ impl MovingAverage for WindowedMovingAverage {
             std::cmp::Ordering::Equal => { /*do nothing*/ }
         }
     }
+
+    fn clone_boxed(&self) -> Box<dyn MovingAverage> {
+        Box::new(self.clone())
+    }
 }
 
 #[cfg(test)]
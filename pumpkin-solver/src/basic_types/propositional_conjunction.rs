@@ -16,19 +16,39 @@ impl PropositionalConjunction {
         }
     }
 
-    pub fn contains(&self, predicate: Predicate) -> bool {
-        self.predicates_in_conjunction.contains(&predicate)
+    /// Returns whether the provided [`Predicate`] is part of the conjunction.
+    pub fn contains(&self, predicate: &Predicate) -> bool {
+        self.predicates_in_conjunction.contains(predicate)
+    }
+
+    /// Removes the duplicate [`Predicate`]s from the conjunction in place; only the first
+    /// occurrence of every predicate is kept.
+    pub fn dedup(&mut self) {
+        let predicates = std::mem::take(&mut self.predicates_in_conjunction);
+        self.predicates_in_conjunction = predicates.into_iter().unique().collect();
     }
 
     pub fn num_predicates(&self) -> u32 {
         self.predicates_in_conjunction.len() as u32
     }
 
+    /// Returns the number of [`Predicate`]s in the conjunction.
+    pub fn len(&self) -> usize {
+        self.predicates_in_conjunction.len()
+    }
+
+    /// Returns whether the conjunction does not contain any [`Predicate`]s; the empty conjunction
+    /// is trivially true.
+    pub fn is_empty(&self) -> bool {
+        self.predicates_in_conjunction.is_empty()
+    }
+
     pub fn add(&mut self, predicate: Predicate) {
         self.predicates_in_conjunction.push(predicate);
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &Predicate> + '_ {
+    /// Returns an iterator over the [`Predicate`]s in the conjunction.
+    pub fn iter(&self) -> std::slice::Iter<'_, Predicate> {
         self.predicates_in_conjunction.iter()
     }
 
@@ -56,6 +76,16 @@ impl IntoIterator for PropositionalConjunction {
     }
 }
 
+impl<'a> IntoIterator for &'a PropositionalConjunction {
+    type Item = &'a Predicate;
+
+    type IntoIter = std::slice::Iter<'a, Predicate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl FromIterator<Predicate> for PropositionalConjunction {
     fn from_iter<T: IntoIterator<Item = Predicate>>(iter: T) -> Self {
         let vec = iter.into_iter().collect();
@@ -188,6 +218,61 @@ mod tests {
         assert_eq!(conjunction!([x >= 5] & [y == 1]), conjunction);
     }
 
+    #[test]
+    fn contains_only_predicates_in_conjunction() {
+        let x = DomainId { id: 0 };
+        let y = DomainId { id: 1 };
+
+        let conjunction = conjunction!([x >= 5] & [y <= 7]);
+
+        assert!(conjunction.contains(&predicate![x >= 5]));
+        assert!(conjunction.contains(&predicate![y <= 7]));
+        assert!(!conjunction.contains(&predicate![x >= 6]));
+        assert!(!conjunction.contains(&predicate![y >= 5]));
+    }
+
+    #[test]
+    fn dedup_removes_non_adjacent_duplicates() {
+        let x = DomainId { id: 0 };
+        let y = DomainId { id: 1 };
+
+        let mut conjunction = conjunction!([x >= 5] & [y <= 7] & [x >= 5] & [y <= 7] & [x >= 5]);
+        conjunction.dedup();
+
+        assert_eq!(2, conjunction.num_predicates());
+        assert_eq!(conjunction!([x >= 5] & [y <= 7]), conjunction);
+    }
+
+    #[test]
+    fn length_counts_all_predicates() {
+        let x = DomainId { id: 0 };
+        let y = DomainId { id: 1 };
+
+        assert!(conjunction!().is_empty());
+        assert_eq!(0, conjunction!().len());
+
+        let conjunction = conjunction!([x >= 5] & [y <= 7] & [x != 6]);
+        assert!(!conjunction.is_empty());
+        assert_eq!(3, conjunction.len());
+    }
+
+    #[test]
+    fn iteration_by_reference_yields_predicates_in_order() {
+        let x = DomainId { id: 0 };
+        let y = DomainId { id: 1 };
+
+        let conjunction =
+            PropositionalConjunction::from(vec![predicate![x >= 5], predicate![y <= 7]]);
+
+        let mut predicates = vec![];
+        for predicate in &conjunction {
+            predicates.push(*predicate);
+        }
+
+        assert_eq!(vec![predicate![x >= 5], predicate![y <= 7]], predicates);
+        assert_eq!(predicates, conjunction.iter().copied().collect::<Vec<_>>());
+    }
+
     #[test]
     fn nested_path_is_forwarded_to_predicate() {
         struct Wrapper {
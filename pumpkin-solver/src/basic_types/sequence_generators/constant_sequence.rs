@@ -16,6 +16,10 @@ impl SequenceGenerator for ConstantSequence {
     fn next(&mut self) -> i64 {
         self.constant_value
     }
+
+    fn clone_boxed(&self) -> Box<dyn SequenceGenerator> {
+        Box::new(*self)
+    }
 }
 
 #[cfg(test)]
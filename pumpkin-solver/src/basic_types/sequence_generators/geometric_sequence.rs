@@ -28,6 +28,10 @@ impl SequenceGenerator for GeometricSequence {
         self.current_value = (self.current_value as f64 * self.multiplication_factor) as i64;
         next_value
     }
+
+    fn clone_boxed(&self) -> Box<dyn SequenceGenerator> {
+        Box::new(*self)
+    }
 }
 
 #[cfg(test)]
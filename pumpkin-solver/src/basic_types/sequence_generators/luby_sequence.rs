@@ -36,6 +36,10 @@ impl SequenceGenerator for LubySequence {
         }
         next_value * self.base_value
     }
+
+    fn clone_boxed(&self) -> Box<dyn SequenceGenerator> {
+        Box::new(*self)
+    }
 }
 
 #[cfg(test)]
@@ -2,4 +2,13 @@ use std::fmt::Debug;
 
 pub(crate) trait SequenceGenerator: Debug {
     fn next(&mut self) -> i64;
+
+    /// Returns a boxed copy of the generator which continues from the same point in the sequence.
+    fn clone_boxed(&self) -> Box<dyn SequenceGenerator>;
+}
+
+impl Clone for Box<dyn SequenceGenerator> {
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
 }
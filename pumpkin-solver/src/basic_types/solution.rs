@@ -1,10 +1,14 @@
 use crate::engine::propagation::propagation_context::HasAssignments;
+#[cfg(feature = "serde")]
+use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 use crate::engine::AssignmentsInteger;
 use crate::engine::AssignmentsPropositional;
 use crate::pumpkin_assert_moderate;
 use crate::variables::IntegerVariable;
+#[cfg(doc)]
+use crate::Solver;
 
 /// A trait which specifies the common behaviours of [`Solution`] and [`SolutionReference`].
 pub trait ProblemSolution: HasAssignments {
@@ -88,6 +92,8 @@ impl ProblemSolution for SolutionReference<'_> {}
 pub struct Solution {
     assignments_propositional: AssignmentsPropositional,
     assignments_integer: AssignmentsInteger,
+    /// The value of the objective for this solution, if it was found while optimising.
+    objective_value: Option<i64>,
 }
 
 impl Solution {
@@ -98,9 +104,22 @@ impl Solution {
         Self {
             assignments_propositional,
             assignments_integer,
+            objective_value: None,
         }
     }
 
+    /// Returns the value of the objective for this solution if it was found by one of the
+    /// optimisation methods of the [`Solver`] (e.g. [`Solver::minimise`]); otherwise, [`None`] is
+    /// returned.
+    pub fn objective_value(&self) -> Option<i64> {
+        self.objective_value
+    }
+
+    pub(crate) fn with_objective_value(mut self, objective_value: i64) -> Self {
+        self.objective_value = Some(objective_value);
+        self
+    }
+
     pub fn as_reference(&self) -> SolutionReference<'_> {
         SolutionReference {
             assignments_propositional: &self.assignments_propositional,
@@ -111,11 +130,124 @@ impl Solution {
 
 impl ProblemSolution for Solution {}
 
+#[cfg(feature = "serde")]
+impl Solution {
+    /// Serialises the values of the integer variables in the solution to a JSON object which maps
+    /// every name in the `variable_map` to the value assigned to its [`DomainId`].
+    ///
+    /// Since the [`DomainId`]s of a [`Solution`] are internal to the solver which created it, the
+    /// variables are identified by the provided names; the solution can be restored (e.g. in
+    /// another run) using [`Solution::from_json`] with a `variable_map` from the same names to the
+    /// [`DomainId`]s of that solver.
+    pub fn to_json(&self, variable_map: &std::collections::HashMap<String, DomainId>) -> String {
+        let values = variable_map
+            .iter()
+            .map(|(name, &domain_id)| (name, self.get_integer_value(domain_id)))
+            .collect::<std::collections::BTreeMap<_, _>>();
+
+        serde_json::to_string(&values).expect("a map of integers can always be serialised")
+    }
+
+    /// Deserialises a [`Solution`] from a JSON object which maps names to values, such as the
+    /// output of [`Solution::to_json`].
+    ///
+    /// The `variable_map` is used to map the names in the JSON object to the [`DomainId`]s of the
+    /// returned [`Solution`]. An error is returned if a name occurs more than once in the JSON
+    /// object or does not occur in the `variable_map`, if two names of the same [`DomainId`] have
+    /// different values, or if there is a [`DomainId`] (up to the largest one which is mapped to)
+    /// which is not assigned a value.
+    ///
+    /// Note that only the integer variables are restored; the returned [`Solution`] does not
+    /// contain any propositional variables.
+    pub fn from_json(
+        json: &str,
+        variable_map: &std::collections::HashMap<String, DomainId>,
+    ) -> Result<Solution, serde_json::Error> {
+        use serde::de::Error;
+
+        let NamedValues(values) = serde_json::from_str(json)?;
+
+        let mut assigned_values: Vec<Option<i32>> = Vec::new();
+        for (name, value) in values {
+            let domain_id = variable_map.get(&name).ok_or_else(|| {
+                serde_json::Error::custom(format!("unknown variable name '{name}'"))
+            })?;
+
+            let index = domain_id.id as usize;
+            if index >= assigned_values.len() {
+                assigned_values.resize(index + 1, None);
+            }
+            if assigned_values[index].is_some_and(|assigned| assigned != value) {
+                return Err(serde_json::Error::custom(format!(
+                    "conflicting values are provided for {domain_id}"
+                )));
+            }
+            assigned_values[index] = Some(value);
+        }
+
+        let mut assignments_integer = AssignmentsInteger::default();
+        for (index, value) in assigned_values.into_iter().enumerate() {
+            let value = value.ok_or_else(|| {
+                serde_json::Error::custom(format!("no value is provided for x{index}"))
+            })?;
+            let _ = assignments_integer.grow(value, value);
+        }
+
+        Ok(Solution::new(
+            AssignmentsPropositional::default(),
+            assignments_integer,
+        ))
+    }
+}
+
+/// The values of the named variables in the JSON representation of a [`Solution`]; unlike a map
+/// deserialised by `serde_json`, a name which occurs more than once is rejected rather than
+/// overwritten.
+#[cfg(feature = "serde")]
+struct NamedValues(std::collections::BTreeMap<String, i32>);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NamedValues {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NamedValuesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for NamedValuesVisitor {
+            type Value = NamedValues;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map from variable names to integer values")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                let mut values = std::collections::BTreeMap::new();
+                while let Some((name, value)) = map.next_entry::<String, i32>()? {
+                    if values.contains_key(&name) {
+                        return Err(A::Error::custom(format!(
+                            "duplicate variable name '{name}'"
+                        )));
+                    }
+                    let _ = values.insert(name, value);
+                }
+
+                Ok(NamedValues(values))
+            }
+        }
+
+        deserializer.deserialize_map(NamedValuesVisitor)
+    }
+}
+
 impl From<SolutionReference<'_>> for Solution {
     fn from(value: SolutionReference) -> Self {
         Self {
             assignments_propositional: value.assignments_propositional.clone(),
             assignments_integer: value.assignments_integer.clone(),
+            objective_value: None,
         }
     }
 }
@@ -139,3 +271,70 @@ impl HasAssignments for Solution {
         &self.assignments_propositional
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::constraints;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::Solver;
+
+    #[test]
+    fn solution_round_trips_through_json() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(-3, 3);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x, y], -1))
+            .post();
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let SatisfactionResult::Satisfiable(solution) =
+            solver.satisfy(&mut brancher, &mut Indefinite)
+        else {
+            panic!("expected a solution");
+        };
+
+        let json = solution.to_json(&HashMap::from([("x".to_owned(), x), ("y".to_owned(), y)]));
+
+        // The variables are created in a different order in the solver which restores the
+        // solution, so they have different domain ids
+        let mut other_solver = Solver::default();
+        let other_y = other_solver.new_bounded_integer(-3, 3);
+        let other_x = other_solver.new_bounded_integer(0, 5);
+        assert_ne!(x, other_x);
+
+        let deserialised = Solution::from_json(
+            &json,
+            &HashMap::from([("x".to_owned(), other_x), ("y".to_owned(), other_y)]),
+        )
+        .expect("valid JSON solution");
+
+        assert_eq!(
+            solution.get_integer_value(x),
+            deserialised.get_integer_value(other_x)
+        );
+        assert_eq!(
+            solution.get_integer_value(y),
+            deserialised.get_integer_value(other_y)
+        );
+    }
+
+    #[test]
+    fn unknown_variable_names_are_rejected() {
+        let result = Solution::from_json(r#"{"y": 1}"#, &HashMap::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn duplicate_variable_names_are_rejected() {
+        let variable_map = HashMap::from([("x".to_owned(), DomainId::new(0))]);
+
+        let result = Solution::from_json(r#"{"x": 1, "x": 2}"#, &variable_map);
+        assert!(result.is_err());
+    }
+}
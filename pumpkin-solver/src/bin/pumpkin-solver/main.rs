@@ -124,6 +124,17 @@ struct Args {
     #[arg(long = "no-learning-minimise", verbatim_doc_comment)]
     no_learning_clause_minimisation: bool,
 
+    /// Decides which learning scheme is used to derive a learned clause from a conflict.
+    /// - "one-uip" learns the first unique implication point (1UIP) clause.
+    /// - "best-of-both" computes both the 1UIP clause and the clause consisting of only decisions,
+    ///   and learns the one with the lowest literal block distance (LBD).
+    /// - "all-decision" learns the clause consisting of only decisions.
+    #[arg(
+        long = "resolution-mode",
+        default_value_t = ResolutionMode::OneUip, verbatim_doc_comment
+    )]
+    resolution_mode: ResolutionMode,
+
     /// Decides the sequence based on which the restarts are performed.
     /// - The "constant" approach uses a constant number of conflicts before another restart is
     ///   triggered
@@ -260,6 +271,13 @@ struct Args {
     #[arg(short = 's', long = "log-statistics", verbatim_doc_comment)]
     log_statistics: bool,
 
+    /// Enables logging of the number of enqueues and propagations of each propagator; this only
+    /// has an effect in combination with the option "--log-statistics".
+    ///
+    /// Possible values: bool
+    #[arg(long = "log-propagator-statistics", verbatim_doc_comment)]
+    log_propagator_statistics: bool,
+
     /// Instructs the solver to perform free search when solving a MiniZinc model; this flag
     /// indicates that it is allowed to ignore the search annotations specified in the model.
     ///
@@ -309,6 +327,29 @@ struct Args {
     )]
     upper_bound_encoding: PseudoBooleanEncoding,
 
+    /// The search strategy which is used to find an optimal solution to a MaxSAT instance.
+    ///
+    /// The "linear" value specifies that the solver should repeatedly search for a solution which
+    /// is better than the best solution found so far, while the "core-guided" value specifies that
+    /// the solver should increase a lower bound using unsatisfiable cores (see "Core-guided MaxSAT
+    /// with soft cardinality constraints - Morgado et al. (2014)").
+    #[arg(
+        long = "maxsat-search",
+        default_value_t = MaxSatSearchStrategy::Linear, verbatim_doc_comment
+    )]
+    maxsat_search: MaxSatSearchStrategy,
+
+    /// The format of the 'v' line which is printed when a solution to a MaxSAT instance is found.
+    ///
+    /// The "literals" value prints the assignment as a list of DIMACS literals (e.g. "v -1 2 -3"),
+    /// while the "bitstring" value prints the value of every variable as a single character (e.g.
+    /// "v 010"), which is the convention of the MaxSAT evaluations since 2022.
+    #[arg(
+        long = "maxsat-solution-format",
+        default_value_t = SolutionFormat::Literals, verbatim_doc_comment
+    )]
+    maxsat_solution_format: SolutionFormat,
+
     /// Determines that the cumulative propagator(s) are allowed to create holes in the domain.
     ///
     /// Possible values: bool
@@ -513,6 +554,9 @@ fn run() -> PumpkinResult<()> {
         },
         proof_log,
         learning_clause_minimisation: !args.no_learning_clause_minimisation,
+        resolution_mode: args.resolution_mode,
+        record_nogood_derivations: false,
+        log_propagator_statistics: args.log_propagator_statistics,
         random_generator: SmallRng::seed_from_u64(args.random_seed),
     };
 
@@ -532,6 +576,8 @@ fn run() -> PumpkinResult<()> {
             time_limit,
             instance_path,
             args.upper_bound_encoding,
+            args.maxsat_search,
+            args.maxsat_solution_format,
         )?,
         FileFormat::FlatZinc => flatzinc::solve(
             Solver::with_options(learning_options, solver_options),
@@ -576,7 +622,12 @@ fn cnf_problem(
             let num_propositional_variables = solution.num_propositional_variables();
             println!(
                 "v {}",
-                stringify_solution(&solution, num_propositional_variables, true)
+                stringify_solution(
+                    &solution,
+                    num_propositional_variables,
+                    SolutionFormat::Literals,
+                    true
+                )
             );
         }
         SatisfactionResult::Unsatisfiable => {
@@ -596,11 +647,28 @@ fn cnf_problem(
     Ok(())
 }
 
+/// Creates the content of the 'v' line for the given `solution` over the DIMACS variables
+/// `1..num_variables`, which correspond one-to-one to the [`PropositionalVariable`]s with the same
+/// index. The `terminate_with_zero` flag only applies to [`SolutionFormat::Literals`].
 fn stringify_solution(
     solution: &Solution,
     num_variables: usize,
+    format: SolutionFormat,
     terminate_with_zero: bool,
 ) -> String {
+    if let SolutionFormat::Bitstring = format {
+        return (1..num_variables)
+            .map(|index| PropositionalVariable::new(index.try_into().unwrap()))
+            .map(|var| {
+                if solution.get_propositional_variable_value(var) {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+    }
+
     (1..num_variables)
         .map(|index| PropositionalVariable::new(index.try_into().unwrap()))
         .map(|var| {
@@ -618,6 +686,40 @@ fn stringify_solution(
         .collect::<String>()
 }
 
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum SolutionFormat {
+    /// A list of DIMACS literals, e.g. "-1 2 -3".
+    Literals,
+    /// The value of every variable as a '0' or a '1', e.g. "010".
+    Bitstring,
+}
+
+impl Display for SolutionFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionFormat::Literals => write!(f, "literals"),
+            SolutionFormat::Bitstring => write!(f, "bitstring"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum MaxSatSearchStrategy {
+    /// Linear search over the upper bound of the objective.
+    Linear,
+    /// Core-guided search over the lower bound of the objective.
+    CoreGuided,
+}
+
+impl Display for MaxSatSearchStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxSatSearchStrategy::Linear => write!(f, "linear"),
+            MaxSatSearchStrategy::CoreGuided => write!(f, "core-guided"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, ValueEnum)]
 enum ProofType {
     /// Log only the proof scaffold.
@@ -637,3 +739,50 @@ impl Display for ProofType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_solver::termination::Indefinite;
+
+    use super::*;
+
+    /// Solves the formula `x1 /\ !x2 /\ x3`, where `xi` is the DIMACS variable `i`.
+    fn solve_small_model() -> (Solution, usize) {
+        let mut solver = Solver::default();
+        let literals = (0..3).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+        let _ = solver.add_clause([literals[0]]);
+        let _ = solver.add_clause([!literals[1]]);
+        let _ = solver.add_clause([literals[2]]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => (solution, literals.len() + 1),
+            _ => panic!("the model is satisfiable"),
+        }
+    }
+
+    #[test]
+    fn solution_is_stringified_as_literals() {
+        let (solution, num_variables) = solve_small_model();
+
+        assert_eq!(
+            "1 -2 3 0",
+            stringify_solution(&solution, num_variables, SolutionFormat::Literals, true)
+        );
+        assert_eq!(
+            "1 -2 3 ",
+            stringify_solution(&solution, num_variables, SolutionFormat::Literals, false)
+        );
+    }
+
+    #[test]
+    fn solution_is_stringified_as_bitstring() {
+        let (solution, num_variables) = solve_small_model();
+
+        assert_eq!(
+            "101",
+            stringify_solution(&solution, num_variables, SolutionFormat::Bitstring, false)
+        );
+    }
+}
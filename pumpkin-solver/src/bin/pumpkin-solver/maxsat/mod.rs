@@ -3,8 +3,12 @@ use std::path::Path;
 use std::time::Duration;
 pub(crate) mod optimisation;
 
+use log::debug;
+
+use optimisation::core_guided_search::CoreGuidedSearch;
 use optimisation::linear_search::LinearSearch;
 use optimisation::optimisation_result::MaxSatOptimisationResult;
+use optimisation::optimisation_solver::MaxSatSearch;
 use optimisation::optimisation_solver::OptimisationSolver;
 use pumpkin_solver::encodings::PseudoBooleanEncoding;
 use pumpkin_solver::options::LearningOptions;
@@ -17,6 +21,8 @@ use crate::parsers::dimacs::SolverDimacsSink;
 use crate::parsers::dimacs::WcnfInstance;
 use crate::result::PumpkinError;
 use crate::stringify_solution;
+use crate::MaxSatSearchStrategy;
+use crate::SolutionFormat;
 
 pub(crate) fn wcnf_problem(
     learning_options: LearningOptions,
@@ -24,6 +30,8 @@ pub(crate) fn wcnf_problem(
     time_limit: Option<Duration>,
     instance_path: impl AsRef<Path>,
     upper_bound_encoding: PseudoBooleanEncoding,
+    search_strategy: MaxSatSearchStrategy,
+    solution_format: SolutionFormat,
 ) -> Result<(), PumpkinError> {
     let instance_file = File::open(instance_path)?;
     let WcnfInstance {
@@ -37,27 +45,45 @@ pub(crate) fn wcnf_problem(
 
     let brancher = solver.default_brancher_over_all_propositional_variables();
 
-    let mut solver = OptimisationSolver::new(
-        solver,
-        objective_function,
-        LinearSearch::new(upper_bound_encoding),
-    );
+    let search = match search_strategy {
+        MaxSatSearchStrategy::Linear => {
+            MaxSatSearch::Linear(LinearSearch::new(upper_bound_encoding))
+        }
+        MaxSatSearchStrategy::CoreGuided => MaxSatSearch::CoreGuided(CoreGuidedSearch::new()),
+    };
+
+    let mut solver = OptimisationSolver::new(solver, objective_function, search);
 
     let mut termination = time_limit.map(TimeBudget::starting_now);
 
     match solver.solve(&mut termination, brancher) {
-        MaxSatOptimisationResult::Optimal { solution } => {
+        MaxSatOptimisationResult::Optimal { solution, cost } => {
+            debug!("Found an optimal solution with cost {cost}");
             println!("s OPTIMUM FOUND");
             println!(
                 "v {}",
-                stringify_solution(&solution, last_instance_variable + 1, false)
+                stringify_solution(
+                    &solution,
+                    last_instance_variable + 1,
+                    solution_format,
+                    false
+                )
             );
         }
-        MaxSatOptimisationResult::Satisfiable { best_solution } => {
+        MaxSatOptimisationResult::Satisfiable {
+            best_solution,
+            cost,
+        } => {
+            debug!("The best solution which was found has cost {cost}");
             println!("s SATISFIABLE");
             println!(
                 "v {}",
-                stringify_solution(&best_solution, last_instance_variable + 1, false)
+                stringify_solution(
+                    &best_solution,
+                    last_instance_variable + 1,
+                    solution_format,
+                    false
+                )
             );
         }
         MaxSatOptimisationResult::Infeasible => {
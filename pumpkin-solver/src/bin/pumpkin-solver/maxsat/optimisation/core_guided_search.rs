@@ -0,0 +1,214 @@
+use log::info;
+use pumpkin_solver::asserts::pumpkin_assert_moderate;
+use pumpkin_solver::asserts::pumpkin_assert_simple;
+use pumpkin_solver::branching::Brancher;
+use pumpkin_solver::encodings::Function;
+use pumpkin_solver::results::SatisfactionResultUnderAssumptions;
+use pumpkin_solver::results::Solution;
+use pumpkin_solver::termination::TerminationCondition;
+use pumpkin_solver::variables::Literal;
+use pumpkin_solver::Solver;
+
+use super::optimisation_result::MaxSatOptimisationResult;
+use super::stopwatch::Stopwatch;
+
+/// A core-guided search which increases a lower bound on the objective until it meets the
+/// objective value of a solution, based on the OLL algorithm \[1\].
+///
+/// In every iteration, the solver is called under the assumptions that none of the terms of the
+/// (reformulated) objective incur a cost. If this is unsatisfiable, then the extracted core states
+/// that at least one of the terms in it incurs a cost; the minimum weight in the core is added to
+/// the lower bound and the core is relaxed by replacing its terms with the outputs of a
+/// cardinality constraint over them. Otherwise, the found solution is optimal.
+///
+/// # Bibliography
+/// \[1\] A. Morgado, C. Dodaro, and J. Marques-Silva, ‘Core-guided MaxSAT with soft cardinality
+/// constraints’, in International Conference on Principles and Practice of Constraint
+/// Programming, 2014, pp. 564–573.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CoreGuidedSearch;
+
+impl CoreGuidedSearch {
+    pub(crate) fn new() -> CoreGuidedSearch {
+        CoreGuidedSearch
+    }
+
+    pub(crate) fn solve(
+        &self,
+        solver: &mut Solver,
+        process_time: Stopwatch,
+        objective_function: &Function,
+        termination: &mut impl TerminationCondition,
+        mut brancher: impl Brancher,
+        initial_solution: Solution,
+    ) -> MaxSatOptimisationResult {
+        let mut best_solution: Solution = initial_solution;
+        let mut best_objective_value = objective_function.evaluate_assignment(&best_solution);
+
+        solver.log_statistics_with_objective(best_objective_value as i64);
+        println!("o {}", best_objective_value);
+
+        // The reformulated objective; a literal which is true incurs a cost of its weight
+        let mut weighted_literals = objective_function
+            .get_function_as_weighted_literals_vector(solver)
+            .into_iter()
+            .map(|term| (term.literal, term.weight))
+            .collect::<Vec<_>>();
+        // The integer terms only contain the literals above their lower bound
+        let mut lower_bound = objective_function.get_constant_term()
+            + objective_function
+                .get_weighted_integers()
+                .map(|(domain_id, weight)| weight * solver.lower_bound(domain_id) as u64)
+                .sum::<u64>();
+
+        loop {
+            if lower_bound == best_objective_value {
+                solver.log_statistics_with_objective(best_objective_value as i64);
+                return MaxSatOptimisationResult::Optimal {
+                    solution: best_solution,
+                    cost: best_objective_value,
+                };
+            }
+
+            let assumptions = weighted_literals
+                .iter()
+                .map(|&(literal, _)| !literal)
+                .collect::<Vec<_>>();
+
+            // The result is converted such that the solver is not borrowed anymore
+            let result =
+                match solver.satisfy_under_assumptions(&mut brancher, termination, &assumptions) {
+                    SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                        IterationResult::Solution(solution)
+                    }
+                    SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
+                        mut unsatisfiable,
+                    ) => IterationResult::Core(unsatisfiable.extract_core()),
+                    SatisfactionResultUnderAssumptions::Unsatisfiable => {
+                        IterationResult::Unsatisfiable
+                    }
+                    SatisfactionResultUnderAssumptions::Unknown => IterationResult::Unknown,
+                };
+
+            let core = match result {
+                IterationResult::Solution(solution) => {
+                    let objective_value = objective_function.evaluate_assignment(&solution);
+                    pumpkin_assert_moderate!(
+                        objective_value == lower_bound,
+                        "A solution under the assumptions should have the lower bound as objective value."
+                    );
+
+                    if objective_value < best_objective_value {
+                        best_objective_value = objective_value;
+                        best_solution = solution;
+                        println!("o {}", best_objective_value);
+                    }
+
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    return MaxSatOptimisationResult::Optimal {
+                        solution: best_solution,
+                        cost: best_objective_value,
+                    };
+                }
+                IterationResult::Core(core) => core,
+                IterationResult::Unsatisfiable => {
+                    // The hard constraints are satisfied by the best solution, and relaxing the
+                    // cores does not remove any solutions; this means that the lower bound cannot
+                    // be improved anymore
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    return MaxSatOptimisationResult::Optimal {
+                        solution: best_solution,
+                        cost: best_objective_value,
+                    };
+                }
+                IterationResult::Unknown => {
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    return MaxSatOptimisationResult::Satisfiable {
+                        best_solution,
+                        cost: best_objective_value,
+                    };
+                }
+            };
+
+            // The core is in terms of the assumptions, we are interested in the cost literals
+            let core_literals = core.iter().map(|&literal| !literal).collect::<Vec<_>>();
+            pumpkin_assert_simple!(
+                !core_literals.is_empty(),
+                "An empty core means that the hard constraints are unsatisfiable."
+            );
+
+            let core_weight = weighted_literals
+                .iter()
+                .filter(|(literal, _)| core_literals.contains(literal))
+                .map(|&(_, weight)| weight)
+                .min()
+                .expect("the core is non-empty");
+
+            lower_bound += core_weight;
+            info!(
+                "Lower bound is {} after {} seconds ({} ms)",
+                lower_bound,
+                process_time.elapsed().as_secs(),
+                process_time.elapsed().as_millis(),
+            );
+
+            weighted_literals.iter_mut().for_each(|(literal, weight)| {
+                if core_literals.contains(literal) {
+                    *weight -= core_weight;
+                }
+            });
+            weighted_literals.retain(|&(_, weight)| weight > 0);
+
+            // The first `core_weight` is accounted for in the lower bound; every additional
+            // literal of the core which is true incurs another `core_weight`
+            let outputs = CoreGuidedSearch::encode_at_least_outputs(solver, &core_literals);
+            weighted_literals.extend(
+                outputs
+                    .into_iter()
+                    .skip(1)
+                    .map(|output| (output, core_weight)),
+            );
+        }
+    }
+
+    /// Encodes, using a sequential counter, output literals `o_1, ..., o_n` such that `o_j` is
+    /// implied if at least `j` of the `n` provided literals are true. Note that only this direction
+    /// of the implication is required since the outputs are minimised.
+    ///
+    /// Additionally, since the literals form a core, the clause stating that at least one of them
+    /// is true is added.
+    fn encode_at_least_outputs(solver: &mut Solver, literals: &[Literal]) -> Vec<Literal> {
+        // The clauses added here never conflict with the (root-level) state of the solver since
+        // all of the outputs are fresh literals and the best solution satisfies the core clause
+        let _ = solver.add_clause(literals.iter().copied());
+
+        // `counter[j]` is implied if at least `j + 1` of the literals processed so far are true
+        let mut counter: Vec<Literal> = vec![];
+
+        for &literal in literals {
+            let next_counter = (0..=counter.len())
+                .map(|_| solver.new_literal())
+                .collect::<Vec<_>>();
+
+            let _ = solver.add_clause([!literal, next_counter[0]]);
+            for (index, &previous) in counter.iter().enumerate() {
+                let _ = solver.add_clause([!previous, next_counter[index]]);
+                let _ = solver.add_clause([!previous, !literal, next_counter[index + 1]]);
+            }
+
+            counter = next_counter;
+        }
+
+        counter
+    }
+}
+
+/// The result of a single call to the solver under assumptions.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+enum IterationResult {
+    Solution(Solution),
+    Core(Box<[Literal]>),
+    Unsatisfiable,
+    Unknown,
+}
@@ -55,6 +55,7 @@ impl LinearSearch {
                 solver.log_statistics_with_objective(best_objective_value as i64);
                 return MaxSatOptimisationResult::Optimal {
                     solution: best_solution,
+                    cost: best_objective_value,
                 };
             }
 
@@ -67,6 +68,7 @@ impl LinearSearch {
                 solver.log_statistics_with_objective(best_objective_value as i64);
                 return MaxSatOptimisationResult::Optimal {
                     solution: best_solution,
+                    cost: best_objective_value,
                 };
             }
 
@@ -101,11 +103,15 @@ impl LinearSearch {
 
                     return MaxSatOptimisationResult::Optimal {
                         solution: best_solution,
+                        cost: best_objective_value,
                     };
                 }
                 SatisfactionResult::Unknown => {
                     solver.log_statistics_with_objective(best_objective_value as i64);
-                    return MaxSatOptimisationResult::Satisfiable { best_solution };
+                    return MaxSatOptimisationResult::Satisfiable {
+                        best_solution,
+                        cost: best_objective_value,
+                    };
                 }
             }
         }
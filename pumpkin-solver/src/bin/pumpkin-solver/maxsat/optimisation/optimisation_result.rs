@@ -7,11 +7,19 @@ use super::optimisation_solver::OptimisationSolver;
 #[derive(Debug)]
 pub(crate) enum MaxSatOptimisationResult {
     /// There exists no solution with a better objective value than this one.
-    Optimal { solution: Solution },
+    Optimal {
+        solution: Solution,
+        /// The value of the objective function for the solution.
+        cost: u64,
+    },
     /// The optimal solution was not found within the time budget. However, at least one solution
     /// was found. The provided solution is the solution with the best objective value that was
     /// encountered.
-    Satisfiable { best_solution: Solution },
+    Satisfiable {
+        best_solution: Solution,
+        /// The value of the objective function for the best solution.
+        cost: u64,
+    },
     /// No solutions exist to the constraint satisfaction problem.
     Infeasible,
     /// No solution was found within the time budget.
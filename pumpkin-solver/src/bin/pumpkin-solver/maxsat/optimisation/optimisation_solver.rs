@@ -5,29 +5,38 @@ use pumpkin_solver::results::SatisfactionResult;
 use pumpkin_solver::termination::TerminationCondition;
 use pumpkin_solver::Solver;
 
+use super::core_guided_search::CoreGuidedSearch;
 use super::linear_search::LinearSearch;
 use super::optimisation_result::MaxSatOptimisationResult;
 use super::stopwatch::Stopwatch;
 
+/// The search strategy which is used by the [`OptimisationSolver`] to find an optimal solution
+/// after an initial solution has been found.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum MaxSatSearch {
+    Linear(LinearSearch),
+    CoreGuided(CoreGuidedSearch),
+}
+
 /// Attempt to find optimal solutions to a constraint satisfaction problem with respect to an
 /// objective function.
 #[derive(Debug)]
 pub(crate) struct OptimisationSolver {
     solver: Solver,
     objective_function: Function,
-    linear_search: LinearSearch,
+    search: MaxSatSearch,
 }
 
 impl OptimisationSolver {
     pub(crate) fn new(
         csp_solver: Solver,
         objective_function: Function,
-        linear_search: LinearSearch,
+        search: MaxSatSearch,
     ) -> OptimisationSolver {
         OptimisationSolver {
             solver: csp_solver,
             objective_function,
-            linear_search,
+            search,
         }
     }
 }
@@ -50,14 +59,24 @@ impl OptimisationSolver {
                     process_time.elapsed().as_secs(),
                 );
 
-                self.linear_search.solve(
-                    &mut self.solver,
-                    process_time,
-                    &self.objective_function,
-                    termination,
-                    brancher,
-                    initial_solution,
-                )
+                match self.search {
+                    MaxSatSearch::Linear(linear_search) => linear_search.solve(
+                        &mut self.solver,
+                        process_time,
+                        &self.objective_function,
+                        termination,
+                        brancher,
+                        initial_solution,
+                    ),
+                    MaxSatSearch::CoreGuided(core_guided_search) => core_guided_search.solve(
+                        &mut self.solver,
+                        process_time,
+                        &self.objective_function,
+                        termination,
+                        brancher,
+                        initial_solution,
+                    ),
+                }
             }
             SatisfactionResult::Unsatisfiable => {
                 self.solver.log_statistics();
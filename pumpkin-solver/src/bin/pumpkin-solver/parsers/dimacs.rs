@@ -4,7 +4,8 @@
 //!
 //! To invoke the parser, there are two options:
 //!  - For a CNF file, the [`parse_cnf`] function can be called,
-//!  - For a WCNF file, the [`parse_wcnf`] function can be called.
+//!  - For a WCNF file, the [`parse_wcnf`] function can be called. Both the format with a `p wcnf`
+//!    header and the headerless format (where hard clauses are prefixed with `h`) are supported.
 //!
 //! Both these functions operate on a type that implements the [`DimacsSink`] trait, which is
 //! serves as an interface between the consumer of the parsed contents of the file.
@@ -12,6 +13,7 @@
 //! It should be noted that the parsers should not be used as DIMACS validators. Even though they
 //! should only accept valid DIMACS files, the errors are not extremely detailed. Perhaps this
 //! could change over time, however.
+use std::fmt::Write;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Read;
@@ -83,6 +85,12 @@ pub(crate) enum DimacsParseError {
     #[error("'{0}' is an invalid DIMACS literal")]
     InvalidLiteral(String),
 
+    #[error("'{0}' is an invalid soft clause weight")]
+    InvalidWeight(String),
+
+    #[error("a weighted clause is missing its weight")]
+    MissingWeight,
+
     #[error("the last clause in the source is not terminated with a '0'")]
     UnterminatedClause,
 
@@ -98,6 +106,7 @@ pub(crate) fn parse_cnf<Sink: DimacsSink>(
     let mut parser =
         DimacsParser::<Sink, _, CNFHeader>::new(sink_constructor_args, |sink, clause, _| {
             sink.add_hard_clause(clause);
+            Ok(())
         });
 
     loop {
@@ -123,6 +132,83 @@ pub(crate) struct WcnfInstance<Formula> {
 }
 
 pub(crate) fn parse_wcnf<Sink: DimacsSink>(
+    mut source: impl Read,
+    sink_constructor_args: Sink::ConstructorArgs,
+) -> Result<WcnfInstance<Sink::Formula>, DimacsParseError> {
+    // The format of the file can only be determined once the first line which is not a comment
+    // is encountered, so the source is read in its entirety.
+    let mut contents = String::new();
+    let _ = source.read_to_string(&mut contents)?;
+
+    if has_dimacs_header(&contents) {
+        parse_wcnf_with_header::<Sink>(contents.as_bytes(), sink_constructor_args)
+    } else {
+        let converted = convert_headerless_wcnf(&contents)?;
+        parse_wcnf_with_header::<Sink>(converted.as_bytes(), sink_constructor_args)
+    }
+}
+
+/// Returns whether the first line in `source` which is neither empty nor a comment is a header.
+fn has_dimacs_header(source: &str) -> bool {
+    source
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('c'))
+        .is_some_and(|line| line.starts_with('p'))
+}
+
+/// Converts a WCNF source in the headerless format, where every clause is on its own line and hard
+/// clauses are prefixed with `h`, to the format with a `p wcnf` header. The top weight of the
+/// converted source is one more than the largest soft clause weight.
+fn convert_headerless_wcnf(source: &str) -> Result<String, DimacsParseError> {
+    let mut num_variables = 0;
+    let mut max_soft_weight = 0;
+    let mut clauses = vec![];
+
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('c') {
+            continue;
+        }
+
+        let (weight, literals) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+
+        let weight = if weight == "h" {
+            None
+        } else {
+            let parsed_weight = weight
+                .parse::<i32>()
+                .ok()
+                .filter(|&weight| weight > 0)
+                .ok_or_else(|| DimacsParseError::InvalidWeight(weight.to_owned()))?;
+            max_soft_weight = max_soft_weight.max(parsed_weight);
+
+            Some(parsed_weight)
+        };
+
+        for literal in literals.split_whitespace() {
+            let dimacs_code = literal
+                .parse::<i32>()
+                .map_err(|_| DimacsParseError::InvalidLiteral(literal.to_owned()))?;
+            num_variables = num_variables.max(dimacs_code.unsigned_abs() as usize);
+        }
+
+        clauses.push((weight, literals));
+    }
+
+    let top_weight = max_soft_weight
+        .checked_add(1)
+        .ok_or_else(|| DimacsParseError::InvalidWeight(max_soft_weight.to_string()))?;
+
+    let mut converted = format!("p wcnf {num_variables} {} {top_weight}\n", clauses.len());
+    for (weight, literals) in clauses {
+        writeln!(converted, "{} {literals}", weight.unwrap_or(top_weight))
+            .expect("writing to a string does not fail");
+    }
+
+    Ok(converted)
+}
+
+fn parse_wcnf_with_header<Sink: DimacsSink>(
     source: impl Read,
     sink_constructor_args: Sink::ConstructorArgs,
 ) -> Result<WcnfInstance<Sink::Formula>, DimacsParseError> {
@@ -130,12 +216,19 @@ pub(crate) fn parse_wcnf<Sink: DimacsSink>(
     let mut reader = BufReader::new(source);
     let mut parser =
         DimacsParser::<Sink, _, WCNFHeader>::new(sink_constructor_args, |sink, clause, header| {
-            let weight = clause[0].get() as u64;
+            let (weight, clause) = clause
+                .split_first()
+                .ok_or(DimacsParseError::MissingWeight)?;
+
+            if weight.is_negative() {
+                return Err(DimacsParseError::InvalidWeight(weight.to_string()));
+            }
+            let weight = weight.get() as u64;
 
             if weight == header.top_weight {
-                sink.add_hard_clause(&clause[1..]);
+                sink.add_hard_clause(clause);
             } else {
-                match sink.add_soft_clause(&clause[1..]) {
+                match sink.add_soft_clause(clause) {
                     SoftClauseAddition::RootViolated => {
                         objective_function.add_constant_term(weight)
                     }
@@ -145,6 +238,8 @@ pub(crate) fn parse_wcnf<Sink: DimacsSink>(
                     }
                 }
             }
+
+            Ok(())
         });
 
     loop {
@@ -198,7 +293,7 @@ enum ParseState {
 
 impl<Sink, OnClause, Header> DimacsParser<Sink, OnClause, Header>
 where
-    OnClause: FnMut(&mut Sink, &[NonZeroI32], &Header),
+    OnClause: FnMut(&mut Sink, &[NonZeroI32], &Header) -> Result<(), DimacsParseError>,
     Sink: DimacsSink,
     Header: DimacsHeader,
 {
@@ -370,7 +465,7 @@ where
             .expect("header is set when the sink is created");
 
         self.parsed_clauses += 1;
-        (self.on_clause)(sink, &self.clause, header);
+        (self.on_clause)(sink, &self.clause, header)?;
         self.clause.clear();
 
         Ok(())
@@ -674,6 +769,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn headerless_weighted_maxsat_is_parsed_correctly() {
+        let source = r#"
+            c hard clauses are prefixed with 'h'
+            h  1 -2 0
+            h -1  2 0
+            2 1 0
+            1 2 0
+        "#;
+
+        let (formula, objective) = parse_wcnf_source(source);
+
+        assert_eq!(vec![vec![1, -2], vec![-1, 2], vec![1], vec![2]], formula);
+
+        let objective_literals = objective
+            .get_weighted_literals()
+            .map(|(&lit, &weight)| (lit, weight))
+            .collect::<Vec<_>>();
+
+        assert!(
+            objective_literals.contains(&(Literal::new(PropositionalVariable::new(1), true), 2))
+        );
+        assert!(
+            objective_literals.contains(&(Literal::new(PropositionalVariable::new(2), true), 1))
+        );
+    }
+
+    #[test]
+    fn negative_weight_is_rejected() {
+        let source = "p wcnf 2 2 3\n3 1 -2 0\n-1 2 0\n";
+        let err = get_wcnf_parse_error(source);
+
+        assert!(matches!(err, DimacsParseError::InvalidWeight(weight) if weight == "-1"));
+    }
+
+    #[test]
+    fn malformed_headerless_weight_is_rejected() {
+        let source = "h 1 -2 0\nx 2 0\n";
+        let err = get_wcnf_parse_error(source);
+
+        assert!(matches!(err, DimacsParseError::InvalidWeight(weight) if weight == "x"));
+    }
+
     #[test]
     fn negative_zero_is_an_unexpected_sequence() {
         let source = "p cnf 2 1\n1 -2 -0";
@@ -718,6 +856,13 @@ mod tests {
             .expect("valid dimacs")
     }
 
+    fn get_wcnf_parse_error(source: &str) -> DimacsParseError {
+        match parse_wcnf::<Vec<Vec<i32>>>(source.as_bytes(), ()) {
+            Ok(_) => panic!("expected invalid dimacs"),
+            Err(err) => err,
+        }
+    }
+
     impl DimacsSink for Vec<Vec<i32>> {
         type ConstructorArgs = ();
 
@@ -0,0 +1,328 @@
+//! A [`Brancher`] which implements impact-based search \[1\]; see [`ImpactBasedSearch`] for more
+//! information.
+//!
+//! # Bibliography
+//! \[1\] P. Refalo, ‘Impact-based search strategies for constraint programming’, in International
+//! Conference on Principles and Practice of Constraint Programming, 2004, pp. 557–571.
+
+use log::warn;
+
+use crate::basic_types::HashMap;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::predicate;
+use crate::Solver;
+
+/// A [`Brancher`] which selects decisions based on their estimated *impact*; the impact of an
+/// assignment `[x == v]` is the relative reduction of the search space (i.e. the product of the
+/// domain sizes of the variables) which is caused by making the decision and propagating it. An
+/// impact of 1 indicates that the decision led to a conflict, while an impact of 0 indicates that
+/// the decision did not reduce the search space at all.
+///
+/// The impact of a decision is measured when the next decision is requested (at which point the
+/// previous decision has been propagated) and it is stored as a running average per
+/// variable-value pair. Assignments which have not been measured yet have an estimated impact of
+/// 0, unless the impacts are initialised by probing (see [`ImpactBasedSearch::with_probing`]).
+///
+/// The impact of a variable is the average estimated impact of its measured assignments, which is
+/// updated whenever an assignment of the variable is measured rather than recomputed over the
+/// domain at every decision. The [`Brancher`] selects the unfixed variable with the highest
+/// impact (ties are broken in favour of the variable which was provided first), and then assigns
+/// it to the value with the lowest estimated impact (ties are broken in favour of the smallest
+/// value) since that value is the most likely to lead to a solution.
+#[derive(Debug)]
+pub struct ImpactBasedSearch {
+    /// The variables over which the [`Brancher`] makes decisions.
+    variables: Vec<DomainId>,
+    /// The running average impact (and the number of times it was measured) of every
+    /// variable-value pair which has been measured.
+    impacts: HashMap<(DomainId, i32), (f64, u32)>,
+    /// The sum of the average impacts of the measured values (and the number of measured values)
+    /// of every variable.
+    variable_impacts: HashMap<DomainId, (f64, u32)>,
+    /// The last decision which was made together with the (logarithm of the) size of the search
+    /// space before it was made; its impact is measured when the next decision is requested.
+    last_decision: Option<(DomainId, i32, f64)>,
+}
+
+impl ImpactBasedSearch {
+    pub fn new(variables: &[DomainId]) -> Self {
+        if variables.is_empty() {
+            warn!("The ImpactBasedSearch brancher was not provided with any variables");
+        }
+
+        ImpactBasedSearch {
+            variables: variables.to_vec(),
+            impacts: HashMap::default(),
+            variable_impacts: HashMap::default(),
+            last_decision: None,
+        }
+    }
+
+    /// Creates an [`ImpactBasedSearch`] of which the impacts are initialised by probing \[1\];
+    /// every value in the domain of every variable is assigned at the root level of the `solver`
+    /// and the impact of the resulting propagation is measured.
+    ///
+    /// Note that this requires a round of propagation for every value in the domains of the
+    /// `variables`. The `solver` should be at the root level, which is the case outside of the
+    /// solving methods (e.g. [`Solver::satisfy`]).
+    pub fn with_probing(solver: &mut Solver, variables: &[DomainId]) -> Self {
+        let mut brancher = ImpactBasedSearch::new(variables);
+
+        let satisfaction_solver = solver.get_satisfaction_solver_mut();
+        let log_size_before = brancher.log_search_space_size(|variable| {
+            satisfaction_solver
+                .assignments_integer
+                .get_domain_size(variable)
+        });
+
+        for &variable in variables {
+            let assignments = &satisfaction_solver.assignments_integer;
+            let values = (assignments.get_lower_bound(variable)
+                ..=assignments.get_upper_bound(variable))
+                .filter(|&value| assignments.is_value_in_domain(variable, value))
+                .collect::<Vec<_>>();
+
+            for value in values {
+                let impact = satisfaction_solver
+                    .probe(predicate!(variable == value), |assignments| {
+                        let log_size_after = brancher.log_search_space_size(|variable| {
+                            assignments.get_domain_size(variable)
+                        });
+                        1.0 - (log_size_after - log_size_before).exp()
+                    })
+                    // A value which leads to a conflict has the maximum impact
+                    .unwrap_or(1.0);
+                brancher.update_impact(variable, value, impact);
+            }
+        }
+
+        brancher
+    }
+
+    /// Returns the estimated impact of assigning `variable` to `value`.
+    pub fn get_impact(&self, variable: DomainId, value: i32) -> f64 {
+        self.impacts
+            .get(&(variable, value))
+            .map(|&(average, _)| average)
+            .unwrap_or(0.0)
+    }
+
+    /// Updates the running average of the impact of `[variable == value]` with the newly measured
+    /// `impact`, together with the impact of `variable`.
+    fn update_impact(&mut self, variable: DomainId, value: i32, impact: f64) {
+        let (average, count) = self.impacts.entry((variable, value)).or_insert((0.0, 0));
+        let previous_average = *average;
+        *count += 1;
+        *average += (impact - *average) / *count as f64;
+
+        let (sum, num_values) = self.variable_impacts.entry(variable).or_insert((0.0, 0));
+        if *count == 1 {
+            *num_values += 1;
+        }
+        *sum += *average - previous_average;
+    }
+
+    /// Returns the average estimated impact of the measured values of `variable`.
+    fn variable_impact(&self, variable: DomainId) -> f64 {
+        self.variable_impacts
+            .get(&variable)
+            .map(|&(sum, num_values)| sum / num_values as f64)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the logarithm of the size of the search space spanned by the variables, given the
+    /// number of values in the domain of every variable.
+    fn log_search_space_size(&self, domain_size: impl Fn(DomainId) -> u32) -> f64 {
+        self.variables
+            .iter()
+            .map(|&variable| (domain_size(variable) as f64).ln())
+            .sum()
+    }
+}
+
+impl Brancher for ImpactBasedSearch {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        if let Some((variable, value, log_size_before)) = self.last_decision.take() {
+            let log_size_after =
+                self.log_search_space_size(|variable| context.num_values_in_domain(variable));
+            let impact = 1.0 - (log_size_after - log_size_before).exp();
+            self.update_impact(variable, value, impact);
+        }
+
+        let mut best: Option<(DomainId, f64)> = None;
+        for &variable in self.variables.iter() {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            let impact = self.variable_impact(variable);
+            if best.is_none_or(|(_, best_impact)| impact > best_impact) {
+                best = Some((variable, impact));
+            }
+        }
+
+        let (variable, _) = best?;
+
+        let mut best_value: Option<(i32, f64)> = None;
+        for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+            if !context.contains(variable, value) {
+                continue;
+            }
+
+            let impact = self.get_impact(variable, value);
+            if best_value.is_none_or(|(_, best_impact)| impact < best_impact) {
+                best_value = Some((value, impact));
+            }
+        }
+
+        let (value, _) = best_value.expect("an unfixed variable has a value in its domain");
+        let log_size_before =
+            self.log_search_space_size(|variable| context.num_values_in_domain(variable));
+        self.last_decision = Some((variable, value, log_size_before));
+
+        Some(predicate!(variable == value))
+    }
+
+    fn on_conflict(&mut self) {
+        // A decision which directly leads to a conflict has the maximum impact
+        if let Some((variable, value, _)) = self.last_decision.take() {
+            self.update_impact(variable, value, 1.0);
+        }
+    }
+
+    fn on_restart(&mut self) {
+        self.last_decision = None;
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+    use crate::constraints;
+    use crate::engine::AssignmentsInteger;
+    use crate::engine::AssignmentsPropositional;
+
+    fn decide(
+        brancher: &mut ImpactBasedSearch,
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> Option<Predicate> {
+        let mut test_random = TestRandom::default();
+        let mut context = SelectionContext::new(
+            assignments_integer,
+            assignments_propositional,
+            &mut test_random,
+        );
+        brancher.next_decision(&mut context)
+    }
+
+    #[test]
+    fn impact_of_propagated_decision_is_measured() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 3), (0, 3)]));
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+
+        let mut brancher = ImpactBasedSearch::new(&[x, y]);
+
+        let decision = decide(
+            &mut brancher,
+            &assignments_integer,
+            &assignments_propositional,
+        );
+        assert_eq!(decision, Some(predicate!(x == 0)));
+
+        // The decision fixes x and propagation halves the domain of y
+        let _ = assignments_integer.make_assignment(x, 0, None);
+        let _ = assignments_integer.tighten_upper_bound(y, 1, None);
+
+        let decision = decide(
+            &mut brancher,
+            &assignments_integer,
+            &assignments_propositional,
+        );
+        assert_eq!(decision, Some(predicate!(y == 0)));
+
+        // The search space went from 16 to 2 values
+        assert!((brancher.get_impact(x, 0) - 0.875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn selection_is_biased_towards_high_impact_variables() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 3), (0, 3)]));
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+
+        let mut brancher = ImpactBasedSearch::new(&[x, y]);
+        let root_assignments = assignments_integer.clone();
+
+        let _ = decide(
+            &mut brancher,
+            &assignments_integer,
+            &assignments_propositional,
+        );
+        let _ = assignments_integer.make_assignment(x, 0, None);
+
+        let decision = decide(
+            &mut brancher,
+            &assignments_integer,
+            &assignments_propositional,
+        );
+        assert_eq!(decision, Some(predicate!(y == 0)));
+        // Deciding on y leads to a conflict
+        brancher.on_conflict();
+        brancher.on_restart();
+
+        // Without any impact information x would be selected, but y now has a higher impact
+        let decision = decide(&mut brancher, &root_assignments, &assignments_propositional);
+        assert_eq!(decision, Some(predicate!(y == 1)));
+    }
+
+    #[test]
+    fn probing_initialises_the_impacts() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 3))
+            .post();
+        assert!(result.is_ok());
+
+        let brancher = ImpactBasedSearch::with_probing(&mut solver, &[x, y]);
+
+        // The search space goes from 16 values to 4 values for [x == 0] and to 1 value for
+        // [x == 3]
+        assert!((brancher.get_impact(x, 0) - 0.75).abs() < 1e-9);
+        assert!((brancher.get_impact(x, 3) - 0.9375).abs() < 1e-9);
+        assert_eq!(0, solver.get_satisfaction_solver_mut().get_decision_level());
+        assert_eq!(3, solver.upper_bound(&y));
+    }
+
+    #[test]
+    fn probing_assigns_the_maximum_impact_to_conflicting_values() {
+        let mut solver = Solver::default();
+        let variables = [0; 3].map(|_| solver.new_bounded_integer(0, 1));
+        for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+            let result = solver
+                .add_constraint(constraints::binary_not_equals(variables[i], variables[j]))
+                .post();
+            assert!(result.is_ok());
+        }
+
+        let brancher = ImpactBasedSearch::with_probing(&mut solver, &variables);
+
+        for variable in variables {
+            assert_eq!(1.0, brancher.get_impact(variable, 0));
+            assert_eq!(1.0, brancher.get_impact(variable, 1));
+        }
+    }
+}
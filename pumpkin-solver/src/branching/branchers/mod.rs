@@ -2,6 +2,8 @@
 
 pub mod alternating_brancher;
 pub mod dynamic_brancher;
+pub mod impact_based_search;
 pub mod independent_variable_value_brancher;
+pub mod sequential_brancher;
 #[cfg(doc)]
 use super::Brancher;
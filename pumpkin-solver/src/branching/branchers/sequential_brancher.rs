@@ -0,0 +1,122 @@
+//! A [`Brancher`] which first makes a fixed sequence of decisions before delegating to another
+//! [`Brancher`].
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+
+/// A [`Brancher`] which follows a static prefix of decisions before delegating to an inner
+/// [`Brancher`].
+///
+/// The decisions are provided as [`Predicate`]s (e.g. `[x == 5]` or a [`Literal`]), which are
+/// tried in the provided order; a decision of which the truth value is already known (i.e. it is
+/// entailed or falsified by the current domains) is skipped. Once every decision in the prefix has
+/// been assigned, the inner [`Brancher`] is used.
+///
+/// Note that the inner [`Brancher`] is informed of every event (e.g. conflicts and unassignments)
+/// even when it is not the one making the decisions.
+#[derive(Debug)]
+pub struct SequentialBrancher<InnerBrancher> {
+    /// The decisions which are made before the inner brancher is used.
+    decisions: Box<[Predicate]>,
+    /// The [`Brancher`] which is used once all of the `decisions` have been assigned.
+    inner_brancher: InnerBrancher,
+}
+
+impl<InnerBrancher: Brancher> SequentialBrancher<InnerBrancher> {
+    pub fn new(
+        decisions: impl IntoIterator<Item = Predicate>,
+        inner_brancher: InnerBrancher,
+    ) -> Self {
+        SequentialBrancher {
+            decisions: decisions.into_iter().collect(),
+            inner_brancher,
+        }
+    }
+}
+
+impl<InnerBrancher: Brancher> Brancher for SequentialBrancher<InnerBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.decisions
+            .iter()
+            .find(|&&decision| !context.is_predicate_assigned(decision))
+            .copied()
+            .or_else(|| self.inner_brancher.next_decision(context))
+    }
+
+    fn on_conflict(&mut self) {
+        self.inner_brancher.on_conflict()
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.inner_brancher.on_unassign_literal(literal)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.inner_brancher.on_unassign_integer(variable, value)
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.inner_brancher
+            .on_appearance_in_conflict_literal(literal)
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.inner_brancher
+            .on_appearance_in_conflict_integer(variable)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.inner_brancher.on_solution(solution)
+    }
+
+    fn on_restart(&mut self) {
+        self.inner_brancher.on_restart()
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        // The prefix is static, so only the inner brancher can benefit from a restart
+        self.inner_brancher.is_restart_pointless()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::predicate;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::Solver;
+
+    #[test]
+    fn prefix_decisions_are_made_before_inner_brancher() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 5);
+        let z = solver.new_bounded_integer(0, 5);
+
+        // The decision [z >= 0] is entailed, so it is skipped
+        let mut brancher = SequentialBrancher::new(
+            [predicate![y == 3], predicate![z >= 0], predicate![x <= 1]],
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y, z]), InDomainMin),
+        );
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        assert_eq!(
+            vec![
+                predicate![y == 3],
+                predicate![x <= 1],
+                predicate![x <= 0],
+                predicate![z <= 0],
+            ],
+            solver.last_solution_decision_path()
+        );
+    }
+}
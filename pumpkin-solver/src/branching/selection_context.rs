@@ -1,12 +1,13 @@
 use std::fmt::Debug;
 
+use crate::basic_types::HashMap;
 use crate::basic_types::Random;
 #[cfg(doc)]
 use crate::branching::Brancher;
+use crate::engine::predicates::predicate::Predicate;
 #[cfg(doc)]
 use crate::engine::propagation::PropagationContext;
 use crate::engine::variables::DomainGeneratorIterator;
-#[cfg(doc)]
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::PropositionalVariable;
@@ -22,6 +23,8 @@ pub struct SelectionContext<'a> {
     assignments_integer: &'a AssignmentsInteger,
     assignments_propositional: &'a AssignmentsPropositional,
     random_generator: &'a mut dyn Random,
+    /// The values which the user prefers to assign to the variables (if any).
+    preferred_values: Option<&'a HashMap<DomainId, i32>>,
 }
 
 impl<'a> SelectionContext<'a> {
@@ -34,9 +37,29 @@ impl<'a> SelectionContext<'a> {
             assignments_integer,
             assignments_propositional,
             random_generator: rng,
+            preferred_values: None,
         }
     }
 
+    /// Provides the values which the user prefers to assign to variables; see
+    /// [`SelectionContext::preferred_value`].
+    pub(crate) fn with_preferred_values(
+        mut self,
+        preferred_values: &'a HashMap<DomainId, i32>,
+    ) -> Self {
+        self.preferred_values = Some(preferred_values);
+        self
+    }
+
+    /// Returns the value which the user prefers to assign to the provided [`DomainId`], if such a
+    /// preference has been set (e.g. through [`crate::Solver::set_preferred_value`]).
+    ///
+    /// Note that the preferred value is not necessarily in the domain of the variable.
+    pub fn preferred_value(&self, variable: DomainId) -> Option<i32> {
+        self.preferred_values
+            .and_then(|preferred_values| preferred_values.get(&variable).copied())
+    }
+
     /// Returns a random generator which can be used to generate random values (see [`Random`] for
     /// more information).
     pub fn random(&mut self) -> &mut dyn Random {
@@ -50,6 +73,12 @@ impl<'a> SelectionContext<'a> {
         var.upper_bound(self.assignments_integer) - var.lower_bound(self.assignments_integer)
     }
 
+    /// Returns the number of values in the domain of the provided [`DomainId`]; as opposed to
+    /// [`SelectionContext::get_size_of_domain`], this takes holes in the domain into account.
+    pub fn num_values_in_domain(&self, domain_id: DomainId) -> u32 {
+        self.assignments_integer.get_domain_size(domain_id)
+    }
+
     /// Returns the lower bound of the provided [`IntegerVariable`]
     pub fn lower_bound<Var: IntegerVariable>(&self, var: Var) -> i32 {
         var.lower_bound(self.assignments_integer)
@@ -71,6 +100,24 @@ impl<'a> SelectionContext<'a> {
         self.lower_bound(var.clone()) == self.upper_bound(var)
     }
 
+    /// Determines whether the truth value of the provided [`Predicate`] is known (i.e. whether it
+    /// is either entailed or falsified by the current domains).
+    pub fn is_predicate_assigned(&self, predicate: Predicate) -> bool {
+        match predicate {
+            Predicate::IntegerPredicate(integer_predicate) => {
+                self.assignments_integer
+                    .does_integer_predicate_hold(integer_predicate)
+                    || self
+                        .assignments_integer
+                        .does_integer_predicate_hold(!integer_predicate)
+            }
+            Predicate::Literal(literal) => {
+                self.assignments_propositional.is_literal_assigned(literal)
+            }
+            Predicate::False | Predicate::True => true,
+        }
+    }
+
     /// Determines whether the provided [`PropositionalVariable`] is assigned.
     pub fn is_propositional_variable_fixed(&self, var: PropositionalVariable) -> bool {
         self.assignments_propositional.is_variable_assigned(var)
@@ -90,4 +90,23 @@ mod tests {
         let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
         assert_eq!(selected_predicate, predicate!(domain_ids[0] == 6))
     }
+
+    #[test]
+    fn test_returns_upper_median_of_even_domain() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(1, 10)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainMedian;
+
+        // The domain {1, ..., 10} has two medians; the larger of the two is selected
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] == 6))
+    }
 }
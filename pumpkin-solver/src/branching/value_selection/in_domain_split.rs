@@ -70,6 +70,25 @@ mod tests {
         assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 5))
     }
 
+    #[test]
+    fn test_large_domain_is_split_near_the_middle() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 100)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = InDomainSplit;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 50))
+    }
+
     #[test]
     fn test_domain_of_size_two() {
         let (assignments_integer, assignments_propositional) =
@@ -0,0 +1,149 @@
+use super::ValueSelector;
+use crate::basic_types::KeyedVec;
+use crate::basic_types::SolutionReference;
+#[cfg(doc)]
+use crate::branching::PhaseSaving;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::predicate;
+
+/// A [`ValueSelector`] which implements phase-saving for integer variables (see [`PhaseSaving`]
+/// for the propositional equivalent).
+///
+/// Whenever a variable is unassigned during backtracking, the value to which it was fixed is
+/// saved; when the variable is selected again, it is assigned to this saved value if it is still
+/// in its domain. If no value has been saved for the variable (or the saved value has been
+/// removed from its domain), then the decision is made by the provided `fallback`
+/// [`ValueSelector`].
+#[derive(Debug, Clone)]
+pub struct IntegerPhaseSaving<ValueSelection> {
+    /// The value to which each variable was last fixed (if any).
+    saved_values: KeyedVec<DomainId, Option<i32>>,
+    fallback: ValueSelection,
+}
+
+impl<ValueSelection> IntegerPhaseSaving<ValueSelection> {
+    pub fn new(fallback: ValueSelection) -> Self {
+        IntegerPhaseSaving {
+            saved_values: KeyedVec::default(),
+            fallback,
+        }
+    }
+}
+
+impl<ValueSelection: ValueSelector<DomainId>> ValueSelector<DomainId>
+    for IntegerPhaseSaving<ValueSelection>
+{
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: DomainId,
+    ) -> Predicate {
+        self.saved_values.accomodate(decision_variable, None);
+
+        match self.saved_values[decision_variable] {
+            Some(value) if context.contains(decision_variable, value) => {
+                predicate!(decision_variable == value)
+            }
+            _ => self.fallback.select_value(context, decision_variable),
+        }
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.fallback.on_unassign_literal(literal)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.saved_values.accomodate(variable, None);
+        self.saved_values[variable] = Some(value);
+
+        self.fallback.on_unassign_integer(variable, value)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.fallback.on_solution(solution)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::InDomainMin;
+    use crate::branching::IntegerPhaseSaving;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn fallback_is_used_for_unseen_variables() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let x = assignments_integer.get_domains().next().unwrap();
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let mut selector = IntegerPhaseSaving::new(InDomainMin);
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x <= 0));
+    }
+
+    #[test]
+    fn prior_assignment_is_reused_after_backtracking() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let domain_ids = assignments_integer.get_domains().collect::<Vec<_>>();
+        let x = domain_ids[0];
+        let y = domain_ids[1];
+
+        let mut selector = IntegerPhaseSaving::new(InDomainMin);
+
+        // Backtracking unassigns `x`, which was previously fixed to 7
+        selector.on_unassign_integer(x, 7);
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x == 7));
+
+        let selected_predicate = selector.select_value(&mut context, y);
+        assert_eq!(selected_predicate, predicate!(y <= 0));
+    }
+
+    #[test]
+    fn fallback_is_used_if_saved_value_is_not_in_domain() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let x = assignments_integer.get_domains().next().unwrap();
+
+        let mut selector = IntegerPhaseSaving::new(InDomainMin);
+        selector.on_unassign_integer(x, 7);
+
+        let _ = assignments_integer.remove_value_from_domain(x, 7, None);
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x <= 0));
+    }
+}
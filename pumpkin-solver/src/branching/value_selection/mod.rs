@@ -15,12 +15,15 @@ mod in_domain_min;
 mod in_domain_random;
 mod in_domain_split;
 mod in_domain_split_random;
+mod integer_phase_saving;
 mod out_domain_max;
 mod out_domain_median;
 mod out_domain_min;
 mod out_domain_random;
 mod phase_saving;
+mod preferred_value_selector;
 mod reverse_in_domain_split;
+mod seeded_in_domain_random;
 mod solution_guided_value_selector;
 mod value_selector;
 
@@ -33,11 +36,14 @@ pub use in_domain_min::*;
 pub use in_domain_random::*;
 pub use in_domain_split::*;
 pub use in_domain_split_random::*;
+pub use integer_phase_saving::*;
 pub use out_domain_max::*;
 pub use out_domain_median::*;
 pub use out_domain_min::*;
 pub use out_domain_random::*;
 pub use phase_saving::*;
+pub use preferred_value_selector::*;
 pub use reverse_in_domain_split::*;
+pub use seeded_in_domain_random::*;
 pub use solution_guided_value_selector::*;
 pub use value_selector::ValueSelector;
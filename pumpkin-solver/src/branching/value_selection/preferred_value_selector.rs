@@ -0,0 +1,120 @@
+use super::ValueSelector;
+use crate::basic_types::SolutionReference;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::predicate;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A [`ValueSelector`] which assigns a variable to its preferred value (see
+/// [`Solver::set_preferred_value`]) if it has one and this value is still in its domain; otherwise,
+/// the decision is made by the provided `fallback` [`ValueSelector`].
+#[derive(Debug, Clone, Copy)]
+pub struct PreferredValueSelector<ValueSelection> {
+    fallback: ValueSelection,
+}
+
+impl<ValueSelection> PreferredValueSelector<ValueSelection> {
+    pub fn new(fallback: ValueSelection) -> Self {
+        PreferredValueSelector { fallback }
+    }
+}
+
+impl<ValueSelection: ValueSelector<DomainId>> ValueSelector<DomainId>
+    for PreferredValueSelector<ValueSelection>
+{
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: DomainId,
+    ) -> Predicate {
+        match context.preferred_value(decision_variable) {
+            Some(value) if context.contains(decision_variable, value) => {
+                predicate!(decision_variable == value)
+            }
+            _ => self.fallback.select_value(context, decision_variable),
+        }
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.fallback.on_unassign_literal(literal)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.fallback.on_unassign_integer(variable, value)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.fallback.on_solution(solution)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.fallback.is_restart_pointless()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::basic_types::HashMap;
+    use crate::branching::InDomainMin;
+    use crate::branching::PreferredValueSelector;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+    use crate::predicate;
+
+    #[test]
+    fn preferred_value_is_selected_if_in_domain() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let domain_ids = assignments_integer.get_domains().collect::<Vec<_>>();
+
+        let mut preferred_values = HashMap::default();
+        let _ = preferred_values.insert(domain_ids[0], 7);
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        )
+        .with_preferred_values(&preferred_values);
+
+        let mut selector = PreferredValueSelector::new(InDomainMin);
+
+        let x = domain_ids[0];
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x == 7));
+
+        // Without a preference, the fallback is used
+        let y = domain_ids[1];
+        let selected_predicate = selector.select_value(&mut context, y);
+        assert_eq!(selected_predicate, predicate!(y <= 0));
+    }
+
+    #[test]
+    fn fallback_is_used_if_preferred_value_is_not_in_domain() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let x = assignments_integer.get_domains().next().unwrap();
+
+        let _ = assignments_integer.remove_value_from_domain(x, 7, None);
+
+        let mut preferred_values = HashMap::default();
+        let _ = preferred_values.insert(x, 7);
+
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        )
+        .with_preferred_values(&preferred_values);
+
+        let mut selector = PreferredValueSelector::new(InDomainMin);
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x <= 0));
+    }
+}
@@ -0,0 +1,105 @@
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+use crate::basic_types::Random;
+#[cfg(doc)]
+use crate::branching::InDomainRandom;
+use crate::branching::SelectionContext;
+use crate::branching::ValueSelector;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::engine::variables::PropositionalVariable;
+use crate::predicate;
+
+/// A [`ValueSelector`] which assigns to a random value in the domain using its own random number
+/// generator, seeded with the provided seed.
+///
+/// In contrast to [`InDomainRandom`], which uses the random number generator of the solver (see
+/// [`SelectionContext::random`]), the choices of this selector are reproducible independently of
+/// the rest of the solver.
+#[derive(Debug, Clone)]
+pub struct SeededInDomainRandom {
+    random: SmallRng,
+}
+
+impl SeededInDomainRandom {
+    pub fn new(seed: u64) -> Self {
+        SeededInDomainRandom {
+            random: SmallRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl ValueSelector<DomainId> for SeededInDomainRandom {
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: DomainId,
+    ) -> Predicate {
+        let values_in_domain = (context.lower_bound(decision_variable)
+            ..=context.upper_bound(decision_variable))
+            .filter(|bound| context.contains(decision_variable, *bound))
+            .collect::<Vec<_>>();
+        let random_index = self
+            .random
+            .generate_usize_in_range(0..values_in_domain.len());
+        predicate!(decision_variable == values_in_domain[random_index])
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+impl ValueSelector<PropositionalVariable> for SeededInDomainRandom {
+    fn select_value(
+        &mut self,
+        _context: &mut SelectionContext,
+        decision_variable: PropositionalVariable,
+    ) -> Predicate {
+        Literal::new(decision_variable, self.random.generate_bool(0.5)).into()
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::SeededInDomainRandom;
+    use crate::branching::SelectionContext;
+    use crate::branching::ValueSelector;
+
+    #[test]
+    fn selectors_with_the_same_seed_make_the_same_choices() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 100)]));
+        let mut test_random = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_random,
+        );
+        let domain_id = context.get_domains().next().unwrap();
+
+        let mut first = SeededInDomainRandom::new(42);
+        let mut second = SeededInDomainRandom::new(42);
+
+        // The test random generator is empty, so it would panic if it were used
+        let first_choices = (0..10)
+            .map(|_| first.select_value(&mut context, domain_id))
+            .collect::<Vec<_>>();
+        let second_choices = (0..10)
+            .map(|_| second.select_value(&mut context, domain_id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(first_choices, second_choices);
+        // The choices are not all the same value
+        assert!(first_choices
+            .iter()
+            .any(|&predicate| predicate != first_choices[0]));
+    }
+}
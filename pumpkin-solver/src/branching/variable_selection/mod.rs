@@ -15,8 +15,10 @@ mod max_regret;
 mod most_constrained;
 mod occurrence;
 mod smallest;
+mod smallest_domain_then_activity;
 mod variable_selector;
 mod vsids;
+mod weighted_input_order;
 
 pub use anti_first_fail::*;
 pub use dynamic_variable_selector::*;
@@ -27,5 +29,7 @@ pub use max_regret::*;
 pub use most_constrained::*;
 pub use occurrence::*;
 pub use smallest::*;
+pub use smallest_domain_then_activity::*;
 pub use variable_selector::VariableSelector;
 pub use vsids::*;
+pub use weighted_input_order::*;
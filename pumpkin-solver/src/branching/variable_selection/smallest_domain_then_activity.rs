@@ -0,0 +1,171 @@
+use log::warn;
+
+use super::VariableSelector;
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::branching::SelectionContext;
+use crate::engine::variables::DomainId;
+
+/// A [`VariableSelector`] which selects the variable with the smallest domain (based on the
+/// lower-bound and upper-bound, disregarding holes, similar to
+/// [`FirstFail`](super::FirstFail)) and breaks ties by selecting the variable with the highest
+/// activity.
+///
+/// The activities are maintained in the same manner as [`Vsids`](super::Vsids); the activity of a
+/// variable is bumped whenever it appears in a conflict, and the activities decay after every
+/// conflict such that recent conflicts are more impactful.
+#[derive(Debug)]
+pub struct SmallestDomainThenActivity {
+    variables: Vec<DomainId>,
+    activities: KeyedVec<DomainId, f64>,
+    /// How much the activity of a variable is increased when it appears in a conflict.
+    increment: f64,
+    /// The maximum allowed activity, if this value is reached then all of the activities are
+    /// divided by this value.
+    max_threshold: f64,
+    /// Whenever a conflict is found, the increment is multiplied by 1 / `decay_factor`.
+    decay_factor: f64,
+}
+
+const DEFAULT_ACTIVITY_INCREMENT: f64 = 1.0;
+const DEFAULT_ACTIVITY_MAX_THRESHOLD: f64 = 1e100;
+const DEFAULT_ACTIVITY_DECAY_FACTOR: f64 = 0.95;
+const DEFAULT_ACTIVITY_VALUE: f64 = 0.0;
+
+impl SmallestDomainThenActivity {
+    /// Creates a new instance of the [`SmallestDomainThenActivity`] [`VariableSelector`] with the
+    /// same default values for the activity parameters as [`Vsids`](super::Vsids) (`1.0` for the
+    /// increment, `1e100` for the max threshold and `0.95` for the decay factor).
+    pub fn new(variables: &[DomainId]) -> Self {
+        if variables.is_empty() {
+            warn!(
+                "The SmallestDomainThenActivity variable selector was not provided with any variables"
+            );
+        }
+
+        SmallestDomainThenActivity {
+            variables: variables.to_vec(),
+            activities: KeyedVec::default(),
+            increment: DEFAULT_ACTIVITY_INCREMENT,
+            max_threshold: DEFAULT_ACTIVITY_MAX_THRESHOLD,
+            decay_factor: DEFAULT_ACTIVITY_DECAY_FACTOR,
+        }
+    }
+
+    fn activity(&self, variable: DomainId) -> f64 {
+        if variable.index() < self.activities.len() {
+            self.activities[variable]
+        } else {
+            DEFAULT_ACTIVITY_VALUE
+        }
+    }
+
+    /// Bumps the activity of a variable after it has been encountered during a conflict.
+    fn bump_activity(&mut self, variable: DomainId) {
+        self.activities.accomodate(variable, DEFAULT_ACTIVITY_VALUE);
+        // Scale the activities if the values are too large
+        if self.activities[variable] + self.increment >= self.max_threshold {
+            self.activities
+                .iter_mut()
+                .for_each(|activity| *activity /= self.max_threshold);
+            self.increment /= self.max_threshold;
+        }
+        self.activities[variable] += self.increment;
+    }
+}
+
+impl VariableSelector<DomainId> for SmallestDomainThenActivity {
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+        self.variables
+            .iter()
+            .filter(|&&variable| !context.is_integer_fixed(variable))
+            .fold(None, |best: Option<(DomainId, i32, f64)>, &variable| {
+                let size = context.get_size_of_domain(variable);
+                let activity = self.activity(variable);
+
+                match best {
+                    Some((_, best_size, best_activity))
+                        if best_size < size || (best_size == size && best_activity >= activity) =>
+                    {
+                        best
+                    }
+                    _ => Some((variable, size, activity)),
+                }
+            })
+            .map(|(variable, _, _)| variable)
+    }
+
+    fn on_conflict(&mut self) {
+        self.increment *= 1.0 / self.decay_factor;
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.bump_activity(variable)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::SelectionContext;
+    use crate::branching::SmallestDomainThenActivity;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn ties_in_domain_size_are_broken_by_activity() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (0, 5), (5, 10)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = SmallestDomainThenActivity::new(&integer_variables);
+        // Without any activity, the first of the smallest domains is selected
+        assert_eq!(
+            Some(integer_variables[1]),
+            strategy.select_variable(&context)
+        );
+
+        strategy.on_appearance_in_conflict_integer(integer_variables[2]);
+        strategy.on_conflict();
+        strategy.on_appearance_in_conflict_integer(integer_variables[1]);
+        strategy.on_appearance_in_conflict_integer(integer_variables[2]);
+        assert_eq!(
+            Some(integer_variables[2]),
+            strategy.select_variable(&context)
+        );
+
+        // Activity does not outweigh a smaller domain
+        strategy.on_appearance_in_conflict_integer(integer_variables[0]);
+        strategy.on_appearance_in_conflict_integer(integer_variables[0]);
+        strategy.on_appearance_in_conflict_integer(integer_variables[0]);
+        assert_eq!(
+            Some(integer_variables[2]),
+            strategy.select_variable(&context)
+        );
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(10, 10), (20, 20)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = SmallestDomainThenActivity::new(&integer_variables);
+        assert!(strategy.select_variable(&context).is_none());
+    }
+}
@@ -0,0 +1,123 @@
+use log::warn;
+
+use crate::branching::SelectionContext;
+use crate::branching::VariableSelector;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::PropositionalVariable;
+
+/// A [`VariableSelector`] which selects the variable with the highest user-provided priority
+/// which is not fixed; ties are broken based on the order in the provided list.
+///
+/// For example, in bin packing, weighting the item variables by the size of the items results in
+/// the items being placed in order of decreasing size.
+#[derive(Debug)]
+pub struct WeightedInputOrder<Var> {
+    /// The variables sorted by decreasing priority.
+    variables: Vec<Var>,
+}
+
+impl<Var: Copy> WeightedInputOrder<Var> {
+    pub fn new(mut weighted_variables: Vec<(Var, f64)>) -> Self {
+        if weighted_variables.is_empty() {
+            warn!("The WeightedInputOrder variable selector was not provided with any variables");
+        }
+
+        // The sort is stable, which ensures that ties are broken by input order
+        weighted_variables.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+
+        WeightedInputOrder {
+            variables: weighted_variables
+                .into_iter()
+                .map(|(variable, _)| variable)
+                .collect(),
+        }
+    }
+}
+
+impl VariableSelector<DomainId> for WeightedInputOrder<DomainId> {
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+        self.variables
+            .iter()
+            .find(|variable| !context.is_integer_fixed(**variable))
+            .copied()
+    }
+}
+
+impl VariableSelector<PropositionalVariable> for WeightedInputOrder<PropositionalVariable> {
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<PropositionalVariable> {
+        self.variables
+            .iter()
+            .find(|variable| !context.is_propositional_variable_fixed(**variable))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+    use crate::branching::WeightedInputOrder;
+
+    #[test]
+    fn variable_with_highest_weight_is_selected() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(
+                4,
+                0,
+                Some(vec![(0, 10), (0, 10), (0, 10), (0, 10)]),
+            );
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = WeightedInputOrder::new(vec![
+            (integer_variables[0], 1.0),
+            (integer_variables[1], 5.0),
+            (integer_variables[2], 3.0),
+            (integer_variables[3], 5.0),
+        ]);
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+
+            // The tie between the second and fourth variable is broken by input order
+            let selected = strategy.select_variable(&context);
+            assert_eq!(Some(integer_variables[1]), selected);
+        }
+
+        let _ = assignments_integer.make_assignment(integer_variables[1], 0, None);
+        let _ = assignments_integer.make_assignment(integer_variables[3], 0, None);
+
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let selected = strategy.select_variable(&context);
+        assert_eq!(Some(integer_variables[2]), selected);
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(10, 10), (20, 20)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = WeightedInputOrder::new(vec![
+            (integer_variables[0], 2.0),
+            (integer_variables[1], 1.0),
+        ]);
+        let selected = strategy.select_variable(&context);
+        assert!(selected.is_none());
+    }
+}
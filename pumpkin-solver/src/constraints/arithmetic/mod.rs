@@ -9,6 +9,7 @@ use crate::propagators::absolute_value::AbsoluteValuePropagator;
 use crate::propagators::division::DivisionPropagator;
 use crate::propagators::integer_multiplication::IntegerMultiplicationPropagator;
 use crate::propagators::maximum::MaximumPropagator;
+use crate::propagators::square::SquarePropagator;
 use crate::variables::IntegerVariable;
 
 /// Creates the [`Constraint`] `a + b = c`.
@@ -29,8 +30,8 @@ pub fn times(
 ///
 /// Note that this [`Constraint`] models truncating division (i.e. rounding towards 0).
 ///
-/// The `denominator` should not contain the value 0 in its domain; if this is the case then the
-/// solver will panic.
+/// The value 0 is removed from the domain of the `denominator`, as the division is undefined in
+/// that case.
 pub fn division(
     numerator: impl IntegerVariable + 'static,
     denominator: impl IntegerVariable + 'static,
@@ -47,6 +48,17 @@ pub fn absolute(
     AbsoluteValuePropagator::new(signed, absolute)
 }
 
+/// Creates the [`Constraint`] `base * base = square`.
+///
+/// As opposed to modelling this using [`times`], the propagation exploits that the square is
+/// monotone in the magnitude of `base`.
+pub fn square(
+    base: impl IntegerVariable + 'static,
+    square: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    SquarePropagator::new(base, square)
+}
+
 /// Creates the [`Constraint`] `max(array) = m`.
 pub fn maximum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
@@ -60,6 +72,6 @@ pub fn minimum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    let array = array.into_iter().map(|var| var.scaled(-1));
-    maximum(array, rhs.scaled(-1))
+    let array = array.into_iter().map(|var| var.negated());
+    maximum(array, rhs.negated())
 }
@@ -4,8 +4,11 @@ use super::equals;
 use super::less_than_or_equals;
 use super::Constraint;
 use crate::predicate;
+use crate::propagators::indicator_channeling::IndicatorChannelingPropagator;
+use crate::propagators::pseudo_boolean_less_or_equal::PseudoBooleanLessOrEqualPropagator;
 use crate::variables::AffineView;
 use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::variables::TransformableVariable;
 use crate::ConstraintOperationError;
@@ -24,6 +27,47 @@ pub fn boolean_less_than_or_equals(
     }
 }
 
+/// Creates the [`Constraint`] `\sum w_i * l_i <= k` over the `terms` `(w_i, l_i)`, where every
+/// literal is interpreted as a 0/1 variable.
+///
+/// As opposed to [`boolean_less_than_or_equals`], this constraint is propagated directly over the
+/// literals rather than through a decomposition into integer variables.
+pub fn pseudo_boolean_le(terms: Vec<(i32, Literal)>, k: i32) -> impl Constraint {
+    PseudoBooleanLessOrEqualPropagator::new(terms, k)
+}
+
+/// Creates the [`Constraint`] which states that at most one of the `literals` is true.
+///
+/// Whenever one of the literals becomes true, all other literals are set to false.
+pub fn at_most_one(literals: impl IntoIterator<Item = Literal>) -> impl Constraint {
+    pseudo_boolean_le(
+        literals.into_iter().map(|literal| (1, literal)).collect(),
+        1,
+    )
+}
+
+/// Creates the [`Constraint`] which states that exactly one of the `literals` is true.
+///
+/// In addition to the propagation of [`at_most_one`], the last literal which is not false is set
+/// to true.
+pub fn exactly_one(literals: impl IntoIterator<Item = Literal>) -> impl Constraint {
+    ExactlyOne {
+        literals: literals.into_iter().collect(),
+    }
+}
+
+/// Creates the [`Constraint`] which channels between the integer `variable` and the `indicators`,
+/// such that `indicators[v]` is true if and only if `variable == v`.
+///
+/// This implies that exactly one of the `indicators` is true, and that `variable` can only take
+/// values in `0..indicators.len()`.
+pub fn int_to_indicators<Var: IntegerVariable + 'static>(
+    variable: Var,
+    indicators: Vec<Literal>,
+) -> impl Constraint {
+    IndicatorChannelingPropagator::new(variable, indicators.into())
+}
+
 /// Creates the [`Constraint`] `\sum weights_i * bools_i == rhs`.
 pub fn boolean_equals(
     weights: impl Into<Box<[i32]>>,
@@ -37,6 +81,35 @@ pub fn boolean_equals(
     }
 }
 
+struct ExactlyOne {
+    literals: Vec<Literal>,
+}
+
+impl Constraint for ExactlyOne {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        at_most_one(self.literals.iter().copied()).post(solver, tag)?;
+        solver.add_clause(self.literals)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        at_most_one(self.literals.iter().copied()).implied_by(solver, reification_literal, tag)?;
+        solver.add_clause(
+            self.literals
+                .into_iter()
+                .chain(std::iter::once(!reification_literal)),
+        )
+    }
+}
+
 struct BooleanLessThanOrEqual {
     weights: Box<[i32]>,
     bools: Box<[Literal]>,
@@ -0,0 +1,30 @@
+use super::Constraint;
+use crate::propagators::circuit::CircuitPropagator;
+use crate::propagators::subcircuit::SubcircuitPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [circuit](https://sofdem.github.io/gccat/gccat/Ccircuit.html) [`Constraint`],
+/// which states that the arcs `i -> successors[i]` form a single Hamiltonian cycle over the nodes
+/// `0..n`; this is typically used to model routing problems such as the travelling salesperson
+/// problem.
+///
+/// The domains of the `successors` are restricted to the nodes `0..n`, where `n` is the number of
+/// provided variables.
+pub fn circuit<Var: IntegerVariable + 'static>(
+    successors: impl IntoIterator<Item = Var>,
+) -> impl Constraint {
+    CircuitPropagator::new(successors.into_iter().collect())
+}
+
+/// Creates the [subcircuit](https://sofdem.github.io/gccat/gccat/Csubcircuit.html)
+/// [`Constraint`], which states that the arcs `i -> successors[i]` with `successors[i] != i` form
+/// a single cycle; a node `i` with `successors[i] = i` is not visited. Note that this also allows
+/// none of the nodes to be visited.
+///
+/// The domains of the `successors` are restricted to the nodes `0..n`, where `n` is the number of
+/// provided variables.
+pub fn subcircuit<Var: IntegerVariable + 'static>(
+    successors: impl IntoIterator<Item = Var>,
+) -> impl Constraint {
+    SubcircuitPropagator::new(successors.into_iter().collect())
+}
@@ -4,6 +4,8 @@ use log::warn;
 
 use super::Constraint;
 use super::NegatableConstraint;
+use crate::engine::propagation::Propagator;
+use crate::propagators::ReifiedPropagator;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
@@ -60,6 +62,33 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
     }
 }
 
+impl<ConcretePropagator> ConstraintPoster<'_, ConcretePropagator>
+where
+    ConcretePropagator: Propagator + 'static,
+{
+    /// Add the half-reified version of the propagator to the [`Solver`] as in
+    /// [`ConstraintPoster::implied_by`], except that the reification literal `r` is not propagated
+    /// to false when the propagator detects an inconsistency; the inconsistency is only reported
+    /// once `r` is set to true.
+    ///
+    /// This method returns a [`ConstraintOperationError`] if the addition of the propagator led
+    /// to a root-level conflict.
+    pub fn implied_by_without_literal_propagation(
+        mut self,
+        reification_literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        let propagator =
+            ReifiedPropagator::new(self.constraint.take().unwrap(), reification_literal)
+                .without_literal_propagation();
+
+        if let Some(tag) = self.tag {
+            self.solver.add_tagged_propagator(propagator, tag)
+        } else {
+            self.solver.add_propagator(propagator)
+        }
+    }
+}
+
 impl<ConstraintImpl: NegatableConstraint> ConstraintPoster<'_, ConstraintImpl> {
     /// Add the reified version of the [`Constraint`] to the [`Solver`]; i.e. post the constraint
     /// `r <-> constraint` where `r` is a reification literal.
@@ -81,3 +110,30 @@ impl<ConstraintImpl> Drop for ConstraintPoster<'_, ConstraintImpl> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+    use crate::Solver;
+
+    #[test]
+    fn reification_literal_is_not_propagated_without_literal_propagation() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(1, 5);
+        let y = solver.new_bounded_integer(1, 5);
+        let propagating = solver.new_literal();
+        let non_propagating = solver.new_literal();
+
+        solver
+            .add_constraint(LinearLessOrEqualPropagator::new([x, y].into(), 1))
+            .implied_by(propagating)
+            .expect("no root-level conflict");
+        solver
+            .add_constraint(LinearLessOrEqualPropagator::new([x, y].into(), 1))
+            .implied_by_without_literal_propagation(non_propagating)
+            .expect("no root-level conflict");
+
+        assert_eq!(Some(false), solver.get_literal_value(propagating));
+        assert_eq!(None, solver.get_literal_value(non_propagating));
+    }
+}
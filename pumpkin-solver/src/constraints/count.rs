@@ -0,0 +1,13 @@
+use super::Constraint;
+use crate::propagators::count::CountPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [`Constraint`] which states that the number of `variables` which take the value
+/// `value` is equal to `count`.
+pub fn count<Var: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    value: i32,
+    count: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    CountPropagator::new(variables.into_iter().collect(), value, count)
+}
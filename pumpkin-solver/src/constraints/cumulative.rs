@@ -5,10 +5,12 @@ use super::Constraint;
 use crate::options::CumulativePropagationMethod;
 use crate::propagators::ArgTask;
 use crate::propagators::CumulativeOptions;
+use crate::propagators::TimeTableOptionalTasksPropagator;
 use crate::propagators::TimeTableOverIntervalIncrementalPropagator;
 use crate::propagators::TimeTableOverIntervalPropagator;
 use crate::propagators::TimeTablePerPointIncrementalPropagator;
 use crate::propagators::TimeTablePerPointPropagator;
+use crate::propagators::TimeTableVariableTasksPropagator;
 use crate::pumpkin_assert_simple;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
@@ -187,6 +189,106 @@ where
     )
 }
 
+/// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) [`Constraint`]
+/// over optional tasks.
+///
+/// The task at index `i` is present if and only if `presence_literals[i]` is true; tasks which
+/// are absent do not consume any of the resource. This constraint ensures that at no point in
+/// time, the cumulative resource usage of the present tasks exceeds `resource_capacity`.
+///
+/// The constraint propagates the bounds of the start times of present tasks and propagates the
+/// presence literal of a task to false if the task cannot be scheduled at any of its start times
+/// without exceeding the resource capacity.
+///
+/// The length of `start_times`, `durations`, `resource_requirements` and `presence_literals`
+/// should be the same; if this is not the case then this method will panic.
+pub fn cumulative_optional<StartTimes, Durations, ResourceRequirements>(
+    start_times: StartTimes,
+    durations: Durations,
+    resource_requirements: ResourceRequirements,
+    resource_capacity: i32,
+    presence_literals: impl Into<Box<[Literal]>>,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i32>,
+    Durations::IntoIter: ExactSizeIterator,
+    ResourceRequirements: IntoIterator<Item = i32>,
+    ResourceRequirements::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter();
+    let durations = durations.into_iter();
+    let resource_requirements = resource_requirements.into_iter();
+    let presence_literals = presence_literals.into();
+
+    pumpkin_assert_simple!(
+        start_times.len() == durations.len()
+            && durations.len() == resource_requirements.len()
+            && resource_requirements.len() == presence_literals.len(),
+        "The number of start variables, durations, resource requirements and presence literals should be the same!"
+    );
+
+    TimeTableOptionalTasksPropagator::new(
+        start_times
+            .zip(durations)
+            .zip(resource_requirements)
+            .map(|((start_time, duration), resource_requirement)| ArgTask {
+                start_time,
+                processing_time: duration,
+                resource_usage: resource_requirement,
+            })
+            .collect(),
+        presence_literals,
+        resource_capacity,
+    )
+}
+
+/// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) [`Constraint`]
+/// where the durations and resource requirements of the tasks are variables.
+///
+/// This constraint ensures that at no point in time, the cumulative resource usage of the tasks
+/// exceeds `resource_capacity`; task `i` starts at `start_times[i]`, executes for
+/// `durations[i]` time units and uses `resource_requirements[i]` of the resource.
+///
+/// The mandatory parts of the tasks are computed using the lower-bounds of the durations and
+/// resource requirements. Based on these, the constraint propagates the bounds of the start times,
+/// and the upper-bounds of the durations and resource requirements.
+///
+/// The length of `start_times`, `durations` and `resource_requirements` should be the same; if
+/// this is not the case then this method will panic.
+pub fn cumulative_variable<StartTimes, Durations, ResourceRequirements>(
+    start_times: StartTimes,
+    durations: Durations,
+    resource_requirements: ResourceRequirements,
+    resource_capacity: i32,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + 'static,
+    Durations: IntoIterator,
+    Durations::Item: IntegerVariable + 'static,
+    ResourceRequirements: IntoIterator,
+    ResourceRequirements::Item: IntegerVariable + 'static,
+{
+    let start_times: Box<[_]> = start_times.into_iter().collect();
+    let durations: Box<[_]> = durations.into_iter().collect();
+    let resource_requirements: Box<[_]> = resource_requirements.into_iter().collect();
+
+    pumpkin_assert_simple!(
+        start_times.len() == durations.len() && durations.len() == resource_requirements.len(),
+        "The number of start variables, durations and resource requirements should be the same!"
+    );
+
+    TimeTableVariableTasksPropagator::new(
+        start_times,
+        durations,
+        resource_requirements,
+        resource_capacity,
+    )
+}
+
 struct CumulativeConstraint<Var> {
     tasks: Vec<ArgTask<Var>>,
     resource_capacity: i32,
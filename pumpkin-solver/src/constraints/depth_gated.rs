@@ -0,0 +1,16 @@
+use super::Constraint;
+use crate::engine::propagation::Propagator;
+use crate::propagators::depth_gated::DepthGated;
+
+/// Creates a [`Constraint`] which enforces the provided `propagator`, but only performs its
+/// propagation when the current decision level is at most `maximum_decision_level`.
+///
+/// This allows expensive propagators to only perform their reasoning near the root of the search
+/// tree. The constraint is still enforced deeper in the search tree: once all of the variables
+/// which the propagator watches are assigned, the propagator is invoked to check the assignment.
+pub fn depth_gated<ConcretePropagator: Propagator + 'static>(
+    propagator: ConcretePropagator,
+    maximum_decision_level: usize,
+) -> impl Constraint {
+    DepthGated::new(propagator, maximum_decision_level)
+}
@@ -0,0 +1,41 @@
+use super::Constraint;
+use crate::propagators::diffn::DiffnPropagator;
+use crate::propagators::diffn::Rectangle;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// Creates the [diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html) [`Constraint`], which
+/// states that the rectangles do not overlap.
+///
+/// The rectangle at index `i` has its origin at `(xs[i], ys[i])` and occupies the points in
+/// `[xs[i], xs[i] + widths[i]) x [ys[i], ys[i] + heights[i])`.
+pub fn diffn<Var: IntegerVariable + 'static>(
+    xs: impl IntoIterator<Item = Var>,
+    ys: impl IntoIterator<Item = Var>,
+    widths: impl IntoIterator<Item = i32>,
+    heights: impl IntoIterator<Item = i32>,
+) -> impl Constraint {
+    let xs = xs.into_iter().collect::<Vec<_>>();
+    let ys = ys.into_iter().collect::<Vec<_>>();
+    let widths = widths.into_iter().collect::<Vec<_>>();
+    let heights = heights.into_iter().collect::<Vec<_>>();
+
+    pumpkin_assert_simple!(
+        xs.len() == ys.len() && ys.len() == widths.len() && widths.len() == heights.len(),
+        "The number of origins, widths and heights should be the same!"
+    );
+
+    DiffnPropagator::new(
+        xs.into_iter()
+            .zip(ys)
+            .zip(widths)
+            .zip(heights)
+            .map(|(((x, y), width), height)| Rectangle {
+                x,
+                y,
+                width,
+                height,
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,84 @@
+use std::num::NonZero;
+
+use super::Constraint;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::propagators::disjunctive::DisjunctiveTask;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`], which states that no two tasks are executed at the same time; the task at index
+/// `i` is executed in the interval `[starts[i], starts[i] + durations[i])`.
+///
+/// This is the special case of [`cumulative`](super::cumulative) in which every task has resource
+/// usage 1 and the capacity is 1. The constraint is enforced through the detectable precedences
+/// rule, which is applied to the tasks and to the mirrored tasks (with start `-(s + p)`) in order to
+/// update both the lower and upper bounds of the start times.
+///
+/// Tasks with a duration of at most 0 are never executed, and are thus not constrained.
+pub fn disjunctive<Var: IntegerVariable + 'static>(
+    starts: impl IntoIterator<Item = Var>,
+    durations: impl IntoIterator<Item = i32>,
+) -> impl Constraint {
+    let starts = starts.into_iter().collect::<Vec<_>>();
+    let durations = durations.into_iter().collect::<Vec<_>>();
+
+    pumpkin_assert_simple!(
+        starts.len() == durations.len(),
+        "The number of start times and durations should be the same!"
+    );
+
+    Disjunctive {
+        tasks: starts
+            .into_iter()
+            .zip(durations)
+            .filter(|&(_, duration)| duration > 0)
+            .map(|(start, duration)| DisjunctiveTask { start, duration })
+            .collect(),
+    }
+}
+
+struct Disjunctive<Var> {
+    tasks: Box<[DisjunctiveTask<Var>]>,
+}
+
+impl<Var: IntegerVariable + 'static> Disjunctive<Var> {
+    fn mirrored_tasks(&self) -> Box<[DisjunctiveTask<impl IntegerVariable + 'static>]> {
+        self.tasks
+            .iter()
+            .map(|task| DisjunctiveTask {
+                start: task.start.negated().offset(-task.duration),
+                duration: task.duration,
+            })
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Disjunctive<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        DisjunctivePropagator::new(self.mirrored_tasks()).post(solver, tag)?;
+        DisjunctivePropagator::new(self.tasks).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        DisjunctivePropagator::new(self.mirrored_tasks()).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )?;
+        DisjunctivePropagator::new(self.tasks).implied_by(solver, reification_literal, tag)
+    }
+}
@@ -0,0 +1,130 @@
+use std::num::NonZero;
+
+use super::Constraint;
+use crate::propagators::gcc::GccPropagator;
+use crate::propagators::gcc::ValueCardinality;
+use crate::propagators::gcc_cost::GccCostPropagator;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [global cardinality](https://sofdem.github.io/gccat/gccat/Cglobal_cardinality.html)
+/// [`Constraint`], which states that, for every index `i`, the number of `variables` which take
+/// the value `values[i]` is at least `lower_counts[i]` and at most `upper_counts[i]`.
+///
+/// The provided `values`, `lower_counts` and `upper_counts` should have the same length.
+pub fn gcc<Var: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    values: impl IntoIterator<Item = i32>,
+    lower_counts: impl IntoIterator<Item = usize>,
+    upper_counts: impl IntoIterator<Item = usize>,
+) -> impl Constraint {
+    GccPropagator::new(
+        variables.into_iter().collect(),
+        cardinalities(values, lower_counts, upper_counts),
+    )
+}
+
+/// Creates the cost-based [global cardinality](https://sofdem.github.io/gccat/gccat/Cglobal_cardinality_with_costs.html)
+/// [`Constraint`], which extends [`gcc`] with costs; every variable `variables[i]` should take
+/// one of the `values`, and `total_cost` is equal to the sum of `costs[i][j]` over the
+/// assignments `variables[i] = values[j]`.
+///
+/// Next to the counting-based propagation of [`gcc`], the bounds of `total_cost` are propagated
+/// using a min-cost flow relaxation, and assignments which would violate these bounds are
+/// removed.
+pub fn gcc_cost<Var: IntegerVariable + 'static, CostVar: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    values: impl IntoIterator<Item = i32>,
+    lower_counts: impl IntoIterator<Item = usize>,
+    upper_counts: impl IntoIterator<Item = usize>,
+    costs: Vec<Vec<i32>>,
+    total_cost: CostVar,
+) -> impl Constraint {
+    GccCost {
+        variables: variables.into_iter().collect(),
+        cardinalities: cardinalities(values, lower_counts, upper_counts),
+        costs: costs.into_iter().map(Vec::into_boxed_slice).collect(),
+        total_cost,
+    }
+}
+
+fn cardinalities(
+    values: impl IntoIterator<Item = i32>,
+    lower_counts: impl IntoIterator<Item = usize>,
+    upper_counts: impl IntoIterator<Item = usize>,
+) -> Box<[ValueCardinality]> {
+    let values = values.into_iter().collect::<Vec<_>>();
+    let lower_counts = lower_counts.into_iter().collect::<Vec<_>>();
+    let upper_counts = upper_counts.into_iter().collect::<Vec<_>>();
+    pumpkin_assert_simple!(
+        values.len() == lower_counts.len() && values.len() == upper_counts.len(),
+        "Every value should have exactly one lower and one upper count"
+    );
+
+    values
+        .into_iter()
+        .zip(lower_counts)
+        .zip(upper_counts)
+        .map(|((value, lower_count), upper_count)| ValueCardinality {
+            value,
+            lower_count,
+            upper_count,
+        })
+        .collect()
+}
+
+struct GccCost<Var, CostVar> {
+    variables: Box<[Var]>,
+    cardinalities: Box<[ValueCardinality]>,
+    costs: Box<[Box<[i32]>]>,
+    total_cost: CostVar,
+}
+
+impl<Var: IntegerVariable + 'static, CostVar: IntegerVariable + 'static> GccCost<Var, CostVar> {
+    fn cost_propagator(&self) -> GccCostPropagator<Var, CostVar> {
+        GccCostPropagator::new(
+            self.variables.clone(),
+            self.cardinalities
+                .iter()
+                .map(|cardinality| cardinality.value)
+                .collect(),
+            self.cardinalities
+                .iter()
+                .map(|cardinality| cardinality.upper_count)
+                .collect(),
+            self.costs.clone(),
+            self.total_cost.clone(),
+        )
+    }
+}
+
+impl<Var: IntegerVariable + 'static, CostVar: IntegerVariable + 'static> Constraint
+    for GccCost<Var, CostVar>
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.cost_propagator().post(solver, tag)?;
+        GccPropagator::new(self.variables, self.cardinalities).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.cost_propagator()
+            .implied_by(solver, reification_literal, tag)?;
+        GccPropagator::new(self.variables, self.cardinalities).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use super::Constraint;
+use crate::propagators::lex_less_equal::LexLessEqualPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [lexicographic less than or equal](https://sofdem.github.io/gccat/gccat/Clex_lesseq.html)
+/// [`Constraint`], which states that the sequence `lhs` is lexicographically smaller than or equal
+/// to the sequence `rhs`; this is typically used to break symmetries.
+///
+/// The provided sequences should have the same length.
+pub fn lex_less_equal<Var: IntegerVariable + 'static>(
+    lhs: impl IntoIterator<Item = Var>,
+    rhs: impl IntoIterator<Item = Var>,
+) -> impl Constraint {
+    LexLessEqualPropagator::new(lhs.into_iter().collect(), rhs.into_iter().collect())
+}
@@ -0,0 +1,17 @@
+use super::Constraint;
+pub use crate::propagators::mdd::Mdd;
+use crate::propagators::mdd::MddPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the multi-valued decision diagram (MDD) [`Constraint`], which states that the
+/// assignment of `variables` is one of the tuples represented by the provided [`Mdd`].
+///
+/// An [`Mdd`] can be created from a list of tuples using [`Mdd::from_tuples`], in which case this
+/// constraint is equivalent to the [`table`](super::table) constraint over these tuples; the
+/// [`Mdd`] is typically much smaller than the list of tuples for structured tables.
+pub fn mdd<Var: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    mdd: Mdd,
+) -> impl Constraint {
+    MddPropagator::new(variables.into_iter().collect(), mdd)
+}
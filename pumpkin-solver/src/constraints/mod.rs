@@ -17,28 +17,48 @@
 //! ```
 //!
 //! # Note
-//! At the moment, the API for posting propagators is not yet publicly accessible as it is
-//! unstable. Consumers of the Pumpkin library can therefore only define constraints by
-//! decomposing them into the constraints that are predefined in the library. Once the
-//! propagator API is stabilized, it will become part of the public API.
+//! Besides decomposing them into the constraints that are predefined in the library, consumers of
+//! the Pumpkin library can define constraints by implementing a custom
+//! [`Propagator`]; such a propagator is a [`Constraint`] itself
+//! and can also be added using [`Solver::add_propagator`]. Note that this API is not yet stable.
 
 mod all_different;
 mod arithmetic;
 mod boolean;
+mod circuit;
 mod clause;
 mod constraint_poster;
+mod count;
 mod cumulative;
+mod depth_gated;
+mod diffn;
+mod disjunctive;
 mod element;
+mod gcc;
+mod lex_less_equal;
+mod mdd;
+mod table;
+mod value_precede;
 
 use std::num::NonZero;
 
 pub use all_different::*;
 pub use arithmetic::*;
 pub use boolean::*;
+pub use circuit::*;
 pub use clause::*;
 pub use constraint_poster::*;
+pub use count::*;
 pub use cumulative::*;
+pub use depth_gated::*;
+pub use diffn::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use gcc::*;
+pub use lex_less_equal::*;
+pub use mdd::*;
+pub use table::*;
+pub use value_precede::*;
 
 use crate::engine::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
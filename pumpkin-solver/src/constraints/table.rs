@@ -0,0 +1,18 @@
+use super::Constraint;
+use crate::propagators::table::TablePropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [table](https://sofdem.github.io/gccat/gccat/Cin_relation.html) [`Constraint`],
+/// which states that the assignment of `variables` is one of the provided (allowed) `tuples`.
+///
+/// For large tables with a lot of structure, consider using [`mdd`](super::mdd) instead, which
+/// represents the tuples in a compressed form.
+pub fn table<Var: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    tuples: Vec<Vec<i32>>,
+) -> impl Constraint {
+    TablePropagator::new(
+        variables.into_iter().collect(),
+        tuples.into_iter().map(|tuple| tuple.into()).collect(),
+    )
+}
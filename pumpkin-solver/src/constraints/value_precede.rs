@@ -0,0 +1,80 @@
+use std::num::NonZero;
+
+use super::Constraint;
+use super::NegatableConstraint;
+use crate::propagators::value_precede::ValuePrecedePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [value precedence](https://sofdem.github.io/gccat/gccat/Cint_value_precede.html)
+/// [`NegatableConstraint`], which states that the first occurrence of the value `s` in `variables`
+/// precedes the first occurrence of the value `t`; if `t` does not occur, then the constraint is
+/// satisfied. This is typically used to break value symmetries.
+///
+/// Its negation is that `t` occurs and that its first occurrence precedes the first occurrence of
+/// `s`; this allows the constraint to be reified in order to conditionally break symmetries.
+///
+/// The provided values `s` and `t` should be distinct.
+pub fn value_precede<Var: IntegerVariable + 'static>(
+    variables: impl IntoIterator<Item = Var>,
+    s: i32,
+    t: i32,
+) -> impl NegatableConstraint {
+    ValuePrecede {
+        variables: variables.into_iter().collect(),
+        s,
+        t,
+        requires_occurrence: false,
+    }
+}
+
+struct ValuePrecede<Var> {
+    variables: Box<[Var]>,
+    s: i32,
+    t: i32,
+    /// Whether `s` is required to occur.
+    requires_occurrence: bool,
+}
+
+impl<Var: IntegerVariable + 'static> ValuePrecede<Var> {
+    fn propagator(self) -> ValuePrecedePropagator<Var> {
+        ValuePrecedePropagator::new(self.variables, self.s, self.t, self.requires_occurrence)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for ValuePrecede<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.propagator().post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.propagator()
+            .implied_by(solver, reification_literal, tag)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> NegatableConstraint for ValuePrecede<Var> {
+    type NegatedConstraint = ValuePrecede<Var>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        // Either `t` does not occur or `s` occurs before it; the negation is that `t` occurs and
+        // that `s` does not occur before it
+        ValuePrecede {
+            variables: self.variables.clone(),
+            s: self.t,
+            t: self.s,
+            requires_occurrence: !self.requires_occurrence,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::options::SolverOptions;
+
+/// The derivation of a learned clause by 1UIP conflict analysis, which is recorded when
+/// [`SolverOptions::record_nogood_derivations`] is enabled.
+///
+/// The learned clause is derived by starting from the [`DerivationGraph::conflict`] clause, and
+/// applying the [`DerivationGraph::steps`] in order; each step resolves the clause with the reason
+/// of a literal which was propagated at the current decision level. Note that literals which are
+/// assigned at the root are not part of the resolvents, and that the
+/// [`DerivationGraph::learned_clause`] may be smaller than the final resolvent if learned clause
+/// minimisation is enabled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DerivationGraph {
+    /// The literals of the clause which was in conflict.
+    pub conflict: Vec<Literal>,
+    /// The resolution steps in the order in which they were applied.
+    pub steps: Vec<ResolutionStep>,
+    /// The learned clause; the asserting literal is placed at index 0.
+    pub learned_clause: Vec<Literal>,
+}
+
+/// A single resolution step performed during conflict analysis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolutionStep {
+    /// The propagated literal which was popped from the trail; its negation is removed from the
+    /// resolvent.
+    pub resolved_literal: Literal,
+    /// The literals of the reason for propagating [`ResolutionStep::resolved_literal`] (excluding
+    /// the propagated literal itself) which are added to the resolvent.
+    pub reason: Vec<Literal>,
+}
@@ -1,11 +1,14 @@
 //! Contains algorithms for conflict analysis, core extraction, and clause minimisation.
 //! The algorithms use resolution and implement the 1uip and all decision literal learning schemes
 mod conflict_analysis_context;
+mod derivation_graph;
 mod recursive_minimisation;
 mod resolution_conflict_analyser;
 mod semantic_minimiser;
 
 pub(crate) use conflict_analysis_context::ConflictAnalysisContext;
+pub use derivation_graph::*;
 pub(crate) use recursive_minimisation::*;
+pub use resolution_conflict_analyser::ResolutionMode;
 pub(crate) use resolution_conflict_analyser::*;
 pub(crate) use semantic_minimiser::*;
@@ -22,7 +22,7 @@ use crate::variables::Literal;
 /// to improved propositional proof traces’. SAT'09.
 ///
 /// \[2\] N. Sörensson and A. Biere, ‘Minimizing learned clauses’. SAT'09
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub(crate) struct RecursiveMinimiser {
     /// Indicates what [`Label`] the current [`Literal`] is assigned to
     label_assignments: HashMap<Literal, Option<Label>>,
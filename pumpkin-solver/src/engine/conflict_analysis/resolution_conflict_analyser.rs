@@ -1,9 +1,14 @@
+use clap::ValueEnum;
+
 use super::ConflictAnalysisContext;
+use super::DerivationGraph;
 use super::RecursiveMinimiser;
+use super::ResolutionStep;
 use super::SemanticMinimiser;
 use crate::basic_types::moving_averages::MovingAverage;
 use crate::basic_types::ClauseReference;
 use crate::basic_types::KeyedVec;
+use crate::branching::Brancher;
 use crate::engine::clause_allocators::ClauseInterface;
 use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
 use crate::engine::propagation::PropagatorId;
@@ -11,6 +16,9 @@ use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 #[cfg(doc)]
 use crate::engine::ConstraintSatisfactionSolver;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+use crate::engine::VariableLiteralMappings;
 use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_eq_simple;
 use crate::pumpkin_assert_moderate;
@@ -26,7 +34,32 @@ pub(crate) struct ConflictAnalysisResult {
     pub(crate) backjump_level: usize,
 }
 
-#[derive(Default, Debug)]
+/// Determines which learning scheme is used to derive the learned clause from a conflict.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResolutionMode {
+    /// Learn the first unique implication point (1UIP) clause.
+    #[default]
+    OneUip,
+    /// Compute both the 1UIP clause and the clause consisting of only decision literals, and
+    /// learn the one with the lowest literal block distance (LBD); ties are broken in favour of
+    /// the 1UIP clause.
+    BestOfBoth,
+    /// Learn the clause consisting of only decision literals; i.e. resolution continues past the
+    /// first unique implication point until every literal in the clause is a decision.
+    AllDecision,
+}
+
+impl std::fmt::Display for ResolutionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ResolutionMode::OneUip => write!(f, "one-uip"),
+            ResolutionMode::BestOfBoth => write!(f, "best-of-both"),
+            ResolutionMode::AllDecision => write!(f, "all-decision"),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
 pub(crate) struct ResolutionConflictAnalyser {
     // data structures used for conflict analysis
     seen: KeyedVec<PropositionalVariable, bool>,
@@ -37,9 +70,179 @@ pub(crate) struct ResolutionConflictAnalyser {
     recursive_minimiser: RecursiveMinimiser,
     /// A clause minimiser which uses a semantic minimisation approach (see [`SemanticMinimiser`]).
     semantic_minimiser: SemanticMinimiser,
+
+    /// The derivation of the last learned clause; only recorded if
+    /// [`SatisfactionSolverOptions::record_nogood_derivations`] is enabled.
+    last_derivation: Option<DerivationGraph>,
+
+    /// If set, the effects of the conflict analysis on the activities (and statistics) are
+    /// recorded rather than applied; see [`DeferredActivity`].
+    deferred_activity: Option<DeferredActivity>,
+}
+
+/// The effects of a conflict analysis on the clause activities, the brancher and the statistics.
+///
+/// When multiple clauses are computed for the same conflict (i.e. when using
+/// [`ResolutionMode::BestOfBoth`]), these are deferred such that only the effects of the analysis
+/// of the clause which is learned are applied.
+#[derive(Clone, Default, Debug)]
+struct DeferredActivity {
+    clauses: Vec<ClauseReference>,
+    literals: Vec<Literal>,
+    conflict_size: Option<u64>,
 }
 
 impl ResolutionConflictAnalyser {
+    /// Computes the learned clause for the current conflict according to the
+    /// [`SatisfactionSolverOptions::resolution_mode`].
+    pub(crate) fn compute_learned_clause(
+        &mut self,
+        context: &mut ConflictAnalysisContext,
+    ) -> ConflictAnalysisResult {
+        let result = self.select_learned_clause(context);
+
+        // The LBD is computed before backtracking, at which point all of the literals in the
+        // learned clause are still assigned at the level at which they were involved in the
+        // conflict
+        if !result.learned_literals.is_empty() {
+            let lbd = context.learned_clause_manager.compute_lbd_for_literals(
+                &result.learned_literals,
+                context.assignments_propositional,
+            ) as u64;
+            let statistics = &mut context.counters.learned_clause_statistics;
+            statistics.average_learned_clause_lbd.add_term(lbd);
+            statistics.last_learned_clause_lbd = lbd;
+        }
+
+        result
+    }
+
+    fn select_learned_clause(
+        &mut self,
+        context: &mut ConflictAnalysisContext,
+    ) -> ConflictAnalysisResult {
+        match context.internal_parameters.resolution_mode {
+            ResolutionMode::OneUip => return self.compute_1uip(context),
+            ResolutionMode::AllDecision => {
+                self.compute_all_decision_learning(false, context);
+                return self.analysis_result.clone();
+            }
+            ResolutionMode::BestOfBoth => {}
+        }
+
+        self.deferred_activity = Some(DeferredActivity::default());
+        let first_uip = self.compute_1uip(context);
+        let first_uip_activity = self.deferred_activity.replace(DeferredActivity::default());
+
+        // An empty learned clause indicates that the problem is infeasible, in which case there
+        // is nothing to choose from
+        if first_uip.learned_literals.is_empty() {
+            self.deferred_activity = None;
+            self.apply_activity(context, first_uip_activity.unwrap());
+            return first_uip;
+        }
+        let first_uip_derivation = self.last_derivation.take();
+
+        self.compute_all_decision_learning(false, context);
+        let all_decision = self.analysis_result.clone();
+        let all_decision_activity = self.deferred_activity.take();
+
+        let first_uip_lbd = context.learned_clause_manager.compute_lbd_for_literals(
+            &first_uip.learned_literals,
+            context.assignments_propositional,
+        );
+        let all_decision_lbd = context.learned_clause_manager.compute_lbd_for_literals(
+            &all_decision.learned_literals,
+            context.assignments_propositional,
+        );
+
+        if all_decision_lbd < first_uip_lbd {
+            self.apply_activity(context, all_decision_activity.unwrap());
+            all_decision
+        } else {
+            self.apply_activity(context, first_uip_activity.unwrap());
+            self.analysis_result = first_uip.clone();
+            self.last_derivation = first_uip_derivation;
+            first_uip
+        }
+    }
+
+    fn record_conflict_size(
+        &mut self,
+        context: &mut ConflictAnalysisContext,
+        conflict: ClauseReference,
+    ) {
+        let conflict_size = context.clause_allocator[conflict].len() as u64;
+        if let Some(activity) = self.deferred_activity.as_mut() {
+            activity.conflict_size = Some(conflict_size);
+        } else {
+            context
+                .counters
+                .learned_clause_statistics
+                .average_conflict_size
+                .add_term(conflict_size);
+        }
+    }
+
+    fn bump_clause_activity(
+        &mut self,
+        context: &mut ConflictAnalysisContext,
+        clause_reference: ClauseReference,
+    ) {
+        if let Some(activity) = self.deferred_activity.as_mut() {
+            activity.clauses.push(clause_reference);
+        } else {
+            context
+                .learned_clause_manager
+                .update_clause_lbd_and_bump_activity(
+                    clause_reference,
+                    context.assignments_propositional,
+                    context.clause_allocator,
+                );
+        }
+    }
+
+    fn bump_literal_activity(
+        deferred_activity: &mut Option<DeferredActivity>,
+        brancher: &mut dyn Brancher,
+        variable_literal_mappings: &VariableLiteralMappings,
+        literal: Literal,
+    ) {
+        if let Some(activity) = deferred_activity.as_mut() {
+            activity.literals.push(literal);
+        } else {
+            brancher.on_appearance_in_conflict_literal(literal);
+            if let Some(domain) = variable_literal_mappings.get_domain_literal(literal) {
+                brancher.on_appearance_in_conflict_integer(domain);
+            }
+        }
+    }
+
+    fn apply_activity(
+        &mut self,
+        context: &mut ConflictAnalysisContext,
+        activity: DeferredActivity,
+    ) {
+        if let Some(conflict_size) = activity.conflict_size {
+            context
+                .counters
+                .learned_clause_statistics
+                .average_conflict_size
+                .add_term(conflict_size);
+        }
+        for clause_reference in activity.clauses {
+            self.bump_clause_activity(context, clause_reference);
+        }
+        for literal in activity.literals {
+            Self::bump_literal_activity(
+                &mut self.deferred_activity,
+                context.brancher,
+                context.variable_literal_mappings,
+                literal,
+            );
+        }
+    }
+
     /// Compute the 1-UIP clause based on the current conflict. According to \[1\] a unit
     /// implication point (UIP), "represents an alternative decision assignment at the current
     /// decision level that results in the same conflict" (i.e. no matter what the variable at the
@@ -80,6 +283,11 @@ impl ResolutionConflictAnalyser {
             .push(context.assignments_propositional.true_literal);
         self.analysis_result.backjump_level = 0;
 
+        let mut derivation = context
+            .internal_parameters
+            .record_nogood_derivations
+            .then(DerivationGraph::default);
+
         let mut num_current_decision_level_literals_to_inspect = 0;
         let mut next_trail_index = context.assignments_propositional.num_trail_entries() - 1;
         let mut next_literal: Option<Literal> = None;
@@ -94,20 +302,22 @@ impl ResolutionConflictAnalyser {
                 context.get_propagation_clause_reference(propagated_literal, &mut |_| {})
             } else {
                 let conflict = context.get_conflict_reason_clause_reference(&mut |_| {});
-                context
-                    .counters
-                    .learned_clause_statistics
-                    .average_conflict_size
-                    .add_term(context.clause_allocator[conflict].len() as u64);
+                self.record_conflict_size(context, conflict);
                 conflict
             };
-            context
-                .learned_clause_manager
-                .update_clause_lbd_and_bump_activity(
-                    clause_reference,
-                    context.assignments_propositional,
-                    context.clause_allocator,
-                );
+            self.bump_clause_activity(context, clause_reference);
+
+            if let Some(derivation) = derivation.as_mut() {
+                let literals = context.clause_allocator[clause_reference].get_literal_slice();
+                if let Some(propagated_literal) = next_literal {
+                    derivation.steps.push(ResolutionStep {
+                        resolved_literal: propagated_literal,
+                        reason: literals[1..].to_vec(),
+                    });
+                } else {
+                    derivation.conflict = literals.to_vec();
+                }
+            }
 
             // process the reason literal
             // 	i.e., perform resolution and update other related internal data structures
@@ -128,17 +338,12 @@ impl ResolutionConflictAnalyser {
                     // mark the variable as seen so that we do not process it more than once
                     self.seen[reason_literal.get_propositional_variable()] = true;
 
-                    context
-                        .brancher
-                        .on_appearance_in_conflict_literal(reason_literal);
-                    if let Some(reason_domain) = context
-                        .variable_literal_mappings
-                        .get_domain_literal(reason_literal)
-                    {
-                        context
-                            .brancher
-                            .on_appearance_in_conflict_integer(reason_domain);
-                    }
+                    Self::bump_literal_activity(
+                        &mut self.deferred_activity,
+                        context.brancher,
+                        context.variable_literal_mappings,
+                        reason_literal,
+                    );
 
                     let literal_decision_level = context
                         .assignments_propositional
@@ -187,6 +392,7 @@ impl ResolutionConflictAnalyser {
                     );
 
                     self.analysis_result.learned_literals.clear();
+                    self.last_derivation = derivation;
 
                     return self.analysis_result.clone();
                 }
@@ -247,13 +453,27 @@ impl ResolutionConflictAnalyser {
             .clean_up_explanation_clauses(context.clause_allocator);
 
         pumpkin_assert_moderate!(self.debug_check_conflict_analysis_result(false, context));
+
+        if let Some(mut derivation) = derivation {
+            derivation
+                .learned_clause
+                .clone_from(&self.analysis_result.learned_literals);
+            self.last_derivation = Some(derivation);
+        } else {
+            self.last_derivation = None;
+        }
+
         // the return value is stored in the input 'analysis_result'
         self.analysis_result.clone()
     }
 
+    /// Returns the derivation of the last learned clause, if it was recorded.
+    pub(crate) fn get_last_derivation(&self) -> Option<&DerivationGraph> {
+        self.last_derivation.as_ref()
+    }
+
     // computes the learned clause containing only decision literals and stores it in
     // 'analysis_result'
-    #[allow(dead_code)]
     fn compute_all_decision_learning(
         &mut self,
         is_extracting_core: bool,
@@ -291,21 +511,36 @@ impl ResolutionConflictAnalyser {
         let mut num_propagated_literals_left_to_inspect = 0;
         let mut next_trail_index = context.assignments_propositional.num_trail_entries() - 1;
 
+        // The derivation is only recorded for learned clauses; not when extracting the core
+        let mut derivation = (context.internal_parameters.record_nogood_derivations
+            && !is_extracting_core)
+            .then(DerivationGraph::default);
+
         loop {
             // Note that the 'next_literal' is given as input.
             //  If it is none, it is none in the first iteration only
             let clause_reference = if let Some(propagated_literal) = next_literal {
                 context.get_propagation_clause_reference(propagated_literal, &mut on_analysis_step)
             } else {
-                context.get_conflict_reason_clause_reference(&mut on_analysis_step)
+                let conflict = context.get_conflict_reason_clause_reference(&mut on_analysis_step);
+                if !is_extracting_core {
+                    self.record_conflict_size(context, conflict);
+                }
+                conflict
             };
-            context
-                .learned_clause_manager
-                .update_clause_lbd_and_bump_activity(
-                    clause_reference,
-                    context.assignments_propositional,
-                    context.clause_allocator,
-                );
+            self.bump_clause_activity(context, clause_reference);
+
+            if let Some(derivation) = derivation.as_mut() {
+                let literals = context.clause_allocator[clause_reference].get_literal_slice();
+                if let Some(propagated_literal) = next_literal {
+                    derivation.steps.push(ResolutionStep {
+                        resolved_literal: propagated_literal,
+                        reason: literals[1..].to_vec(),
+                    });
+                } else {
+                    derivation.conflict = literals.to_vec();
+                }
+            }
 
             // process the reason literal
             // 	i.e., perform resolution and update other related internal data structures
@@ -328,17 +563,12 @@ impl ResolutionConflictAnalyser {
                     // mark the variable as seen so that we do not process it more than once
                     self.seen[reason_literal.get_propositional_variable()] = true;
 
-                    context
-                        .brancher
-                        .on_appearance_in_conflict_literal(reason_literal);
-                    if let Some(reason_domain) = context
-                        .variable_literal_mappings
-                        .get_domain_literal(reason_literal)
-                    {
-                        context
-                            .brancher
-                            .on_appearance_in_conflict_integer(reason_domain);
-                    }
+                    Self::bump_literal_activity(
+                        &mut self.deferred_activity,
+                        context.brancher,
+                        context.variable_literal_mappings,
+                        reason_literal,
+                    );
 
                     num_propagated_literals_left_to_inspect += context
                         .assignments_propositional
@@ -424,7 +654,10 @@ impl ResolutionConflictAnalyser {
             lits.swap(0, max_index);
         };
 
-        place_max_in_front(self.analysis_result.learned_literals.as_mut_slice());
+        // The learned clause is empty if the conflict is derived at the root
+        if !self.analysis_result.learned_literals.is_empty() {
+            place_max_in_front(self.analysis_result.learned_literals.as_mut_slice());
+        }
         if self.analysis_result.learned_literals.len() > 2 {
             place_max_in_front(&mut self.analysis_result.learned_literals[1..]);
         }
@@ -440,8 +673,18 @@ impl ResolutionConflictAnalyser {
             .clean_up_explanation_clauses(context.clause_allocator);
 
         pumpkin_assert_moderate!(
-            self.debug_check_conflict_analysis_result(is_extracting_core, context)
+            self.analysis_result.learned_literals.is_empty()
+                || self.debug_check_conflict_analysis_result(is_extracting_core, context)
         );
+
+        if !is_extracting_core {
+            self.last_derivation = derivation.map(|mut derivation| {
+                derivation
+                    .learned_clause
+                    .clone_from(&self.analysis_result.learned_literals);
+                derivation
+            });
+        }
         // the return value is stored in the input 'analysis_result'
     }
 
@@ -4,11 +4,13 @@
 use std::cmp::min;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::io::Write;
 use std::marker::PhantomData;
 use std::num::NonZero;
 use std::time::Instant;
 
 use drcp_format::steps::StepId;
+use log::debug;
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 
@@ -21,6 +23,7 @@ use super::propagation::store::PropagatorStore;
 use super::solver_statistics::SolverStatistics;
 use super::termination::TerminationCondition;
 use super::variables::IntegerVariable;
+use crate::basic_types::moving_averages::CumulativeMovingAverage;
 use crate::basic_types::moving_averages::MovingAverage;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ClauseReference;
@@ -32,6 +35,7 @@ use crate::basic_types::Inconsistency;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::PropagationStatusOneStepCP;
 use crate::basic_types::Random;
+use crate::basic_types::Solution;
 use crate::basic_types::SolutionReference;
 use crate::basic_types::StoredConflictInfo;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
@@ -42,6 +46,8 @@ use crate::branching::SolutionGuidedValueSelector;
 use crate::branching::Vsids;
 use crate::engine::clause_allocators::ClauseAllocatorBasic;
 use crate::engine::conflict_analysis::ConflictAnalysisContext;
+use crate::engine::conflict_analysis::DerivationGraph;
+use crate::engine::conflict_analysis::ResolutionMode;
 use crate::engine::cp::PropagatorQueue;
 use crate::engine::cp::WatchListCP;
 use crate::engine::cp::WatchListPropositional;
@@ -51,7 +57,10 @@ use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorInitialisationContext;
+#[cfg(doc)]
+use crate::engine::reason::LazyReason;
 use crate::engine::reason::ReasonStore;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
@@ -68,6 +77,7 @@ use crate::engine::LearningOptions;
 use crate::engine::RestartOptions;
 use crate::engine::RestartStrategy;
 use crate::engine::VariableLiteralMappings;
+use crate::predicate;
 use crate::propagators::clausal::BasicClausalPropagator;
 use crate::propagators::clausal::ClausalPropagator;
 use crate::pumpkin_assert_advanced;
@@ -76,6 +86,7 @@ use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
 use crate::statistics::statistic_logger::StatisticLogger;
 use crate::statistics::statistic_logging::should_log_statistics;
+use crate::statistics::PropagatorStatistics;
 use crate::statistics::Statistic;
 use crate::variable_names::VariableNames;
 use crate::DefaultBrancher;
@@ -195,6 +206,9 @@ pub struct ConstraintSatisfactionSolver {
     analysis_result: ConflictAnalysisResult,
     /// A set of counters updated during the search.
     counters: SolverStatistics,
+    /// The average LBD of the clauses which have been learned since the last restart; it is
+    /// reported when a restart takes place.
+    lbd_since_last_restart: CumulativeMovingAverage,
     /// Miscellaneous constant parameters used by the solver.
     internal_parameters: SatisfactionSolverOptions,
     /// The names of the variables in the solver.
@@ -202,6 +216,17 @@ pub struct ConstraintSatisfactionSolver {
     /// A map from clause references to nogood step ids in the proof.
     nogood_step_ids: KeyedVec<ClauseReference, Option<StepId>>,
     unit_nogood_step_ids: HashMap<Literal, StepId>,
+    /// The predicates which were decided by the brancher and are currently on the trail, together
+    /// with the decision level at which they were decided.
+    decided_predicates: Vec<(usize, Predicate)>,
+    /// The decisions which were on the trail when the last solution was found.
+    last_solution_decision_path: Vec<Predicate>,
+    /// The core which was extracted after the last call to solve turned out to be infeasible under
+    /// the assumptions; it is computed once and reused by subsequent extractions.
+    last_core: Option<CoreExtractionResult>,
+    /// The values which the user prefers to assign to variables; these are exposed to the
+    /// [`Brancher`] through the [`SelectionContext`].
+    preferred_values: HashMap<DomainId, i32>,
 }
 
 impl Default for ConstraintSatisfactionSolver {
@@ -235,8 +260,17 @@ pub enum CoreExtractionResult {
 pub struct SatisfactionSolverOptions {
     /// The options used by the restart strategy.
     pub restart_options: RestartOptions,
-    /// Whether learned clause minimisation should take place
+    /// Whether learned clause minimisation should take place; if disabled, neither recursive nor
+    /// semantic minimisation is applied and the learned clause is the result of resolution.
     pub learning_clause_minimisation: bool,
+    /// The learning scheme which is used to derive learned clauses from conflicts.
+    pub resolution_mode: ResolutionMode,
+    /// Whether the resolution steps which derive each learned clause should be recorded (see
+    /// [`Solver::last_learned_nogood_derivation`]); this is intended for debugging purposes.
+    pub record_nogood_derivations: bool,
+    /// Whether the number of enqueues and propagations of each propagator should be logged
+    /// alongside the other statistics (see [`Solver::propagator_statistics`]).
+    pub log_propagator_statistics: bool,
 
     /// The proof log.
     pub proof_log: ProofLog,
@@ -252,6 +286,9 @@ impl Default for SatisfactionSolverOptions {
             restart_options: RestartOptions::default(),
             proof_log: ProofLog::default(),
             learning_clause_minimisation: true,
+            resolution_mode: ResolutionMode::default(),
+            record_nogood_derivations: false,
+            log_propagator_statistics: false,
             random_generator: SmallRng::seed_from_u64(42),
         }
     }
@@ -289,7 +326,11 @@ impl ConstraintSatisfactionSolver {
 
     /// Process the stored domain events. If no events were present, this returns false. Otherwise,
     /// true is returned.
-    fn process_domain_events(&mut self) -> bool {
+    ///
+    /// If the events were caused by the propagator `propagating_propagator` and it is idempotent
+    /// (see [`Propagator::is_idempotent`]), then it is notified of the events but it is not
+    /// enqueued again.
+    fn process_domain_events(&mut self, propagating_propagator: Option<PropagatorId>) -> bool {
         // If there are no variables being watched then there is no reason to perform these
         // operations
         if self.watch_list_cp.is_watching_anything() {
@@ -311,13 +352,28 @@ impl ConstraintSatisfactionSolver {
                         &self.assignments_propositional,
                     );
 
+                    #[cfg(feature = "profiling")]
+                    let start_time = Instant::now();
+
                     let enqueue_decision =
                         propagator.notify(context, propagator_var.variable, event.into());
 
-                    if enqueue_decision == EnqueueDecision::Enqueue {
-                        self.propagator_queue
-                            .enqueue_propagator(propagator_var.propagator, propagator.priority());
+                    let is_own_event = Some(propagator_var.propagator) == propagating_propagator
+                        && propagator.is_idempotent();
+                    if enqueue_decision == EnqueueDecision::Enqueue
+                        && !is_own_event
+                        && self
+                            .propagator_queue
+                            .enqueue_propagator(propagator_var.propagator, propagator.priority())
+                    {
+                        self.cp_propagators
+                            .get_statistics_mut(propagator_var.propagator)
+                            .num_enqueues += 1;
                     }
+
+                    #[cfg(feature = "profiling")]
+                    self.cp_propagators
+                        .add_time_spent(propagator_var.propagator, start_time.elapsed());
                 }
             }
             self.last_notified_cp_trail_index = self.assignments_integer.num_trail_entries();
@@ -340,15 +396,30 @@ impl ConstraintSatisfactionSolver {
                             &self.assignments_propositional,
                         );
 
+                        #[cfg(feature = "profiling")]
+                        let start_time = Instant::now();
+
                         let enqueue_decision =
                             propagator.notify_literal(context, propagator_var.variable, event);
 
-                        if enqueue_decision == EnqueueDecision::Enqueue {
-                            self.propagator_queue.enqueue_propagator(
+                        let is_own_event = Some(propagator_var.propagator)
+                            == propagating_propagator
+                            && propagator.is_idempotent();
+                        if enqueue_decision == EnqueueDecision::Enqueue
+                            && !is_own_event
+                            && self.propagator_queue.enqueue_propagator(
                                 propagator_var.propagator,
                                 propagator.priority(),
-                            );
+                            )
+                        {
+                            self.cp_propagators
+                                .get_statistics_mut(propagator_var.propagator)
+                                .num_enqueues += 1;
                         }
+
+                        #[cfg(feature = "profiling")]
+                        self.cp_propagators
+                            .add_time_spent(propagator_var.propagator, start_time.elapsed());
                     }
                 }
             }
@@ -358,6 +429,33 @@ impl ConstraintSatisfactionSolver {
         true
     }
 
+    /// Returns the name of every propagator together with its statistics, in the order in which
+    /// the propagators were added.
+    pub(crate) fn get_propagator_statistics(&self) -> Vec<(String, PropagatorStatistics)> {
+        self.cp_propagators
+            .statistics()
+            .map(|(name, statistics)| (name.to_owned(), statistics))
+            .collect()
+    }
+
+    /// Returns the name of every propagator together with the time spent in it (in
+    /// milliseconds), in the order in which the propagators were added.
+    #[cfg(feature = "profiling")]
+    pub(crate) fn get_propagator_time_ms(&self) -> Vec<(String, u128)> {
+        self.cp_propagators
+            .time_spent()
+            .map(|(name, duration)| (name.to_owned(), duration.as_millis()))
+            .collect()
+    }
+
+    pub(crate) fn set_preferred_value(&mut self, variable: DomainId, value: i32) {
+        let _ = self.preferred_values.insert(variable, value);
+    }
+
+    pub(crate) fn get_preferred_value(&self, variable: DomainId) -> Option<i32> {
+        self.preferred_values.get(&variable).copied()
+    }
+
     /// Given a predicate, returns the corresponding literal.
     pub fn get_literal(&self, predicate: Predicate) -> Literal {
         match predicate {
@@ -466,11 +564,16 @@ impl ConstraintSatisfactionSolver {
             restart_strategy: RestartStrategy::new(solver_options.restart_options),
             cp_propagators: PropagatorStore::default(),
             counters: SolverStatistics::default(),
+            lbd_since_last_restart: CumulativeMovingAverage::default(),
             internal_parameters: solver_options,
             analysis_result: ConflictAnalysisResult::default(),
             variable_names: VariableNames::default(),
             nogood_step_ids: KeyedVec::default(),
             unit_nogood_step_ids: HashMap::default(),
+            decided_predicates: vec![],
+            last_solution_decision_path: vec![],
+            last_core: None,
+            preferred_values: HashMap::default(),
         };
 
         // we introduce a dummy variable set to true at the root level
@@ -506,6 +609,8 @@ impl ConstraintSatisfactionSolver {
         termination: &mut impl TerminationCondition,
         brancher: &mut impl Brancher,
     ) -> CSPSolverExecutionFlag {
+        self.counters.engine_statistics.num_solve_calls += 1;
+
         if self.state.is_inconsistent() {
             return CSPSolverExecutionFlag::Infeasible;
         }
@@ -528,11 +633,25 @@ impl ConstraintSatisfactionSolver {
             .get_propositional_variables()
             .collect::<Vec<_>>();
 
+        // The propositional variables linked to a domain with a preferred value are initially
+        // assigned such that their predicate agrees with the preferred value.
+        let variables_with_initial_value = variables
+            .iter()
+            .filter_map(|&variable| {
+                self.variable_literal_mappings
+                    .get_predicates(Literal::new(variable, true))
+                    .find_map(|predicate| {
+                        let value = self.get_preferred_value(predicate.get_domain())?;
+                        Some((variable, predicate.is_satisfied_by(value)))
+                    })
+            })
+            .collect();
+
         IndependentVariableValueBrancher {
             variable_selector: Vsids::new(&variables),
             value_selector: SolutionGuidedValueSelector::new(
                 &variables,
-                Vec::new(),
+                variables_with_initial_value,
                 PhaseSaving::new(&variables),
             ),
             variable_type: PhantomData,
@@ -552,7 +671,23 @@ impl ConstraintSatisfactionSolver {
         // going through all the propagators
         if should_log_statistics() {
             self.counters.log(StatisticLogger::default());
-            for (index, propagator) in self.cp_propagators.iter_propagators().enumerate() {
+            for (index, (propagator, (_, statistics))) in self
+                .cp_propagators
+                .iter_propagators()
+                .zip(self.cp_propagators.statistics())
+                .enumerate()
+            {
+                // The engine statistics depend on how incrementally a propagator is implemented,
+                // so they are only logged on request; their names do not contain the name of the
+                // propagator such that they can be compared between implementations
+                if self.internal_parameters.log_propagator_statistics {
+                    statistics.log(StatisticLogger::new([
+                        "propagator",
+                        "number",
+                        index.to_string().as_str(),
+                        "engine",
+                    ]));
+                }
                 propagator.log_statistics(StatisticLogger::new([
                     propagator.name(),
                     "number",
@@ -707,6 +842,10 @@ impl ConstraintSatisfactionSolver {
     /// }
     /// ```
     pub fn extract_clausal_core(&mut self, brancher: &mut impl Brancher) -> CoreExtractionResult {
+        if let Some(core) = &self.last_core {
+            return core.clone();
+        }
+
         let mut conflict_analysis_context = ConflictAnalysisContext {
             propagator_store: &self.cp_propagators,
             assumptions: &self.assumptions,
@@ -728,6 +867,7 @@ impl ConstraintSatisfactionSolver {
         let core = self
             .conflict_analyser
             .compute_clausal_core(&mut conflict_analysis_context);
+        self.last_core = Some(core.clone());
 
         if !self.state.is_infeasible() {
             self.restore_state_at_root(brancher);
@@ -736,6 +876,13 @@ impl ConstraintSatisfactionSolver {
         core
     }
 
+    /// Returns the core which was extracted (see
+    /// [`ConstraintSatisfactionSolver::extract_clausal_core`]) after the last call to solve, or
+    /// [`None`] if no core has been extracted since then.
+    pub fn get_last_core(&self) -> Option<&CoreExtractionResult> {
+        self.last_core.as_ref()
+    }
+
     #[allow(unused)]
     pub(crate) fn get_conflict_reasons(
         &mut self,
@@ -853,6 +1000,115 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
+    /// Propagates `predicate` as a decision at the root level and returns the result of `inspect`
+    /// on the resulting integer domains, or `None` if this leads to a conflict (which is always
+    /// the case if the solver is in an infeasible state); afterwards, the solver is restored to
+    /// the root level.
+    ///
+    /// Nothing is learned from a conflict. The solver should be at the root level.
+    pub(crate) fn probe<T>(
+        &mut self,
+        predicate: Predicate,
+        inspect: impl FnOnce(&AssignmentsInteger) -> T,
+    ) -> Option<T> {
+        pumpkin_assert_simple!(
+            self.get_decision_level() == 0,
+            "probing is only possible at the root level"
+        );
+        if self.state.is_infeasible() {
+            return None;
+        }
+
+        let literal = self.get_literal(predicate);
+        if self.assignments_propositional.is_literal_assigned(literal) {
+            return self
+                .assignments_propositional
+                .is_literal_assigned_true(literal)
+                .then(|| inspect(&self.assignments_integer));
+        }
+
+        self.declare_new_decision_level();
+        self.assignments_propositional
+            .enqueue_decision_literal(literal);
+        self.propagate_enqueued();
+
+        let result = (!self.state.conflicting()).then(|| inspect(&self.assignments_integer));
+
+        self.backtrack(0, &mut DummyBrancher);
+        self.state.declare_ready();
+
+        result
+    }
+
+    /// Creates a copy of the solver at the root level which does not share any state with this
+    /// solver and which uses the provided `random_generator`; the lazy reasons of root-level
+    /// propagations are computed in the copy only.
+    ///
+    /// Returns `None` if one of the propagators or one of these lazy reasons cannot be copied (see
+    /// [`Propagator::clone_boxed`] and [`LazyReason::clone_boxed`]). The proof log is not copied, i.e. the copy does not log a
+    /// proof.
+    pub(crate) fn clone_with_state(
+        &self,
+        random_generator: SmallRng,
+    ) -> Option<ConstraintSatisfactionSolver> {
+        pumpkin_assert_simple!(
+            self.get_decision_level() == 0,
+            "the solver can only be cloned at the root level"
+        );
+
+        let cp_propagators = self.cp_propagators.try_clone()?;
+        let reason_store = self.reason_store.clone_computed(PropagationContext::new(
+            &self.assignments_integer,
+            &self.assignments_propositional,
+        ))?;
+
+        Some(ConstraintSatisfactionSolver {
+            state: self.state.clone(),
+            assignments_propositional: self.assignments_propositional.clone(),
+            clausal_propagator: self.clausal_propagator.clone(),
+            cp_propagators,
+            clause_allocator: self.clause_allocator.clone(),
+            learned_clause_manager: self.learned_clause_manager.clone(),
+            restart_strategy: self.restart_strategy.clone(),
+            assumptions: self.assumptions.clone(),
+            conflict_analyser: self.conflict_analyser.clone(),
+            assignments_integer: self.assignments_integer.clone(),
+            watch_list_cp: self.watch_list_cp.clone(),
+            watch_list_propositional: self.watch_list_propositional.clone(),
+            propositional_trail_index: self.propositional_trail_index,
+            last_notified_cp_trail_index: self.last_notified_cp_trail_index,
+            propagator_queue: self.propagator_queue.clone(),
+            reason_store,
+            event_drain: self.event_drain.clone(),
+            backtrack_event_drain: self.backtrack_event_drain.clone(),
+            variable_literal_mappings: self.variable_literal_mappings.clone(),
+            cp_trail_synced_position: self.cp_trail_synced_position,
+            sat_trail_synced_position: self.sat_trail_synced_position,
+            explanation_clause_manager: self.explanation_clause_manager.clone(),
+            true_literal: self.true_literal,
+            false_literal: self.false_literal,
+            analysis_result: self.analysis_result.clone(),
+            counters: self.counters,
+            lbd_since_last_restart: self.lbd_since_last_restart,
+            internal_parameters: SatisfactionSolverOptions {
+                restart_options: self.internal_parameters.restart_options,
+                learning_clause_minimisation: self.internal_parameters.learning_clause_minimisation,
+                resolution_mode: self.internal_parameters.resolution_mode,
+                record_nogood_derivations: self.internal_parameters.record_nogood_derivations,
+                log_propagator_statistics: self.internal_parameters.log_propagator_statistics,
+                proof_log: ProofLog::default(),
+                random_generator,
+            },
+            variable_names: self.variable_names.clone(),
+            nogood_step_ids: self.nogood_step_ids.clone(),
+            unit_nogood_step_ids: self.unit_nogood_step_ids.clone(),
+            decided_predicates: self.decided_predicates.clone(),
+            last_solution_decision_path: self.last_solution_decision_path.clone(),
+            last_core: self.last_core.clone(),
+            preferred_values: self.preferred_values.clone(),
+        })
+    }
+
     fn synchronise_propositional_trail_based_on_integer_trail(&mut self) -> Option<ConflictInfo> {
         // for each entry on the integer trail, we now add the equivalent propositional
         // representation on the propositional trail  note that only one literal per
@@ -925,6 +1181,8 @@ impl ConstraintSatisfactionSolver {
 
         if self.assignments_integer.num_domains() == 0 {
             self.sat_trail_synced_position = self.assignments_propositional.num_trail_entries();
+            // Propagators which only watch literals should still be notified
+            let _ = self.process_domain_events(None);
             return Ok(());
         }
 
@@ -943,7 +1201,7 @@ impl ConstraintSatisfactionSolver {
         // trail
         self.cp_trail_synced_position = self.assignments_integer.num_trail_entries();
 
-        let _ = self.process_domain_events();
+        let _ = self.process_domain_events(None);
 
         Ok(())
     }
@@ -982,6 +1240,7 @@ impl ConstraintSatisfactionSolver {
              Missed extracting the core?"
         );
         self.state.declare_solving();
+        self.last_core = None;
         assumptions.clone_into(&mut self.assumptions);
     }
 
@@ -1001,6 +1260,16 @@ impl ConstraintSatisfactionSolver {
                     &self.assignments_propositional,
                     &mut self.clause_allocator,
                     &mut self.clausal_propagator,
+                    |clause_reference, literals| {
+                        // The nogood is also removed from the proof, so the proof checker does
+                        // not have to keep propagating it
+                        if let Some(step_id) = self.nogood_step_ids[clause_reference].take() {
+                            self.internal_parameters
+                                .proof_log
+                                .log_deleted_clause(step_id, literals.iter().copied())
+                                .expect("Failed to write proof log");
+                        }
+                    },
                 );
 
             self.propagate_enqueued();
@@ -1058,13 +1327,18 @@ impl ConstraintSatisfactionSolver {
             }
             Ok(())
         } else {
-            let decided_predicate = brancher.next_decision(&mut SelectionContext::new(
-                &self.assignments_integer,
-                &self.assignments_propositional,
-                &mut self.internal_parameters.random_generator,
-            ));
+            let decided_predicate = brancher.next_decision(
+                &mut SelectionContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                    &mut self.internal_parameters.random_generator,
+                )
+                .with_preferred_values(&self.preferred_values),
+            );
             if let Some(predicate) = decided_predicate {
                 self.counters.engine_statistics.num_decisions += 1;
+                self.decided_predicates
+                    .push((self.get_decision_level(), predicate));
                 self.assignments_propositional
                     .enqueue_decision_literal(match predicate {
                         Predicate::IntegerPredicate(integer_predicate) => {
@@ -1082,12 +1356,158 @@ impl ConstraintSatisfactionSolver {
                     });
                 Ok(())
             } else {
+                self.record_decision_path();
                 self.state.declare_solution_found();
                 Err(CSPSolverExecutionFlag::Feasible)
             }
         }
     }
 
+    /// Greedily extends the assignment under the provided `assumptions` to a complete assignment,
+    /// without performing any search; after propagating, the first unfixed integer variable is
+    /// assigned to its lower bound and the first unassigned propositional variable is assigned to
+    /// false, propagating after every decision.
+    ///
+    /// Returns the found solution, or [`None`] if an assumption is violated or a conflict is
+    /// encountered. No nogoods are learned from the conflict, and the solver is restored to the
+    /// root afterwards.
+    pub fn greedy_complete(&mut self, assumptions: &[Literal]) -> Option<Solution> {
+        if self.state.is_inconsistent() {
+            return None;
+        }
+
+        self.initialise(assumptions);
+
+        let result = loop {
+            self.propagate_enqueued();
+
+            if self.state.conflicting() {
+                if self.assignments_propositional.is_at_the_root_level() {
+                    self.state.declare_infeasible();
+                    return None;
+                }
+                break None;
+            }
+
+            self.declare_new_decision_level();
+
+            if let Some(assumption_literal) = self.peek_next_assumption_literal() {
+                if !self.enqueue_assumption_literal(assumption_literal) {
+                    break None;
+                }
+                continue;
+            }
+
+            let integer_decision = self
+                .assignments_integer
+                .get_domains()
+                .find(|&domain_id| !self.assignments_integer.is_domain_assigned(domain_id))
+                .map(|domain_id| {
+                    let lower_bound = self.assignments_integer.get_lower_bound(domain_id);
+                    self.get_literal(predicate![domain_id <= lower_bound])
+                });
+            let decision = integer_decision.or_else(|| {
+                self.assignments_propositional
+                    .get_propositional_variables()
+                    .find(|&variable| {
+                        !self
+                            .assignments_propositional
+                            .is_variable_assigned(variable)
+                    })
+                    .map(|variable| Literal::new(variable, false))
+            });
+
+            match decision {
+                Some(literal) => {
+                    self.assignments_propositional
+                        .enqueue_decision_literal(literal);
+                }
+                None => break Some(self.get_solution_reference().into()),
+            }
+        };
+
+        self.restore_state_at_root(&mut DummyBrancher);
+        result
+    }
+
+    /// Returns the derivation of the last learned clause, if
+    /// [`SatisfactionSolverOptions::record_nogood_derivations`] is enabled and a clause has been
+    /// learned.
+    pub fn get_last_learned_nogood_derivation(&self) -> Option<&DerivationGraph> {
+        self.conflict_analyser.get_last_derivation()
+    }
+
+    /// Writes the learned clauses which are currently in the database, together with the learned
+    /// unit clauses, in the DIMACS format; the DIMACS variable `i` corresponds to the
+    /// [`PropositionalVariable`] with index `i`.
+    ///
+    /// Learned clauses which are satisfied by the true literal are skipped, and occurrences of the
+    /// false literal are removed from the learned clauses.
+    pub fn write_learned_clauses_dimacs(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let true_literal = self.assignments_propositional.true_literal;
+        let false_literal = self.assignments_propositional.false_literal;
+
+        let mut unit_clauses = self
+            .unit_nogood_step_ids
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        unit_clauses.sort_by_key(|literal| literal.to_u32());
+
+        let clauses = unit_clauses
+            .iter()
+            .map(std::slice::from_ref)
+            .chain(
+                self.learned_clause_manager
+                    .learned_clauses()
+                    .map(|clause_reference| {
+                        self.clause_allocator[clause_reference].get_literal_slice()
+                    }),
+            )
+            .filter(|clause| !clause.contains(&true_literal))
+            .collect::<Vec<_>>();
+
+        writeln!(
+            writer,
+            "p cnf {} {}",
+            self.assignments_propositional.num_propositional_variables() - 1,
+            clauses.len()
+        )?;
+        for clause in clauses {
+            for &literal in clause.iter().filter(|&&literal| literal != false_literal) {
+                let index = literal.get_propositional_variable().get_index() as i64;
+                let dimacs_literal = if literal.is_positive() { index } else { -index };
+                write!(writer, "{dimacs_literal} ")?;
+            }
+            writeln!(writer, "0")?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the decisions which are currently on the trail, in the order in which they were
+    /// made. Assumptions are not considered to be decisions.
+    ///
+    /// Every decision is stored as the predicate which was decided by the brancher; several
+    /// predicates can share the same [`Literal`] (e.g. `[x >= 3]` and `[x == 3]` if 3 is the
+    /// upper bound of `x`), so it cannot be recovered from the literal on the trail.
+    fn record_decision_path(&mut self) {
+        self.last_solution_decision_path = self
+            .decided_predicates
+            .iter()
+            .map(|&(_, predicate)| predicate)
+            .collect();
+    }
+
+    /// Returns the decisions which were on the trail when the last solution was found, in the
+    /// order in which they were made; every decision is returned as the predicate which was
+    /// decided by the brancher.
+    ///
+    /// If no solution has been found yet, the returned slice is empty.
+    pub fn get_last_solution_decision_path(&self) -> &[Predicate] {
+        &self.last_solution_decision_path
+    }
+
     /// Returns true if the assumption was successfully enqueued, and false otherwise
     pub(crate) fn enqueue_assumption_literal(&mut self, assumption_literal: Literal) -> bool {
         // Case 1: the assumption is unassigned, assign it
@@ -1140,6 +1560,11 @@ impl ConstraintSatisfactionSolver {
         pumpkin_assert_moderate!(self.state.conflicting());
 
         self.analysis_result = self.compute_learned_clause(brancher);
+        self.lbd_since_last_restart.add_term(
+            self.counters
+                .learned_clause_statistics
+                .last_learned_clause_lbd,
+        );
 
         self.process_learned_clause(brancher);
 
@@ -1165,7 +1590,7 @@ impl ConstraintSatisfactionSolver {
             nogood_step_ids: &self.nogood_step_ids,
         };
         self.conflict_analyser
-            .compute_1uip(&mut conflict_analysis_context)
+            .compute_learned_clause(&mut conflict_analysis_context)
     }
 
     fn process_learned_clause(&mut self, brancher: &mut impl Brancher) {
@@ -1257,6 +1682,9 @@ impl ConstraintSatisfactionSolver {
 
         self.counters.engine_statistics.num_restarts += 1;
 
+        debug!("{}", self.describe_restart());
+        self.lbd_since_last_restart = CumulativeMovingAverage::default();
+
         self.backtrack(0, brancher);
 
         self.restart_strategy.notify_restart();
@@ -1264,9 +1692,29 @@ impl ConstraintSatisfactionSolver {
         self.declare_new_decision_level();
     }
 
+    /// Returns the message which is logged when a restart takes place; it contains the number of
+    /// conflicts, the number of learned nogoods, and the average LBD since the last restart.
+    fn describe_restart(&self) -> String {
+        format!(
+            "Restart {}: {} conflicts, {} learned nogoods, {:.2} average LBD since the last restart",
+            self.counters.engine_statistics.num_restarts,
+            self.counters.engine_statistics.num_conflicts,
+            self.learned_clause_manager.num_learned_clauses(),
+            self.lbd_since_last_restart.value(),
+        )
+    }
+
     pub(crate) fn backtrack(&mut self, backtrack_level: usize, brancher: &mut impl Brancher) {
         pumpkin_assert_simple!(backtrack_level < self.get_decision_level());
 
+        while self
+            .decided_predicates
+            .last()
+            .is_some_and(|&(decision_level, _)| decision_level > backtrack_level)
+        {
+            let _ = self.decided_predicates.pop();
+        }
+
         // We clear all of the unprocessed events from the watch list since synchronisation, we do
         // not need to process these events
         if self.watch_list_cp.is_watching_anything() {
@@ -1439,9 +1887,22 @@ impl ConstraintSatisfactionSolver {
                 propagator_id,
             );
 
-            propagator.propagate(context)
+            #[cfg(feature = "profiling")]
+            let start_time = Instant::now();
+
+            let status = propagator.propagate(context);
+
+            #[cfg(feature = "profiling")]
+            self.cp_propagators
+                .add_time_spent(propagator_id, start_time.elapsed());
+
+            status
         };
 
+        let statistics = self.cp_propagators.get_statistics_mut(propagator_id);
+        statistics.num_propagations += 1;
+        statistics.num_conflicts += propagation_status.is_err() as u64;
+
         if is_at_root && self.internal_parameters.proof_log.is_logging_inferences() {
             self.log_root_propagation_to_proof(cp_trail_length, tag);
         }
@@ -1469,7 +1930,7 @@ impl ConstraintSatisfactionSolver {
             }
 
             Ok(()) => {
-                let _ = self.process_domain_events();
+                let _ = self.process_domain_events(Some(propagator_id));
 
                 PropagationStatusOneStepCP::PropagationHappened
             }
@@ -1579,6 +2040,12 @@ impl ConstraintSatisfactionSolver {
                         .assignments_propositional
                         .get_literal_reason_constraint(premise);
 
+                    // Unit clauses which are added to the solver are assigned at the root without
+                    // a reason. They are part of the model, so no proof step is needed for them.
+                    if reason.is_null() {
+                        continue;
+                    }
+
                     // If the reason were a CP propagation, then `self.unit_nogood_step_ids` would
                     // have contained `premise`.
                     assert!(
@@ -1706,8 +2173,14 @@ impl ConstraintSatisfactionSolver {
             self.state.declare_infeasible();
             Err(ConstraintOperationError::InfeasiblePropagator)
         } else {
-            self.propagator_queue
-                .enqueue_propagator(new_propagator_id, new_propagator.priority());
+            if self
+                .propagator_queue
+                .enqueue_propagator(new_propagator_id, new_propagator.priority())
+            {
+                self.cp_propagators
+                    .get_statistics_mut(new_propagator_id)
+                    .num_enqueues += 1;
+            }
 
             self.propagate_enqueued();
 
@@ -1778,7 +2251,7 @@ impl ConstraintSatisfactionSolver {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 enum CSPSolverStateInternal {
     #[default]
     Ready,
@@ -1794,7 +2267,7 @@ enum CSPSolverStateInternal {
     Timeout,
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct CSPSolverState {
     internal_state: CSPSolverStateInternal,
 }
@@ -1914,12 +2387,49 @@ impl Brancher for DummyBrancher {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Mutex;
+    use std::sync::Once;
+    use std::thread;
+    use std::thread::ThreadId;
+
+    use enumset::EnumSet;
+    use regex::Regex;
+
     use super::ConstraintSatisfactionSolver;
     use super::CoreExtractionResult;
+    use super::DummyBrancher;
+    use super::SatisfactionSolverOptions;
+    use crate::basic_types::moving_averages::MovingAverage;
     use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::basic_types::HashSet;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::conjunction;
+    use crate::engine::conflict_analysis::ResolutionMode;
+    use crate::engine::cp::propagation::ReadDomains;
+    use crate::engine::domain_events::DomainEvents;
+    use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+    use crate::engine::predicates::predicate::Predicate;
+    use crate::engine::propagation::EnqueueDecision;
+    use crate::engine::propagation::LocalId;
+    use crate::engine::propagation::PropagationContext;
+    use crate::engine::propagation::PropagationContextMut;
+    use crate::engine::propagation::Propagator;
+    use crate::engine::propagation::PropagatorInitialisationContext;
     use crate::engine::reason::ReasonRef;
     use crate::engine::termination::indefinite::Indefinite;
+    use crate::engine::variables::DomainId;
+    use crate::engine::variables::IntegerVariable;
     use crate::engine::variables::Literal;
+    use crate::engine::variables::TransformableVariable;
+    use crate::engine::IntDomainEvent;
+    use crate::engine::LearningOptions;
+    use crate::engine::RestartOptions;
     use crate::predicate;
     use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
 
@@ -2275,4 +2785,710 @@ mod tests {
         let result = solver.add_propagator(propagator, None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn best_of_both_learns_clause_with_lowest_lbd() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                resolution_mode: ResolutionMode::BestOfBoth,
+                ..Default::default()
+            },
+        );
+        let [a, b, c, p, q, y, z] =
+            [(); 7].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+
+        let _ = solver.add_clause([!a, p]);
+        let _ = solver.add_clause([!b, !p, q]);
+        let _ = solver.add_clause([!c, y]);
+        let _ = solver.add_clause([!c, z]);
+        let _ = solver.add_clause([!y, !z, !q]);
+
+        for decision in [a, b, c] {
+            solver.declare_new_decision_level();
+            solver
+                .assignments_propositional
+                .enqueue_decision_literal(decision);
+            solver.propagate_enqueued();
+        }
+        assert!(solver.state.conflicting());
+
+        // The 1UIP clause [!c, !q] spans two decision levels, whereas the all-decision clause
+        // [!c, !b, !a] spans three decision levels
+        let result = solver.compute_learned_clause(&mut DummyBrancher);
+        assert_eq!(!c, result.learned_literals[0]);
+        assert_eq!(
+            HashSet::from_iter([!c, !q]),
+            result
+                .learned_literals
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>()
+        );
+        assert_eq!(2, result.backjump_level);
+    }
+
+    #[test]
+    fn all_decision_mode_learns_clause_over_decisions() {
+        for resolution_mode in [ResolutionMode::OneUip, ResolutionMode::AllDecision] {
+            let mut solver = ConstraintSatisfactionSolver::new(
+                LearningOptions::default(),
+                SatisfactionSolverOptions {
+                    resolution_mode,
+                    ..Default::default()
+                },
+            );
+            let [a, b, c, p, q, y, z] =
+                [(); 7].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+
+            let _ = solver.add_clause([!a, p]);
+            let _ = solver.add_clause([!b, !p, q]);
+            let _ = solver.add_clause([!c, y]);
+            let _ = solver.add_clause([!c, z]);
+            let _ = solver.add_clause([!y, !z, !q]);
+
+            for decision in [a, b, c] {
+                solver.declare_new_decision_level();
+                solver
+                    .assignments_propositional
+                    .enqueue_decision_literal(decision);
+                solver.propagate_enqueued();
+            }
+            assert!(solver.state.conflicting());
+
+            let expected_clause = match resolution_mode {
+                ResolutionMode::AllDecision => HashSet::from_iter([!c, !b, !a]),
+                _ => HashSet::from_iter([!c, !q]),
+            };
+
+            let result = solver.compute_learned_clause(&mut DummyBrancher);
+            assert_eq!(!c, result.learned_literals[0]);
+            assert_eq!(
+                expected_clause,
+                result
+                    .learned_literals
+                    .iter()
+                    .copied()
+                    .collect::<HashSet<_>>(),
+                "unexpected clause for {resolution_mode}"
+            );
+        }
+    }
+
+    /// A [`Brancher`] which records the literals which it is notified of during conflict analysis.
+    #[derive(Default)]
+    struct ConflictLiteralRecorder {
+        literals: Vec<Literal>,
+    }
+
+    impl Brancher for ConflictLiteralRecorder {
+        fn next_decision(&mut self, _: &mut SelectionContext) -> Option<Predicate> {
+            None
+        }
+
+        fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+            self.literals.push(literal);
+        }
+    }
+
+    #[test]
+    fn all_decision_mode_only_performs_all_decision_analysis() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                resolution_mode: ResolutionMode::AllDecision,
+                record_nogood_derivations: true,
+                ..Default::default()
+            },
+        );
+        let [a, b, c, p, q, y, z] =
+            [(); 7].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+
+        let _ = solver.add_clause([!a, p]);
+        let _ = solver.add_clause([!b, !p, q]);
+        let _ = solver.add_clause([!c, y]);
+        let _ = solver.add_clause([!c, z]);
+        let _ = solver.add_clause([!y, !z, !q]);
+
+        for decision in [a, b, c] {
+            solver.declare_new_decision_level();
+            solver
+                .assignments_propositional
+                .enqueue_decision_literal(decision);
+            solver.propagate_enqueued();
+        }
+        assert!(solver.state.conflicting());
+
+        let mut brancher = ConflictLiteralRecorder::default();
+        let result = solver.compute_learned_clause(&mut brancher);
+
+        // Every literal involved in the conflict is reported to the brancher exactly once
+        let reported = brancher.literals.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(reported.len(), brancher.literals.len());
+
+        let derivation = solver
+            .get_last_learned_nogood_derivation()
+            .expect("the derivation is recorded");
+        assert_eq!(result.learned_literals, derivation.learned_clause);
+        assert_eq!(
+            HashSet::from_iter([!c, !b, !a]),
+            derivation
+                .learned_clause
+                .iter()
+                .copied()
+                .collect::<HashSet<_>>()
+        );
+    }
+
+    /// A test propagator which propagates `consequent` due to `antecedent`, but only once
+    /// `trigger` is true; this delays the propagation to the decision level of `trigger`.
+    struct DelayedImplication {
+        trigger: Literal,
+        antecedent: Literal,
+        consequent: Literal,
+    }
+
+    impl Propagator for DelayedImplication {
+        fn name(&self) -> &str {
+            "DelayedImplication"
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register_literal(
+                self.trigger,
+                DomainEvents::ASSIGNED_TRUE,
+                LocalId::from(0),
+            );
+            Ok(())
+        }
+
+        fn debug_propagate_from_scratch(
+            &self,
+            mut context: PropagationContextMut,
+        ) -> PropagationStatusCP {
+            if context.is_literal_true(self.trigger) && context.is_literal_true(self.antecedent) {
+                context.assign_literal(
+                    self.consequent,
+                    true,
+                    PropositionalConjunction::from(vec![Predicate::from(self.antecedent)]),
+                )?;
+            }
+            Ok(())
+        }
+    }
+
+    /// A test propagator which reports a conflict once `variable` is at least 1 and all of the
+    /// `literals` are true.
+    struct ConflictOnLowerBound {
+        variable: DomainId,
+        literals: Vec<Literal>,
+    }
+
+    impl Propagator for ConflictOnLowerBound {
+        fn name(&self) -> &str {
+            "ConflictOnLowerBound"
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register(self.variable, DomainEvents::LOWER_BOUND, LocalId::from(0));
+            Ok(())
+        }
+
+        fn debug_propagate_from_scratch(
+            &self,
+            context: PropagationContextMut,
+        ) -> PropagationStatusCP {
+            if context.lower_bound(&self.variable) >= 1
+                && self
+                    .literals
+                    .iter()
+                    .all(|&literal| context.is_literal_true(literal))
+            {
+                let mut conflict = PropositionalConjunction::from(
+                    self.literals
+                        .iter()
+                        .map(|&literal| Predicate::from(literal))
+                        .collect::<Vec<_>>(),
+                );
+                conflict.add(predicate![self.variable >= 1]);
+                return Err(conflict.into());
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn best_of_both_learns_all_decision_clause_with_lower_lbd() {
+        for resolution_mode in [ResolutionMode::OneUip, ResolutionMode::BestOfBoth] {
+            let mut solver = ConstraintSatisfactionSolver::new(
+                LearningOptions::default(),
+                SatisfactionSolverOptions {
+                    resolution_mode,
+                    ..Default::default()
+                },
+            );
+            let [a, b, c, p, q] =
+                [(); 5].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+            let x = solver.create_new_integer_variable(0, 1, None);
+            let d = solver.get_literal(predicate![x >= 1]);
+
+            // Both `p` and `q` are implied by `a`, but they are propagated at the decision levels
+            // of `b` and `c` respectively; the decision `d` then leads to a conflict with `p` and
+            // `q`
+            for (trigger, consequent) in [(b, p), (c, q)] {
+                let result = solver.add_propagator(
+                    DelayedImplication {
+                        trigger,
+                        antecedent: a,
+                        consequent,
+                    },
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+            let result = solver.add_propagator(
+                ConflictOnLowerBound {
+                    variable: x,
+                    literals: vec![p, q],
+                },
+                None,
+            );
+            assert!(result.is_ok());
+
+            for decision in [a, b, c, d] {
+                solver.declare_new_decision_level();
+                solver
+                    .assignments_propositional
+                    .enqueue_decision_literal(decision);
+                solver.propagate_enqueued();
+            }
+            assert!(solver.state.conflicting());
+
+            // The 1UIP clause [!d, !p, !q] spans three decision levels, whereas the all-decision
+            // clause [!d, !a] spans two decision levels
+            let expected_clause = match resolution_mode {
+                ResolutionMode::BestOfBoth => HashSet::from_iter([!d, !a]),
+                _ => HashSet::from_iter([!d, !p, !q]),
+            };
+
+            let mut brancher = ConflictLiteralRecorder::default();
+            let result = solver.compute_learned_clause(&mut brancher);
+            assert_eq!(!d, result.learned_literals[0]);
+            assert_eq!(
+                expected_clause,
+                result
+                    .learned_literals
+                    .iter()
+                    .copied()
+                    .collect::<HashSet<_>>(),
+                "unexpected clause for {resolution_mode}"
+            );
+
+            // Only the analysis of the learned clause is reported to the brancher
+            let reported = brancher.literals.iter().copied().collect::<HashSet<_>>();
+            assert_eq!(reported.len(), brancher.literals.len());
+        }
+    }
+
+    #[test]
+    fn disabling_minimisation_keeps_dominated_literals() {
+        for learning_clause_minimisation in [true, false] {
+            let mut solver = ConstraintSatisfactionSolver::new(
+                LearningOptions::default(),
+                SatisfactionSolverOptions {
+                    learning_clause_minimisation,
+                    ..Default::default()
+                },
+            );
+            let [a, b, p, y] =
+                [(); 4].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+
+            let _ = solver.add_clause([!a, p]);
+            let _ = solver.add_clause([!b, !p, y]);
+            let _ = solver.add_clause([!y, !a, !b]);
+
+            for decision in [a, b] {
+                solver.declare_new_decision_level();
+                solver
+                    .assignments_propositional
+                    .enqueue_decision_literal(decision);
+                solver.propagate_enqueued();
+            }
+            assert!(solver.state.conflicting());
+
+            // The literal !p is implied by !a, so it is removed by recursive minimisation
+            let expected_clause = if learning_clause_minimisation {
+                HashSet::from_iter([!b, !a])
+            } else {
+                HashSet::from_iter([!b, !a, !p])
+            };
+
+            let result = solver.compute_learned_clause(&mut DummyBrancher);
+            assert_eq!(
+                expected_clause,
+                result
+                    .learned_literals
+                    .iter()
+                    .copied()
+                    .collect::<HashSet<_>>(),
+                "unexpected clause when minimisation is {learning_clause_minimisation}"
+            );
+        }
+    }
+
+    #[test]
+    fn lbd_of_learned_clause_is_recorded() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let [a, b, c, d] =
+            [(); 4].map(|_| Literal::new(solver.create_new_propositional_variable(None), true));
+
+        let _ = solver.add_clause([!a, !b, !c, d]);
+        let _ = solver.add_clause([!a, !b, !c, !d]);
+
+        for decision in [a, b, c] {
+            solver.declare_new_decision_level();
+            solver
+                .assignments_propositional
+                .enqueue_decision_literal(decision);
+            solver.propagate_enqueued();
+        }
+        assert!(solver.state.conflicting());
+
+        // The learned clause [!a, !b, !c] spans three decision levels
+        let _ = solver.compute_learned_clause(&mut DummyBrancher);
+
+        let statistics = &solver.counters.learned_clause_statistics;
+        assert_eq!(3, statistics.last_learned_clause_lbd);
+        assert_eq!(3.0, statistics.average_learned_clause_lbd.value());
+    }
+
+    /// A logger which records the messages of the solver per thread, such that a test can inspect
+    /// the messages which were logged while it was running.
+    struct RecordingLogger {
+        records: Mutex<Vec<(ThreadId, String)>>,
+    }
+
+    impl log::Log for RecordingLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == SOLVER_LOG_TARGET
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.records
+                    .lock()
+                    .unwrap()
+                    .push((thread::current().id(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    const SOLVER_LOG_TARGET: &str = "pumpkin_solver::engine::constraint_satisfaction_solver";
+
+    static RECORDING_LOGGER: RecordingLogger = RecordingLogger {
+        records: Mutex::new(Vec::new()),
+    };
+
+    /// Installs the [`RecordingLogger`] and returns the messages which have been logged by the
+    /// solver on the current thread.
+    fn recorded_messages_of_current_thread() -> Vec<String> {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&RECORDING_LOGGER).expect("no other logger is installed in the tests");
+            log::set_max_level(log::LevelFilter::Debug);
+        });
+
+        RECORDING_LOGGER
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(thread_id, _)| *thread_id == thread::current().id())
+            .map(|(_, message)| message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn restarts_are_reported_with_statistics() {
+        // Ensures that the messages are recorded from the start
+        let _ = recorded_messages_of_current_thread();
+
+        // Restart after every conflict without ever blocking the restart
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                restart_options: RestartOptions {
+                    base_interval: 1,
+                    min_num_conflicts_before_first_restart: 0,
+                    lbd_coef: 0.0,
+                    num_assigned_coef: f64::MAX,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+
+        // The pigeonhole problem with 6 pigeons and 5 holes
+        let pigeons = (0..6)
+            .map(|_| {
+                (0..5)
+                    .map(|_| Literal::new(solver.create_new_propositional_variable(None), true))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for pigeon in pigeons.iter() {
+            let _ = solver.add_clause(pigeon.iter().copied());
+        }
+        for hole in 0..5 {
+            for (index, first) in pigeons.iter().enumerate() {
+                for second in pigeons[index + 1..].iter() {
+                    let _ = solver.add_clause([!first[hole], !second[hole]]);
+                }
+            }
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(CSPSolverExecutionFlag::Infeasible, result);
+        assert!(solver.counters.engine_statistics.num_restarts > 0);
+
+        // Every restart is logged with its number, the number of conflicts, the number of learned
+        // nogoods and the average LBD since the last restart
+        let restart_message = Regex::new(
+            r"^Restart (\d+): (\d+) conflicts, \d+ learned nogoods, \d+\.\d{2} average LBD since the last restart$",
+        )
+        .unwrap();
+        let restarts = recorded_messages_of_current_thread()
+            .into_iter()
+            .filter(|message| message.starts_with("Restart"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            solver.counters.engine_statistics.num_restarts as usize,
+            restarts.len()
+        );
+
+        let mut previous_num_conflicts = 0;
+        for (index, message) in restarts.iter().enumerate() {
+            let captures = restart_message
+                .captures(message)
+                .unwrap_or_else(|| panic!("unexpected restart message: {message}"));
+            assert_eq!(index + 1, captures[1].parse::<usize>().unwrap());
+
+            let num_conflicts = captures[2].parse::<u64>().unwrap();
+            assert!(previous_num_conflicts < num_conflicts);
+            assert!(num_conflicts <= solver.counters.engine_statistics.num_conflicts);
+            previous_num_conflicts = num_conflicts;
+        }
+    }
+
+    /// A test propagator which raises the lower bound of `variable` to `bound` and counts the
+    /// number of times that it has been called.
+    struct RaiseLowerBound {
+        variable: DomainId,
+        bound: i32,
+        is_idempotent: bool,
+        num_calls: Rc<Cell<usize>>,
+    }
+
+    impl Propagator for RaiseLowerBound {
+        fn name(&self) -> &str {
+            "RaiseLowerBound"
+        }
+
+        fn is_idempotent(&self) -> bool {
+            self.is_idempotent
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register(self.variable, DomainEvents::LOWER_BOUND, LocalId::from(0));
+            Ok(())
+        }
+
+        fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+            self.num_calls.set(self.num_calls.get() + 1);
+            self.debug_propagate_from_scratch(context)
+        }
+
+        fn debug_propagate_from_scratch(
+            &self,
+            mut context: PropagationContextMut,
+        ) -> PropagationStatusCP {
+            context.set_lower_bound(&self.variable, self.bound, conjunction!())?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn idempotent_propagator_is_not_enqueued_due_to_its_own_events() {
+        for (is_idempotent, expected_num_calls) in [(true, 1), (false, 2)] {
+            let mut solver = ConstraintSatisfactionSolver::default();
+            let x = solver.create_new_integer_variable(0, 10, None);
+            let num_calls = Rc::new(Cell::new(0));
+
+            let result = solver.add_propagator(
+                RaiseLowerBound {
+                    variable: x,
+                    bound: 5,
+                    is_idempotent,
+                    num_calls: Rc::clone(&num_calls),
+                },
+                None,
+            );
+            assert!(result.is_ok());
+            assert_eq!(5, solver.assignments_integer.get_lower_bound(x));
+            assert_eq!(expected_num_calls, num_calls.get());
+        }
+    }
+
+    /// A test propagator which records the events with which it is notified for `variable`.
+    struct RecordEvents<Var> {
+        variable: Var,
+        events: Rc<RefCell<EnumSet<IntDomainEvent>>>,
+    }
+
+    impl<Var: IntegerVariable + 'static> Propagator for RecordEvents<Var> {
+        fn name(&self) -> &str {
+            "RecordEvents"
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register(
+                self.variable.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(0),
+            );
+            Ok(())
+        }
+
+        fn notify(
+            &mut self,
+            _context: PropagationContext,
+            _local_id: LocalId,
+            event: OpaqueDomainEvent,
+        ) -> EnqueueDecision {
+            let _ = self
+                .events
+                .borrow_mut()
+                .insert(self.variable.unpack_event(event));
+            EnqueueDecision::Skip
+        }
+
+        fn debug_propagate_from_scratch(&self, _: PropagationContextMut) -> PropagationStatusCP {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn notify_distinguishes_bound_events_through_views() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+
+        let events = Rc::new(RefCell::new(EnumSet::new()));
+        let negated_events = Rc::new(RefCell::new(EnumSet::new()));
+        let result = solver.add_propagator(
+            RecordEvents {
+                variable: x,
+                events: Rc::clone(&events),
+            },
+            None,
+        );
+        assert!(result.is_ok());
+        let result = solver.add_propagator(
+            RecordEvents {
+                variable: x.scaled(-1),
+                events: Rc::clone(&negated_events),
+            },
+            None,
+        );
+        assert!(result.is_ok());
+
+        let result = solver.add_clause([solver.get_literal(predicate![x >= 3])]);
+        assert!(result.is_ok());
+        assert_eq!(EnumSet::only(IntDomainEvent::LowerBound), *events.borrow());
+        assert_eq!(
+            EnumSet::only(IntDomainEvent::UpperBound),
+            *negated_events.borrow()
+        );
+
+        events.borrow_mut().clear();
+        negated_events.borrow_mut().clear();
+
+        let result = solver.add_clause([solver.get_literal(predicate![x != 5])]);
+        assert!(result.is_ok());
+        assert_eq!(EnumSet::only(IntDomainEvent::Removal), *events.borrow());
+        assert_eq!(
+            EnumSet::only(IntDomainEvent::Removal),
+            *negated_events.borrow()
+        );
+
+        events.borrow_mut().clear();
+        negated_events.borrow_mut().clear();
+
+        // Fixing the variable through its upper bound also results in an assignment event
+        let result = solver.add_clause([solver.get_literal(predicate![x <= 3])]);
+        assert!(result.is_ok());
+        assert_eq!(
+            IntDomainEvent::UpperBound | IntDomainEvent::Assign,
+            *events.borrow()
+        );
+        assert_eq!(
+            IntDomainEvent::LowerBound | IntDomainEvent::Assign,
+            *negated_events.borrow()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "profiling")]
+    fn time_spent_is_attributed_to_propagators() {
+        use std::time::Duration;
+
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 100, None);
+        let y = solver.create_new_integer_variable(0, 100, None);
+
+        let result =
+            solver.add_propagator(LinearNotEqualPropagator::new(Box::new([x, y]), 3), None);
+        assert!(result.is_ok());
+        let result =
+            solver.add_propagator(LinearNotEqualPropagator::new(Box::new([x, y]), 5), None);
+        assert!(result.is_ok());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(CSPSolverExecutionFlag::Feasible, result);
+
+        let time_spent = solver.cp_propagators.time_spent().collect::<Vec<_>>();
+        assert_eq!(2, time_spent.len());
+        assert!(time_spent.iter().all(|(name, _)| *name == "LinearNe"));
+        assert!(
+            time_spent
+                .iter()
+                .map(|(_, duration)| *duration)
+                .sum::<Duration>()
+                > Duration::ZERO
+        );
+
+        let reported = solver.get_propagator_time_ms();
+        assert_eq!(
+            vec!["LinearNe", "LinearNe"],
+            reported
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
 }
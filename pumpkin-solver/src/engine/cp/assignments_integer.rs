@@ -159,6 +159,12 @@ impl AssignmentsInteger {
         self.domains[domain_id].upper_bound
     }
 
+    /// Returns the number of values in the domain of the provided [`DomainId`]; as opposed to the
+    /// difference between the bounds, this takes the holes in the domain into account.
+    pub fn get_domain_size(&self, domain_id: DomainId) -> u32 {
+        self.domains[domain_id].size
+    }
+
     /// Returns the initial lower-bound of the provided [`DomainId`]
     pub fn get_initial_lower_bound(&self, domain_id: DomainId) -> i32 {
         self.domains[domain_id].initial_lower_bound
@@ -248,11 +254,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.get_domain_size(domain_id);
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -286,11 +294,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.get_domain_size(domain_id);
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -346,11 +356,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.get_domain_size(domain_id);
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -381,11 +393,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.get_domain_size(domain_id);
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -538,6 +552,8 @@ pub struct ConstraintProgrammingTrailEntry {
     pub old_lower_bound: i32,
     /// The upper-bound before the predicate was applied
     pub old_upper_bound: i32,
+    /// The number of values in the domain before the predicate was applied
+    pub old_domain_size: u32,
     /// Stores the a reference to the reason in the [`ReasonStore`], only makes sense if a
     /// propagation  took place, e.g., does _not_ make sense in the case of a decision or if
     /// the update was due to synchronisation from the propositional trail.
@@ -564,6 +580,8 @@ struct IntegerDomainExplicit {
     offset: i32,
 
     is_value_in_domain: Box<[bool]>,
+    /// The number of values in `[lower_bound, upper_bound]` which are in the domain.
+    size: u32,
 }
 
 impl IntegerDomainExplicit {
@@ -584,6 +602,7 @@ impl IntegerDomainExplicit {
             initial_upper_bound: upper_bound,
             offset,
             is_value_in_domain: is_value_in_domain.into(),
+            size: size as u32,
         }
     }
 
@@ -607,6 +626,7 @@ impl IntegerDomainExplicit {
 
         if self.is_value_in_domain[idx] {
             events.event_occurred(IntDomainEvent::Removal, self.id);
+            self.size -= 1;
         }
 
         self.is_value_in_domain[idx] = false;
@@ -626,6 +646,8 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::UpperBound, self.id);
 
+        self.size -=
+            self.num_values_in_range(value.max(self.lower_bound - 1) + 1, self.upper_bound);
         self.upper_bound = value;
         self.update_upper_bound(events);
 
@@ -641,6 +663,8 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::LowerBound, self.id);
 
+        self.size -=
+            self.num_values_in_range(self.lower_bound, value.min(self.upper_bound + 1) - 1);
         self.lower_bound = value;
         self.update_lower_bound(events);
 
@@ -671,6 +695,14 @@ impl IntegerDomainExplicit {
         (value + self.offset) as usize
     }
 
+    /// Returns the number of values in `[lower, upper]` which are in the domain, where the range
+    /// is assumed to be within the current bounds.
+    fn num_values_in_range(&self, lower: i32, upper: i32) -> u32 {
+        (lower..=upper)
+            .filter(|&value| self.is_value_in_domain[self.get_index(value)])
+            .count() as u32
+    }
+
     fn debug_bounds_check(&self) -> bool {
         // If the domain is empty, the lower bound will be greater than the upper bound.
         if self.lower_bound > self.upper_bound {
@@ -706,6 +738,7 @@ impl IntegerDomainExplicit {
 
         self.lower_bound = entry.old_lower_bound;
         self.upper_bound = entry.old_upper_bound;
+        self.size = entry.old_domain_size;
 
         pumpkin_assert_moderate!(self.debug_bounds_check());
     }
@@ -1115,6 +1148,38 @@ mod tests {
         assert_eq!(5, assignment.get_upper_bound(d1));
     }
 
+    #[test]
+    fn domain_size_takes_holes_into_account_and_is_restored_when_backtracking() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+        assert_eq!(10, assignment.get_domain_size(d1));
+
+        assignment.increase_decision_level();
+
+        assignment
+            .remove_value_from_domain(d1, 3, None)
+            .expect("non-empty domain");
+        assignment
+            .remove_value_from_domain(d1, 8, None)
+            .expect("non-empty domain");
+        assert_eq!(8, assignment.get_domain_size(d1));
+
+        // The hole at 3 does not count towards the values removed by the new lower-bound
+        assignment
+            .tighten_lower_bound(d1, 5, None)
+            .expect("non-empty domain");
+        assert_eq!(5, assignment.get_domain_size(d1));
+
+        assignment
+            .tighten_upper_bound(d1, 7, None)
+            .expect("non-empty domain");
+        assert_eq!(3, assignment.get_domain_size(d1));
+
+        let _ = assignment.synchronise(0, false, usize::MAX);
+
+        assert_eq!(10, assignment.get_domain_size(d1));
+    }
+
     fn assert_contains_events<DomainEvent: PartialEq + Copy>(
         slice: &[(DomainEvent, DomainId)],
         domain: DomainId,
@@ -11,12 +11,13 @@ mod watch_list_cp;
 mod watch_list_propositional;
 
 pub(crate) use assignments_integer::AssignmentsInteger;
-pub(crate) use assignments_integer::EmptyDomain;
+pub use assignments_integer::EmptyDomain;
 pub(crate) use propagator_queue::PropagatorQueue;
 pub(crate) use variable_literal_mappings::VariableLiteralMappings;
-pub(crate) use watch_list_cp::IntDomainEvent;
+pub use watch_list_cp::IntDomainEvent;
 pub(crate) use watch_list_cp::WatchListCP;
 pub(crate) use watch_list_cp::Watchers;
+pub use watch_list_propositional::BooleanDomainEvent;
 pub(crate) use watch_list_propositional::*;
 
 #[cfg(test)]
@@ -1,7 +1,15 @@
+#[cfg(doc)]
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::engine::variables::IntegerVariable;
 use crate::engine::IntDomainEvent;
 
 /// A wrapper for a domain event, which forces the propagator implementation to map the event
 /// through the variable view.
+///
+/// The wrapped event describes the change to the underlying [`DomainId`]; the
+/// [`IntDomainEvent`] with respect to a view is obtained using
+/// [`IntegerVariable::unpack_event`].
 #[derive(Clone, Debug, Copy)]
 pub struct OpaqueDomainEvent(IntDomainEvent);
 
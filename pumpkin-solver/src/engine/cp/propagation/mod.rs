@@ -84,14 +84,14 @@ pub(crate) mod propagator_initialisation_context;
 pub(crate) mod propagator_var_id;
 pub(crate) mod store;
 
-pub(crate) use local_id::LocalId;
-pub(crate) use propagation_context::PropagationContext;
-pub(crate) use propagation_context::PropagationContextMut;
-pub(crate) use propagation_context::ReadDomains;
-pub(crate) use propagator::EnqueueDecision;
-pub(crate) use propagator::Propagator;
+pub use local_id::LocalId;
+pub use propagation_context::PropagationContext;
+pub use propagation_context::PropagationContextMut;
+pub use propagation_context::ReadDomains;
+pub use propagator::EnqueueDecision;
+pub use propagator::Propagator;
 pub(crate) use propagator_id::PropagatorId;
-pub(crate) use propagator_initialisation_context::PropagatorInitialisationContext;
+pub use propagator_initialisation_context::PropagatorInitialisationContext;
 pub(crate) use propagator_var_id::PropagatorVarId;
 
 #[cfg(doc)]
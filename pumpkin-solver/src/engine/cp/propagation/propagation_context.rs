@@ -1,7 +1,10 @@
 use super::PropagatorId;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::Inconsistency;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
 use crate::engine::predicates::predicate::Predicate;
+use crate::engine::reason::LazyReason;
 use crate::engine::reason::Reason;
 use crate::engine::reason::ReasonStore;
 use crate::engine::variables::IntegerVariable;
@@ -80,13 +83,10 @@ impl<'a> PropagationContextMut<'a> {
                     conjunction.add(reification_literal.into());
                     Reason::Eager(conjunction)
                 }
-                Reason::Lazy(callback) => {
-                    Reason::Lazy(Box::new(move |context: PropagationContext| {
-                        let mut conjunction = callback.compute(context);
-                        conjunction.add(reification_literal.into());
-                        conjunction
-                    }))
-                }
+                Reason::Lazy(reason) => Reason::Lazy(Box::new(ReifiedLazyReason {
+                    reason,
+                    reification_literal,
+                })),
             }
         } else {
             reason
@@ -101,6 +101,28 @@ impl<'a> PropagationContextMut<'a> {
     }
 }
 
+/// A [`LazyReason`] of a reified propagation context; the reification literal is added to the
+/// wrapped reason once it is computed.
+struct ReifiedLazyReason {
+    reason: Box<dyn LazyReason>,
+    reification_literal: Literal,
+}
+
+impl LazyReason for ReifiedLazyReason {
+    fn compute(self: Box<Self>, context: PropagationContext) -> PropositionalConjunction {
+        let mut conjunction = self.reason.compute(context);
+        conjunction.add(self.reification_literal.into());
+        conjunction
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn LazyReason>> {
+        Some(Box::new(ReifiedLazyReason {
+            reason: self.reason.clone_boxed()?,
+            reification_literal: self.reification_literal,
+        }))
+    }
+}
+
 /// A trait which defines common methods for retrieving the [`AssignmentsInteger`] and
 /// [`AssignmentsPropositional`] from the structure which implements this trait.
 pub trait HasAssignments {
@@ -135,7 +157,7 @@ mod private {
     }
 }
 
-pub(crate) trait ReadDomains: HasAssignments {
+pub trait ReadDomains: HasAssignments {
     fn is_literal_fixed(&self, var: Literal) -> bool {
         self.assignments_propositional().is_literal_assigned(var)
     }
@@ -155,6 +177,11 @@ pub(crate) trait ReadDomains: HasAssignments {
         self.lower_bound(var) == self.upper_bound(var)
     }
 
+    /// Returns `true` if the domain of the given variable is singleton and equal to `value`.
+    fn is_fixed_to<Var: IntegerVariable>(&self, var: &Var, value: i32) -> bool {
+        self.is_fixed(var) && self.lower_bound(var) == value
+    }
+
     fn lower_bound<Var: IntegerVariable>(&self, var: &Var) -> i32 {
         var.lower_bound(self.assignments_integer())
     }
@@ -163,6 +190,14 @@ pub(crate) trait ReadDomains: HasAssignments {
         var.upper_bound(self.assignments_integer())
     }
 
+    fn lower_bound_initial<Var: IntegerVariable>(&self, var: &Var) -> i32 {
+        var.lower_bound_initial(self.assignments_integer())
+    }
+
+    fn upper_bound_initial<Var: IntegerVariable>(&self, var: &Var) -> i32 {
+        var.upper_bound_initial(self.assignments_integer())
+    }
+
     fn contains<Var: IntegerVariable>(&self, var: &Var, value: i32) -> bool {
         var.contains(self.assignments_integer(), value)
     }
@@ -217,6 +252,143 @@ impl PropagationContextMut<'_> {
         Ok(())
     }
 
+    /// Tightens both the lower bound and the upper bound of `var` for the same `reason`; this is
+    /// equivalent to calling [`PropagationContextMut::set_lower_bound`] followed by
+    /// [`PropagationContextMut::set_upper_bound`], except that the reason is stored only once and
+    /// shared by both of the bound updates.
+    pub fn set_bounds<Var: IntegerVariable, R: Into<Reason>>(
+        &mut self,
+        var: &Var,
+        lower_bound: i32,
+        upper_bound: i32,
+        reason: R,
+    ) -> Result<(), EmptyDomain> {
+        let tightens_lower_bound = lower_bound > var.lower_bound(self.assignments_integer);
+        let tightens_upper_bound = upper_bound < var.upper_bound(self.assignments_integer);
+        if !tightens_lower_bound && !tightens_upper_bound {
+            return Ok(());
+        }
+
+        let reason = self.build_reason(reason.into());
+        let reason_ref = self.reason_store.push(self.propagator, reason);
+        if tightens_lower_bound {
+            var.set_lower_bound(self.assignments_integer, lower_bound, Some(reason_ref))?;
+        }
+        if tightens_upper_bound {
+            var.set_upper_bound(self.assignments_integer, upper_bound, Some(reason_ref))?;
+        }
+        Ok(())
+    }
+
+    /// Posts each of the provided `predicates` for the same `reason`; the reason is stored only
+    /// once and shared by all of the resulting domain changes, rather than being cloned for every
+    /// predicate. Predicates which already hold are ignored.
+    ///
+    /// Posting [`Predicate::False`] is not allowed; a propagator should report a conflict instead.
+    pub fn post_predicates<R: Into<Reason>>(
+        &mut self,
+        predicates: impl IntoIterator<Item = Predicate>,
+        reason: R,
+    ) -> Result<(), Inconsistency> {
+        let predicates = predicates
+            .into_iter()
+            .filter(|&predicate| !self.does_predicate_hold(predicate))
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return Ok(());
+        }
+
+        let reason = self.build_reason(reason.into());
+        let reason_ref = self.reason_store.push(self.propagator, reason);
+        for predicate in predicates {
+            match predicate {
+                Predicate::IntegerPredicate(IntegerPredicate::LowerBound {
+                    domain_id,
+                    lower_bound,
+                }) => {
+                    domain_id.set_lower_bound(
+                        self.assignments_integer,
+                        lower_bound,
+                        Some(reason_ref),
+                    )?;
+                }
+                Predicate::IntegerPredicate(IntegerPredicate::UpperBound {
+                    domain_id,
+                    upper_bound,
+                }) => {
+                    domain_id.set_upper_bound(
+                        self.assignments_integer,
+                        upper_bound,
+                        Some(reason_ref),
+                    )?;
+                }
+                Predicate::IntegerPredicate(IntegerPredicate::NotEqual {
+                    domain_id,
+                    not_equal_constant,
+                }) => {
+                    domain_id.remove(
+                        self.assignments_integer,
+                        not_equal_constant,
+                        Some(reason_ref),
+                    )?;
+                }
+                Predicate::IntegerPredicate(IntegerPredicate::Equal {
+                    domain_id,
+                    equality_constant,
+                }) => {
+                    if equality_constant > domain_id.lower_bound(self.assignments_integer) {
+                        domain_id.set_lower_bound(
+                            self.assignments_integer,
+                            equality_constant,
+                            Some(reason_ref),
+                        )?;
+                    }
+                    if equality_constant < domain_id.upper_bound(self.assignments_integer) {
+                        domain_id.set_upper_bound(
+                            self.assignments_integer,
+                            equality_constant,
+                            Some(reason_ref),
+                        )?;
+                    }
+                }
+                Predicate::Literal(literal) => {
+                    // The literal could have been set by a duplicate earlier in the batch
+                    if self
+                        .assignments_propositional
+                        .is_literal_assigned_true(literal)
+                    {
+                        continue;
+                    }
+                    let enqueue_result = self.assignments_propositional.enqueue_propagated_literal(
+                        literal,
+                        ConstraintReference::create_reason_reference(reason_ref),
+                    );
+                    if let Some(conflict_info) = enqueue_result {
+                        return Err(Inconsistency::Other(conflict_info));
+                    }
+                }
+                Predicate::True => {}
+                Predicate::False => {
+                    pumpkin_assert_simple!(false, "Cannot post the predicate false");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn does_predicate_hold(&self, predicate: Predicate) -> bool {
+        match predicate {
+            Predicate::IntegerPredicate(integer_predicate) => self
+                .assignments_integer
+                .does_integer_predicate_hold(integer_predicate),
+            Predicate::Literal(literal) => self
+                .assignments_propositional
+                .is_literal_assigned_true(literal),
+            Predicate::True => true,
+            Predicate::False => false,
+        }
+    }
+
     pub fn assign_literal<R: Into<Reason>>(
         &mut self,
         var: Literal,
@@ -238,3 +410,139 @@ impl PropagationContextMut<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::predicate;
+
+    #[test]
+    fn is_fixed_to_checks_both_assignment_and_value() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let assignments_propositional = AssignmentsPropositional::default();
+
+        let fixed = assignments_integer.grow(3, 3);
+        let unfixed = assignments_integer.grow(0, 5);
+
+        let context = PropagationContext::new(&assignments_integer, &assignments_propositional);
+
+        assert!(context.is_fixed_to(&fixed, 3));
+        assert!(!context.is_fixed_to(&fixed, 4));
+        assert!(!context.is_fixed_to(&unfixed, 0));
+        assert!(!context.is_fixed_to(&unfixed, 3));
+    }
+
+    #[test]
+    fn set_bounds_records_the_reason_for_both_bounds() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+        let mut reason_store = ReasonStore::default();
+
+        let x = assignments_integer.grow(0, 10);
+        let y = assignments_integer.grow(2, 6);
+
+        let mut context = PropagationContextMut::new(
+            &mut assignments_integer,
+            &mut reason_store,
+            &mut assignments_propositional,
+            PropagatorId(0),
+        );
+        context
+            .set_bounds(&x, 4, 4, conjunction!([y >= 2] & [y <= 6]))
+            .expect("non-empty domain");
+
+        assert!(assignments_integer.is_domain_assigned_to_value(x, 4));
+        assert_eq!(2, assignments_integer.num_trail_entries());
+
+        let context = PropagationContext::new(&assignments_integer, &assignments_propositional);
+        for index in 0..assignments_integer.num_trail_entries() {
+            let reason_ref = assignments_integer
+                .get_trail_entry(index)
+                .reason
+                .expect("a propagation has a reason");
+            assert_eq!(
+                Some(&conjunction!([y >= 2] & [y <= 6])),
+                reason_store.get_or_compute(reason_ref, context)
+            );
+        }
+    }
+
+    #[test]
+    fn post_predicates_shares_the_reason_between_all_predicates() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+        let mut reason_store = ReasonStore::default();
+
+        let x = assignments_integer.grow(0, 10);
+        let y = assignments_integer.grow(0, 10);
+        let z = assignments_integer.grow(0, 10);
+
+        let mut context = PropagationContextMut::new(
+            &mut assignments_integer,
+            &mut reason_store,
+            &mut assignments_propositional,
+            PropagatorId(0),
+        );
+        context
+            .post_predicates(
+                [predicate![x >= 3], predicate![y <= 7], predicate![z != 0]],
+                conjunction!([x >= 1] & [y <= 9]),
+            )
+            .expect("non-empty domain");
+
+        assert_eq!(1, reason_store.len());
+        assert_eq!(3, assignments_integer.num_trail_entries());
+
+        let context = PropagationContext::new(&assignments_integer, &assignments_propositional);
+        for index in 0..assignments_integer.num_trail_entries() {
+            let reason_ref = assignments_integer
+                .get_trail_entry(index)
+                .reason
+                .expect("a propagation has a reason");
+            assert_eq!(
+                Some(&conjunction!([x >= 1] & [y <= 9])),
+                reason_store.get_or_compute(reason_ref, context)
+            );
+        }
+    }
+
+    #[test]
+    fn post_predicates_does_not_store_a_reason_when_all_predicates_hold() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+        let mut reason_store = ReasonStore::default();
+
+        let x = assignments_integer.grow(2, 10);
+
+        let mut context = PropagationContextMut::new(
+            &mut assignments_integer,
+            &mut reason_store,
+            &mut assignments_propositional,
+            PropagatorId(0),
+        );
+        context
+            .post_predicates([predicate![x >= 1], predicate![x <= 10]], conjunction!())
+            .expect("non-empty domain");
+
+        assert_eq!(0, reason_store.len());
+        assert_eq!(0, assignments_integer.num_trail_entries());
+    }
+
+    #[test]
+    fn set_bounds_stops_at_an_empty_domain() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+        let mut reason_store = ReasonStore::default();
+
+        let x = assignments_integer.grow(0, 10);
+
+        let mut context = PropagationContextMut::new(
+            &mut assignments_integer,
+            &mut reason_store,
+            &mut assignments_propositional,
+            PropagatorId(0),
+        );
+        assert!(context.set_bounds(&x, 11, 12, conjunction!()).is_err());
+    }
+}
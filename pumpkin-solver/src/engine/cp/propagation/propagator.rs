@@ -8,17 +8,21 @@ use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::local_id::LocalId;
 use crate::engine::propagation::propagation_context::PropagationContext;
 use crate::engine::propagation::propagation_context::PropagationContextMut;
+#[cfg(doc)]
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::engine::variables::IntegerVariable;
 use crate::engine::BooleanDomainEvent;
 #[cfg(doc)]
-use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 #[cfg(doc)]
-use crate::propagators::clausal::BasicClausalPropagator;
-#[cfg(doc)]
 use crate::pumpkin_asserts::PUMPKIN_ASSERT_ADVANCED;
 #[cfg(doc)]
 use crate::pumpkin_asserts::PUMPKIN_ASSERT_EXTREME;
 use crate::statistics::statistic_logger::StatisticLogger;
+#[cfg(doc)]
+use crate::Solver;
 
 /// All propagators implement the [`Propagator`] trait, with the exception of the
 /// clausal propagator. Structs implementing the trait defines the main propagator logic with
@@ -29,7 +33,10 @@ use crate::statistics::statistic_logger::StatisticLogger;
 /// functions have default implementations. For initial development, the required functions are
 /// enough, but a more mature implementation considers all functions in most cases.
 ///
-/// See the [`crate::engine::cp::propagation`] documentation for more details.
+/// See the [`propagation`](crate::propagation) module documentation for more details.
+///
+/// A [`Propagator`] can opt in to being copied along with the [`Solver`] by implementing
+/// [`Propagator::clone_boxed`] (see [`Solver::clone_with_state`]).
 pub trait Propagator {
     /// Return the name of the propagator, this is a convenience method that is used for printing.
     fn name(&self) -> &str;
@@ -39,7 +46,7 @@ pub trait Propagator {
     /// This method propagates without relying on internal data structures, hence the immutable
     /// &self parameter. It is usually best to implement this propagation method in the simplest
     /// but correct way. When the assert level is set to [`PUMPKIN_ASSERT_ADVANCED`] or
-    /// [`PUMPKIN_ASSERT_EXTREME`] (see [`crate::pumpkin_asserts`]) this method will be called
+    /// [`PUMPKIN_ASSERT_EXTREME`] this method will be called
     /// to double check the reasons for failures and propagations that have been reported by
     /// this propagator.
     ///
@@ -47,8 +54,7 @@ pub trait Propagator {
     /// the solver until no further propagations happen.
     fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP;
 
-    /// Propagate method that will be called during search (e.g. in
-    /// [`ConstraintSatisfactionSolver::solve`]).
+    /// Propagate method that will be called during search (e.g. in [`Solver::satisfy`]).
     ///
     /// This method extends the current partial
     /// assignments with inferred domain changes found by the
@@ -82,6 +88,12 @@ pub trait Propagator {
     /// Note that the variables and events to which the propagator is subscribed to are determined
     /// upon propagator initialisation via [`Propagator::initialise_at_root`] by calling
     /// [`PropagatorInitialisationContext::register()`].
+    ///
+    /// The method is called once for every kind of event which occurred; e.g. if tightening the
+    /// lower bound of a variable also fixes it, then it is called for both the
+    /// [`IntDomainEvent::LowerBound`] and the [`IntDomainEvent::Assign`] event. The event should
+    /// be mapped through the variable view using [`IntegerVariable::unpack_event`], as a lower
+    /// bound event on a [`DomainId`] is an upper bound event on a negated view of it.
     fn notify(
         &mut self,
         _context: PropagationContext,
@@ -126,7 +138,7 @@ pub trait Propagator {
         EnqueueDecision::Enqueue
     }
 
-    /// Called each time the [`ConstraintSatisfactionSolver`] backtracks, the propagator can then
+    /// Called each time the [`Solver`] backtracks, the propagator can then
     /// update its internal data structures given the new variable domains.
     ///
     /// By default this function does nothing.
@@ -136,7 +148,7 @@ pub trait Propagator {
     /// priority and the priority determines the order in which propagators will be asked to
     /// propagate.
     ///
-    /// In other words, after the [`BasicClausalPropagator`] has propagated, propagators
+    /// In other words, after the clausal propagator has propagated, propagators
     /// with lower priority values are called before those with higher priority. It is custom
     /// for simpler propagators to have lower priority values
     ///
@@ -147,9 +159,22 @@ pub trait Propagator {
         3
     }
 
+    /// Indicates whether the propagator is idempotent, i.e. whether a single call to
+    /// [`Propagator::propagate`] always results in a fixed point of the propagator.
+    ///
+    /// If this is the case, then the [`Solver`] does not enqueue the
+    /// propagator due to the domain changes which it made itself; it is only enqueued due to
+    /// events caused by other propagators or by decisions. Note that the propagator is still
+    /// notified of its own domain changes (see [`Propagator::notify`]) such that it can maintain
+    /// its internal data structures.
+    ///
+    /// By default, propagators are assumed to not be idempotent.
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
     /// Initialises the propagator without performing propagation. This method is called only once
-    /// by the [`ConstraintSatisfactionSolver`] when the propagator is added using
-    /// [`ConstraintSatisfactionSolver::add_propagator`].
+    /// by the [`Solver`] when the propagator is added using [`Solver::add_propagator`].
     ///
     /// The method can be used to detect root-level inconsistencies and to register variables used
     /// for notifications (see [`Propagator::notify`]) by calling
@@ -178,6 +203,93 @@ pub trait Propagator {
     ///
     /// It is recommended to create a struct through the [`create_statistics_struct!`] macro!
     fn log_statistics(&self, _statistic_logger: StatisticLogger) {}
+
+    /// Returns a boxed copy of the propagator which does not share any state with it; this is
+    /// used when the [`Solver`] is copied using [`Solver::clone_with_state`].
+    ///
+    /// By default, `None` is returned, in which case a [`Solver`] containing the propagator cannot
+    /// be copied. A propagator which implements [`Clone`] can return
+    /// `Some(Box::new(self.clone()))`.
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        None
+    }
+}
+
+/// A boxed [`Propagator`] forwards every method to the propagator which it contains; this allows
+/// wrappers (e.g. a reified propagator) to hold a copy of a propagator whose type is not known.
+impl<WrappedPropagator: Propagator + ?Sized> Propagator for Box<WrappedPropagator> {
+    fn name(&self) -> &str {
+        self.as_ref().name()
+    }
+
+    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.as_ref().debug_propagate_from_scratch(context)
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.as_mut().propagate(context)
+    }
+
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        self.as_mut().notify(context, local_id, event)
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) {
+        self.as_mut().notify_backtrack(context, local_id, event)
+    }
+
+    fn notify_literal(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: BooleanDomainEvent,
+    ) -> EnqueueDecision {
+        self.as_mut().notify_literal(context, local_id, event)
+    }
+
+    fn synchronise(&mut self, context: PropagationContext) {
+        self.as_mut().synchronise(context)
+    }
+
+    fn priority(&self) -> u32 {
+        self.as_ref().priority()
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.as_ref().is_idempotent()
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.as_mut().initialise_at_root(context)
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.as_ref().detect_inconsistency(context)
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.as_ref().log_statistics(statistic_logger)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        self.as_ref().clone_boxed()
+    }
 }
 
 /// Indicator of what to do when a propagator is notified.
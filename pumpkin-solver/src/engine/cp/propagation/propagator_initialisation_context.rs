@@ -6,6 +6,9 @@ use crate::engine::propagation::LocalId;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorVarId;
+#[cfg(doc)]
+use crate::engine::variables::AffineView;
+use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
 use crate::engine::AssignmentsInteger;
@@ -14,6 +17,8 @@ use crate::engine::WatchListCP;
 use crate::engine::WatchListPropositional;
 use crate::engine::Watchers;
 use crate::engine::WatchersPropositional;
+#[cfg(doc)]
+use crate::Solver;
 
 /// [`PropagatorInitialisationContext`] is used when [`Propagator`]s are initialised after creation.
 ///
@@ -83,7 +88,7 @@ impl PropagatorInitialisationContext<'_> {
     }
 
     /// Subscribes the propagator to the given [`DomainEvents`] when they are undone during
-    /// backtracking. This method is complementary to [`PropagatorConstructorContext::register`],
+    /// backtracking. This method is complementary to [`PropagatorInitialisationContext::register`],
     /// the [`LocalId`]s provided to both of these method should be the same for the same variable.
     ///
     /// The domain events determine when [`Propagator::notify_backtrack()`] will be called on the
@@ -137,6 +142,17 @@ impl PropagatorInitialisationContext<'_> {
     pub fn get_next_local_id(&self) -> LocalId {
         self.next_local_id
     }
+
+    /// Returns the domains which the propagator has registered for so far.
+    pub(crate) fn get_watched_domains(&self) -> Vec<DomainId> {
+        self.watch_list.get_domains_watched_by(self.propagator_id)
+    }
+
+    /// Returns the literals which the propagator has registered for so far.
+    pub(crate) fn get_watched_literals(&self) -> Vec<Literal> {
+        self.watch_list_propositional
+            .get_literals_watched_by(self.propagator_id)
+    }
 }
 
 mod private {
@@ -2,11 +2,14 @@ use std::fmt::Debug;
 use std::num::NonZero;
 use std::ops::Index;
 use std::ops::IndexMut;
+#[cfg(feature = "profiling")]
+use std::time::Duration;
 
 use super::Propagator;
 use super::PropagatorId;
 use crate::basic_types::KeyedVec;
 use crate::engine::DebugDyn;
+use crate::statistics::PropagatorStatistics;
 
 /// A central store for propagators.
 ///
@@ -16,9 +19,32 @@ use crate::engine::DebugDyn;
 pub(crate) struct PropagatorStore {
     propagators: KeyedVec<PropagatorId, Box<dyn Propagator>>,
     tags: KeyedVec<PropagatorId, Option<NonZero<u32>>>,
+    /// The counters (e.g. the number of propagations) of every propagator.
+    statistics: KeyedVec<PropagatorId, PropagatorStatistics>,
+    /// The time spent in the [`Propagator::propagate`] and notification methods of every
+    /// propagator.
+    #[cfg(feature = "profiling")]
+    time_spent: KeyedVec<PropagatorId, Duration>,
 }
 
 impl PropagatorStore {
+    /// Returns a copy of the store, or `None` if one of the propagators does not support being
+    /// copied (see [`Propagator::clone_boxed`]).
+    pub(crate) fn try_clone(&self) -> Option<PropagatorStore> {
+        let mut propagators: Vec<Box<dyn Propagator>> = Vec::with_capacity(self.propagators.len());
+        for propagator in self.propagators.iter() {
+            propagators.push(propagator.clone_boxed()?);
+        }
+
+        Some(PropagatorStore {
+            propagators: KeyedVec::new(propagators),
+            tags: self.tags.clone(),
+            statistics: self.statistics.clone(),
+            #[cfg(feature = "profiling")]
+            time_spent: self.time_spent.clone(),
+        })
+    }
+
     pub(crate) fn alloc(
         &mut self,
         propagator: Box<dyn Propagator>,
@@ -26,6 +52,9 @@ impl PropagatorStore {
     ) -> PropagatorId {
         let id = self.propagators.push(propagator);
         let _ = self.tags.push(tag);
+        let _ = self.statistics.push(PropagatorStatistics::default());
+        #[cfg(feature = "profiling")]
+        let _ = self.time_spent.push(Duration::ZERO);
 
         id
     }
@@ -34,6 +63,35 @@ impl PropagatorStore {
         self.tags[propagator_id]
     }
 
+    pub(crate) fn get_statistics_mut(
+        &mut self,
+        propagator_id: PropagatorId,
+    ) -> &mut PropagatorStatistics {
+        &mut self.statistics[propagator_id]
+    }
+
+    /// Returns the name of every propagator together with its statistics.
+    pub(crate) fn statistics(&self) -> impl Iterator<Item = (&str, PropagatorStatistics)> + '_ {
+        self.propagators
+            .iter()
+            .zip(self.statistics.iter())
+            .map(|(propagator, statistics)| (propagator.name(), *statistics))
+    }
+
+    #[cfg(feature = "profiling")]
+    pub(crate) fn add_time_spent(&mut self, propagator_id: PropagatorId, duration: Duration) {
+        self.time_spent[propagator_id] += duration;
+    }
+
+    /// Returns the name of every propagator together with the time spent in it.
+    #[cfg(feature = "profiling")]
+    pub(crate) fn time_spent(&self) -> impl Iterator<Item = (&str, Duration)> + '_ {
+        self.propagators
+            .iter()
+            .zip(self.time_spent.iter())
+            .map(|(propagator, duration)| (propagator.name(), *duration))
+    }
+
     pub(crate) fn iter_propagators(&self) -> impl Iterator<Item = &dyn Propagator> + '_ {
         self.propagators.iter().map(|b| b.as_ref())
     }
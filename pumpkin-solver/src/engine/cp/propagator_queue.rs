@@ -6,7 +6,7 @@ use crate::basic_types::HashSet;
 use crate::engine::cp::propagation::PropagatorId;
 use crate::pumpkin_assert_moderate;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct PropagatorQueue {
     queues: Vec<VecDeque<PropagatorId>>,
     present_propagators: HashSet<PropagatorId>,
@@ -26,16 +26,26 @@ impl PropagatorQueue {
         self.present_propagators.is_empty()
     }
 
-    pub(crate) fn enqueue_propagator(&mut self, propagator_id: PropagatorId, priority: u32) {
+    /// Enqueues the propagator with the provided priority; returns whether the propagator was
+    /// added to the queue (i.e. it returns false if the propagator was already enqueued).
+    pub(crate) fn enqueue_propagator(
+        &mut self,
+        propagator_id: PropagatorId,
+        priority: u32,
+    ) -> bool {
         pumpkin_assert_moderate!((priority as usize) < self.queues.len());
 
-        if !self.is_propagator_enqueued(propagator_id) {
-            if self.queues[priority as usize].is_empty() {
-                self.present_priorities.push(Reverse(priority));
-            }
-            self.queues[priority as usize].push_back(propagator_id);
-            let _ = self.present_propagators.insert(propagator_id);
+        if self.is_propagator_enqueued(propagator_id) {
+            return false;
         }
+
+        if self.queues[priority as usize].is_empty() {
+            self.present_priorities.push(Reverse(priority));
+        }
+        self.queues[priority as usize].push_back(propagator_id);
+        let _ = self.present_propagators.insert(propagator_id);
+
+        true
     }
 
     pub(crate) fn pop(&mut self) -> PropagatorId {
@@ -53,6 +53,31 @@ impl ReasonStore {
         self.trail.len()
     }
 
+    /// Returns a copy of the reason store at the root level in which every reason is [`Eager`];
+    /// a lazy reason is computed using a copy of it, which leaves the reason in this store lazy.
+    ///
+    /// If one of the lazy reasons cannot be copied (see [`LazyReason::clone_boxed`]), then `None`
+    /// is returned.
+    ///
+    /// [`Eager`]: Reason::Eager
+    pub(crate) fn clone_computed(&self, context: PropagationContext) -> Option<ReasonStore> {
+        pumpkin_assert_simple!(
+            self.trail.get_decision_level() == 0,
+            "the reason store can only be copied at the root level"
+        );
+
+        let mut clone = ReasonStore::default();
+        for index in 0..self.trail.len() {
+            let (propagator, reason) = &self.trail[index];
+            let conjunction = match reason {
+                Reason::Eager(conjunction) => conjunction.clone(),
+                Reason::Lazy(callback) => callback.clone_boxed()?.compute(context),
+            };
+            clone.trail.push((*propagator, Reason::Eager(conjunction)));
+        }
+        Some(clone)
+    }
+
     /// Get the propagator which generated the given reason.
     pub fn get_propagator(&self, reason_ref: ReasonRef) -> PropagatorId {
         self.trail.get(reason_ref.0 as usize).unwrap().0
@@ -95,6 +120,16 @@ pub trait LazyReason {
     ///   made. The CP propagator must compute and save any required information in the closure if
     ///   dependent on the state of the assignments at that time.
     fn compute(self: Box<Self>, context: PropagationContext) -> PropositionalConjunction;
+
+    /// Returns a boxed copy of the [`LazyReason`], which is used to copy a [`ReasonStore`]
+    /// without computing the reasons of the original.
+    ///
+    /// By default, `None` is returned, in which case a [`ReasonStore`] containing the reason
+    /// cannot be copied; a closure can be made copyable by wrapping it in a
+    /// [`CloneableLazyReason`].
+    fn clone_boxed(&self) -> Option<Box<dyn LazyReason>> {
+        None
+    }
 }
 
 impl<F: FnOnce(PropagationContext) -> PropositionalConjunction> LazyReason for F {
@@ -103,6 +138,23 @@ impl<F: FnOnce(PropagationContext) -> PropositionalConjunction> LazyReason for F
     }
 }
 
+/// A [`LazyReason`] given by a closure which implements [`Clone`]; unlike a plain closure, it can
+/// be copied using [`LazyReason::clone_boxed`].
+#[derive(Clone, Copy, Debug)]
+pub struct CloneableLazyReason<F>(pub F);
+
+impl<F: FnOnce(PropagationContext) -> PropositionalConjunction + Clone + 'static> LazyReason
+    for CloneableLazyReason<F>
+{
+    fn compute(self: Box<Self>, context: PropagationContext) -> PropositionalConjunction {
+        (self.0)(context)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn LazyReason>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
 impl Reason {
     /// Compute the reason for a propagation, replacing the original reason with an `Eager` one if
     ///   it's `Lazy`.
@@ -139,6 +191,14 @@ impl<F: FnOnce(PropagationContext) -> PropositionalConjunction + 'static> From<F
     }
 }
 
+impl<F: FnOnce(PropagationContext) -> PropositionalConjunction + Clone + 'static>
+    From<CloneableLazyReason<F>> for Reason
+{
+    fn from(value: CloneableLazyReason<F>) -> Self {
+        Reason::Lazy(Box::new(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +258,56 @@ mod tests {
             reason_store.get_or_compute(reason_ref, context)
         );
     }
+
+    #[test]
+    fn copying_computes_the_lazy_reasons_only_in_the_copy() {
+        let mut reason_store = ReasonStore::default();
+        let integers = AssignmentsInteger::default();
+        let booleans = AssignmentsPropositional::default();
+        let context = PropagationContext::new(&integers, &booleans);
+
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+
+        let conjunction = conjunction!([x == 1] & [y == 2]);
+        let conjunction_to_return = conjunction.clone();
+        let reason_ref = reason_store.push(
+            PropagatorId(0),
+            Reason::from(CloneableLazyReason(move |_: PropagationContext| {
+                conjunction_to_return
+            })),
+        );
+
+        let mut clone = reason_store
+            .clone_computed(context)
+            .expect("the lazy reason can be copied");
+
+        assert!(matches!(clone.trail[0].1, Reason::Eager(_)));
+        assert!(matches!(reason_store.trail[0].1, Reason::Lazy(_)));
+        assert_eq!(PropagatorId(0), clone.get_propagator(reason_ref));
+        assert_eq!(
+            Some(&conjunction),
+            clone.get_or_compute(reason_ref, context)
+        );
+        assert_eq!(
+            Some(&conjunction),
+            reason_store.get_or_compute(reason_ref, context)
+        );
+    }
+
+    #[test]
+    fn a_lazy_reason_which_cannot_be_copied_prevents_copying() {
+        let mut reason_store = ReasonStore::default();
+        let integers = AssignmentsInteger::default();
+        let booleans = AssignmentsPropositional::default();
+        let context = PropagationContext::new(&integers, &booleans);
+
+        let x = DomainId::new(0);
+        let _ = reason_store.push(
+            PropagatorId(0),
+            Reason::from(move |_: PropagationContext| conjunction!([x == 1])),
+        );
+
+        assert!(reason_store.clone_computed(context).is_none());
+    }
 }
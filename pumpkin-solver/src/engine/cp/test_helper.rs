@@ -143,6 +143,11 @@ impl TestSolver {
         )
     }
 
+    pub(crate) fn increase_decision_level(&mut self) {
+        self.assignments_integer.increase_decision_level();
+        self.assignments_propositional.increase_decision_level();
+    }
+
     pub(crate) fn set_literal(&mut self, var: Literal, val: bool) {
         self.assignments_propositional
             .enqueue_decision_literal(if val { var } else { !var });
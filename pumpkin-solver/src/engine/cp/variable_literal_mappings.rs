@@ -21,7 +21,7 @@ use crate::propagators::clausal::ClausalPropagator;
 use crate::pumpkin_assert_eq_simple;
 use crate::pumpkin_assert_simple;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub(crate) struct VariableLiteralMappings {
     /// `domain_to_equality_literals[DomainId x][i]` is the [`Literal`]
     /// that represents `[x == i + initial_lb(x)]`, where `initial_lb(x)` is
@@ -2,10 +2,12 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorVarId;
 use crate::engine::variables::DomainId;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub(crate) struct WatchListCP {
     watchers: KeyedVec<DomainId, WatcherCP>, /* contains propagator ids of propagators that
                                               * watch domain changes of the i-th integer
@@ -79,6 +81,16 @@ impl WatchListCP {
             IntDomainEvent::Removal => &watcher.backtrack_watcher.removal_watchers,
         }
     }
+
+    /// Returns the domains for which the given propagator is notified of (forward) events.
+    pub(crate) fn get_domains_watched_by(&self, propagator: PropagatorId) -> Vec<DomainId> {
+        self.watchers
+            .iter()
+            .enumerate()
+            .filter(|(_, watcher)| watcher.forward_watcher.contains(propagator))
+            .map(|(index, _)| DomainId::create_from_index(index))
+            .collect()
+    }
 }
 
 impl<'a> Watchers<'a> {
@@ -130,17 +142,31 @@ impl<'a> Watchers<'a> {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 struct WatcherCP {
     // FIXME measure performance of these vectors, they are treated as sets
     forward_watcher: Watcher,
     backtrack_watcher: Watcher,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 struct Watcher {
     lower_bound_watchers: Vec<PropagatorVarId>,
     upper_bound_watchers: Vec<PropagatorVarId>,
     assign_watchers: Vec<PropagatorVarId>,
     removal_watchers: Vec<PropagatorVarId>,
 }
+
+impl Watcher {
+    fn contains(&self, propagator: PropagatorId) -> bool {
+        [
+            &self.lower_bound_watchers,
+            &self.upper_bound_watchers,
+            &self.assign_watchers,
+            &self.removal_watchers,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|propagator_var| propagator_var.propagator == propagator)
+    }
+}
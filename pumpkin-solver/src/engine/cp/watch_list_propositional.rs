@@ -2,10 +2,12 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorVarId;
 use crate::engine::variables::Literal;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct WatchListPropositional {
     watchers: KeyedVec<Literal, WatcherPropositional>, /* contains propagator ids of propagators
                                                         * that watch domain changes of the i-th
@@ -69,6 +71,22 @@ impl WatchListPropositional {
             BooleanDomainEvent::AssignedFalse => &watcher.assigned_false_watchers,
         }
     }
+
+    /// Returns the literals for which the given propagator is notified of events.
+    pub(crate) fn get_literals_watched_by(&self, propagator: PropagatorId) -> Vec<Literal> {
+        self.watchers
+            .iter()
+            .enumerate()
+            .filter(|(_, watcher)| {
+                watcher
+                    .assigned_true_watchers
+                    .iter()
+                    .chain(watcher.assigned_false_watchers.iter())
+                    .any(|propagator_var| propagator_var.propagator == propagator)
+            })
+            .map(|(index, _)| Literal::create_from_index(index))
+            .collect()
+    }
 }
 
 impl<'a> WatchersPropositional<'a> {
@@ -99,7 +117,7 @@ impl<'a> WatchersPropositional<'a> {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 struct WatcherPropositional {
     assigned_true_watchers: Vec<PropagatorVarId>,
     assigned_false_watchers: Vec<PropagatorVarId>,
@@ -314,7 +314,7 @@ impl DebugHelper {
                             {
                                 found_inconsistency
                                     .iter()
-                                    .all(|&predicate| reason.contains(predicate))
+                                    .all(|predicate| reason.contains(predicate))
                             } else {
                                 false
                             }
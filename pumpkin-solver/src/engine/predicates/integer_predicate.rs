@@ -59,6 +59,20 @@ impl IntegerPredicate {
         )
     }
 
+    /// Returns whether the [`IntegerPredicate`] holds when its domain is assigned to `value`.
+    pub(crate) fn is_satisfied_by(&self, value: i32) -> bool {
+        match *self {
+            IntegerPredicate::LowerBound { lower_bound, .. } => value >= lower_bound,
+            IntegerPredicate::UpperBound { upper_bound, .. } => value <= upper_bound,
+            IntegerPredicate::NotEqual {
+                not_equal_constant, ..
+            } => value != not_equal_constant,
+            IntegerPredicate::Equal {
+                equality_constant, ..
+            } => value == equality_constant,
+        }
+    }
+
     /// Returns the [`DomainId`] of the [`IntegerPredicate`]
     pub fn get_domain(&self) -> DomainId {
         match *self {
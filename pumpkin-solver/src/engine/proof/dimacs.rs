@@ -40,4 +40,20 @@ impl<W: Write> DimacsProof<W> {
 
         Ok(id)
     }
+
+    pub(crate) fn deleted_clause(
+        &mut self,
+        literals: impl IntoIterator<Item = Literal>,
+    ) -> std::io::Result<()> {
+        write!(self.writer, "d ")?;
+
+        for lit in literals.into_iter() {
+            let prefix = if lit.is_negative() { "-" } else { "" };
+            let code = lit.get_propositional_variable().index();
+
+            write!(self.writer, "{prefix}{code} ")?;
+        }
+
+        writeln!(self.writer, "0")
+    }
 }
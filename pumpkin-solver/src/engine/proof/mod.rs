@@ -143,6 +143,20 @@ impl ProofLog {
         }
     }
 
+    /// Log that the learned clause with the given step ID and `literals` is removed from the
+    /// clause database.
+    pub(crate) fn log_deleted_clause(
+        &mut self,
+        step_id: NonZeroU64,
+        literals: impl IntoIterator<Item = Literal>,
+    ) -> std::io::Result<()> {
+        match &mut self.internal_proof {
+            Some(ProofImpl::CpProof { writer, .. }) => writer.log_deletion(step_id),
+            Some(ProofImpl::DimacsProof(writer)) => writer.deleted_clause(literals),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn unsat(
         self,
         variable_names: &VariableNames,
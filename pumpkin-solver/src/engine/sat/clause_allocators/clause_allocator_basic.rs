@@ -7,7 +7,7 @@ use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub(crate) struct ClauseAllocatorBasic {
     allocated_clauses: Vec<ClauseBasic>,
     deleted_clause_references: Vec<ClauseReference>,
@@ -4,7 +4,7 @@ use crate::engine::constraint_satisfaction_solver::ClauseAllocator;
 use crate::engine::variables::Literal;
 use crate::pumpkin_assert_moderate;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub(crate) struct ExplanationClauseManager {
     explanation_clauses: Vec<ClauseReference>,
 }
@@ -64,7 +64,7 @@ impl std::fmt::Display for LearnedClauseSortingStrategy {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 struct LearnedClauses {
     low_lbd: Vec<ClauseReference>,
     high_lbd: Vec<ClauseReference>,
@@ -72,7 +72,7 @@ struct LearnedClauses {
 
 // todo explain the learned clause removal strategy
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct LearnedClauseManager {
     learned_clauses: LearnedClauses,
     parameters: LearningOptions,
@@ -118,11 +118,30 @@ impl LearnedClauseManager {
         unreachable!("This should always allocate a clause");
     }
 
+    /// Returns the number of learned clauses which are currently in the database.
+    pub(crate) fn num_learned_clauses(&self) -> usize {
+        self.learned_clauses.low_lbd.len() + self.learned_clauses.high_lbd.len()
+    }
+
+    /// Returns the learned clauses which are currently in the database.
+    pub(crate) fn learned_clauses(&self) -> impl Iterator<Item = ClauseReference> + '_ {
+        self.learned_clauses
+            .low_lbd
+            .iter()
+            .chain(self.learned_clauses.high_lbd.iter())
+            .copied()
+    }
+
+    /// Removes roughly half of the learned clauses with a high LBD if there are too many of them.
+    ///
+    /// The callback `on_deletion` is called with every clause which is removed, before it is
+    /// deleted from the `clause_allocator`.
     pub(crate) fn shrink_learned_clause_database_if_needed(
         &mut self,
         assignments: &AssignmentsPropositional,
         clause_allocator: &mut ClauseAllocator,
         clausal_propagator: &mut ClausalPropagatorType,
+        on_deletion: impl FnMut(ClauseReference, &[Literal]),
     ) {
         // only consider clause removals once the threshold is reached
         if self.learned_clauses.high_lbd.len()
@@ -138,7 +157,12 @@ impl LearnedClauseManager {
 
         self.promote_high_lbd_clauses(clause_allocator);
 
-        self.remove_high_lbd_clauses(assignments, clause_allocator, clausal_propagator);
+        self.remove_high_lbd_clauses(
+            assignments,
+            clause_allocator,
+            clausal_propagator,
+            on_deletion,
+        );
     }
 
     fn remove_high_lbd_clauses(
@@ -146,6 +170,7 @@ impl LearnedClauseManager {
         assignments: &AssignmentsPropositional,
         clause_allocator: &mut ClauseAllocator,
         clausal_propagator: &mut ClausalPropagatorType,
+        mut on_deletion: impl FnMut(ClauseReference, &[Literal]),
     ) {
         // roughly half of the learned clauses will be removed
 
@@ -184,6 +209,10 @@ impl LearnedClauseManager {
             );
 
             // delete the clause
+            on_deletion(
+                clause_reference,
+                clause_allocator[clause_reference].get_literal_slice(),
+            );
             clause_allocator.delete_clause(clause_reference);
 
             num_clauses_to_remove -= 1;
@@ -96,7 +96,7 @@ impl Default for RestartOptions {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RestartStrategy {
     /// A generator for determining how many conflicts should be found before the next restart is
     /// able to take place (one example of such a generator is [`LubySequence`]).
@@ -276,3 +276,56 @@ impl RestartStrategy {
             .adapt(self.number_of_conflicts_until_restart);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RestartOptions;
+    use super::RestartStrategy;
+    use crate::basic_types::sequence_generators::SequenceGeneratorType;
+
+    /// Returns the number of conflicts between each of the first `num_restarts` restarts; the
+    /// options are set up such that restarts are never forced or blocked based on the LBD or the
+    /// number of assigned variables.
+    fn restart_intervals(
+        sequence_generator_type: SequenceGeneratorType,
+        num_restarts: usize,
+    ) -> Vec<u64> {
+        let mut restart_strategy = RestartStrategy::new(RestartOptions {
+            sequence_generator_type,
+            base_interval: 1,
+            min_num_conflicts_before_first_restart: 0,
+            lbd_coef: 0.0,
+            num_assigned_coef: f64::MAX,
+            geometric_coef: Some(2.0),
+            ..Default::default()
+        });
+
+        (0..num_restarts)
+            .map(|_| {
+                let mut num_conflicts = 0;
+                while !restart_strategy.should_restart() {
+                    restart_strategy.notify_conflict(2, 0);
+                    num_conflicts += 1;
+                }
+                restart_strategy.notify_restart();
+                num_conflicts
+            })
+            .collect()
+    }
+
+    #[test]
+    fn luby_restarts_follow_luby_sequence() {
+        assert_eq!(
+            vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2],
+            restart_intervals(SequenceGeneratorType::Luby, 10)
+        );
+    }
+
+    #[test]
+    fn geometric_restarts_follow_geometric_sequence() {
+        assert_eq!(
+            vec![1, 2, 4, 8, 16],
+            restart_intervals(SequenceGeneratorType::Geometric, 5)
+        );
+    }
+}
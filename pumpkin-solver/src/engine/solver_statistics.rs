@@ -25,6 +25,8 @@ create_statistics_struct!(
         num_propagations: u64,
         /// The amount of time which is spent in the solver
         time_spent_in_solver: u64,
+        /// The number of times the solver has been called to solve (under assumptions)
+        num_solve_calls: u64,
 });
 
 create_statistics_struct!(
@@ -40,6 +42,10 @@ create_statistics_struct!(
         num_unit_clauses_learned: u64,
         /// The average length of the learned clauses
         average_learned_clause_length: CumulativeMovingAverage,
+        /// The average literal block distance (LBD) of the learned clauses; i.e. the number of distinct decision levels in the learned clause at the moment it is learned
+        average_learned_clause_lbd: CumulativeMovingAverage,
+        /// The literal block distance (LBD) of the last learned clause
+        last_learned_clause_lbd: u64,
         /// The average number of levels which have been backtracked by the solver (e.g. when a learned clause is created)
          average_backtrack_amount: CumulativeMovingAverage,
 });
@@ -33,6 +33,11 @@ impl<Inner> AffineView<Inner> {
         }
     }
 
+    /// Returns the inner variable, the scale and the offset of this view.
+    pub(crate) fn into_parts(self) -> (Inner, i32, i32) {
+        (self.inner, self.scale, self.offset)
+    }
+
     /// Apply the inverse transformation of this view on a value, to go from the value in the domain
     /// of `self` to a value in the domain of `self.inner`.
     fn invert(&self, value: i32, rounding: Rounding) -> i32 {
@@ -49,6 +54,22 @@ impl<Inner> AffineView<Inner> {
     }
 }
 
+impl<Inner> AffineView<AffineView<Inner>> {
+    /// Composes this view with its inner view, resulting in a single [`AffineView`] over the
+    /// innermost variable; i.e. `a * (b * x + c) + d` becomes `(a * b) * x + (a * c + d)`.
+    ///
+    /// Note that [`TransformableVariable::scaled`] and [`TransformableVariable::offset`] already
+    /// compose the transformations when applied to an [`AffineView`]; this method is only needed
+    /// for views which are explicitly constructed over another view.
+    pub fn flatten(self) -> AffineView<Inner> {
+        AffineView {
+            inner: self.inner.inner,
+            scale: self.scale * self.inner.scale,
+            offset: self.scale * self.inner.offset + self.offset,
+        }
+    }
+}
+
 impl<View> IntegerVariable for AffineView<View>
 where
     View: IntegerVariable,
@@ -71,6 +92,22 @@ where
         }
     }
 
+    fn lower_bound_initial(&self, assignment: &AssignmentsInteger) -> i32 {
+        if self.scale < 0 {
+            self.map(self.inner.upper_bound_initial(assignment))
+        } else {
+            self.map(self.inner.lower_bound_initial(assignment))
+        }
+    }
+
+    fn upper_bound_initial(&self, assignment: &AssignmentsInteger) -> i32 {
+        if self.scale < 0 {
+            self.map(self.inner.lower_bound_initial(assignment))
+        } else {
+            self.map(self.inner.upper_bound_initial(assignment))
+        }
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
         if (value - self.offset) % self.scale == 0 {
             let inverted = self.invert(value, Rounding::Up);
@@ -259,6 +296,7 @@ enum Rounding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::variables::DomainId;
     use crate::predicate;
 
     #[test]
@@ -281,6 +319,71 @@ mod tests {
         assert_eq!(10, scaled_view.offset);
     }
 
+    #[test]
+    fn chained_transformations_result_in_a_single_view() {
+        let domain = DomainId::new(0);
+
+        let view: AffineView<DomainId> = domain.scaled(2).scaled(3).offset(4).offset(-1);
+        assert_eq!(6, view.scale);
+        assert_eq!(3, view.offset);
+
+        let view: AffineView<DomainId> = domain.offset(1).scaled(3);
+        assert_eq!(3, view.scale);
+        assert_eq!(3, view.offset);
+    }
+
+    #[test]
+    fn nested_view_has_the_same_domain_as_flattened_view() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-3, 7);
+        let _ = assignment.remove_value_from_domain(domain, 2, None);
+
+        let nested = AffineView::new(AffineView::new(domain, 2, 1), -3, 5);
+        let flattened = nested.flatten();
+        assert_eq!(-6, flattened.scale);
+        assert_eq!(2, flattened.offset);
+
+        assert_eq!(
+            nested.lower_bound(&assignment),
+            flattened.lower_bound(&assignment)
+        );
+        assert_eq!(
+            nested.upper_bound(&assignment),
+            flattened.upper_bound(&assignment)
+        );
+        for value in -50..50 {
+            assert_eq!(
+                nested.contains(&assignment, value),
+                flattened.contains(&assignment, value),
+                "views disagree on {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn nested_view_removes_the_same_values_as_flattened_view() {
+        let mut nested_assignment = AssignmentsInteger::default();
+        let mut flattened_assignment = AssignmentsInteger::default();
+        let nested_domain = nested_assignment.grow(-3, 7);
+        let flattened_domain = flattened_assignment.grow(-3, 7);
+
+        let nested = AffineView::new(AffineView::new(nested_domain, 2, 1), -3, 5);
+        let flattened = AffineView::new(AffineView::new(flattened_domain, 2, 1), -3, 5).flatten();
+
+        for value in [-10, -9, 2, 3, 8, 13] {
+            let _ = nested.remove(&mut nested_assignment, value, None);
+            let _ = flattened.remove(&mut flattened_assignment, value, None);
+        }
+
+        for value in -3..=7 {
+            assert_eq!(
+                nested_assignment.is_value_in_domain(nested_domain, value),
+                flattened_assignment.is_value_in_domain(flattened_domain, value),
+                "domains disagree on {value}"
+            );
+        }
+    }
+
     #[test]
     fn affine_view_obtaining_a_bound_should_round_optimistically_in_inner_domain() {
         let domain = DomainId::new(0);
@@ -300,4 +403,57 @@ mod tests {
         assert_eq!(predicate!(view <= -3), predicate!(domain >= 2));
         assert_eq!(predicate!(view >= 5), predicate!(domain <= -3));
     }
+
+    #[test]
+    fn negated_view_mirrors_the_domain_of_the_inner_variable() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-2, 5);
+        let negated = domain.negated();
+
+        let check_mirrored = |assignment: &AssignmentsInteger| {
+            assert_eq!(
+                -domain.upper_bound(assignment),
+                negated.lower_bound(assignment)
+            );
+            assert_eq!(
+                -domain.lower_bound(assignment),
+                negated.upper_bound(assignment)
+            );
+            for value in -10..=10 {
+                assert_eq!(
+                    domain.contains(assignment, value),
+                    negated.contains(assignment, -value),
+                    "views disagree on {value}"
+                );
+            }
+        };
+
+        check_mirrored(&assignment);
+
+        let _ = assignment.remove_value_from_domain(domain, 1, None);
+        check_mirrored(&assignment);
+
+        let _ = negated.set_upper_bound(&mut assignment, 0, None);
+        assert_eq!(0, domain.lower_bound(&assignment));
+        check_mirrored(&assignment);
+
+        let _ = negated.set_lower_bound(&mut assignment, -3, None);
+        assert_eq!(3, domain.upper_bound(&assignment));
+        check_mirrored(&assignment);
+    }
+
+    #[test]
+    fn initial_bounds_of_a_negated_view_are_mirrored() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-3, 7);
+        let view = AffineView::new(domain, -2, 1);
+
+        let _ = assignment.tighten_lower_bound(domain, 0, None);
+        let _ = assignment.tighten_upper_bound(domain, 4, None);
+
+        assert_eq!(-13, view.lower_bound_initial(&assignment));
+        assert_eq!(7, view.upper_bound_initial(&assignment));
+        assert_eq!(-7, view.lower_bound(&assignment));
+        assert_eq!(1, view.upper_bound(&assignment));
+    }
 }
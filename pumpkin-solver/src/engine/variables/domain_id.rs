@@ -36,6 +36,14 @@ impl IntegerVariable for DomainId {
         assignment.get_upper_bound(*self)
     }
 
+    fn lower_bound_initial(&self, assignment: &AssignmentsInteger) -> i32 {
+        assignment.get_initial_lower_bound(*self)
+    }
+
+    fn upper_bound_initial(&self, assignment: &AssignmentsInteger) -> i32 {
+        assignment.get_initial_upper_bound(*self)
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
         assignment.is_value_in_domain(*self, value)
     }
@@ -24,6 +24,14 @@ pub trait IntegerVariable:
     /// Get the upper bound of the variable.
     fn upper_bound(&self, assignment: &AssignmentsInteger) -> i32;
 
+    /// Get the lower bound of the variable when it was created, i.e. before any propagation
+    /// took place.
+    fn lower_bound_initial(&self, assignment: &AssignmentsInteger) -> i32;
+
+    /// Get the upper bound of the variable when it was created, i.e. before any propagation
+    /// took place.
+    fn upper_bound_initial(&self, assignment: &AssignmentsInteger) -> i32;
+
     /// Determine whether the value is in the domain of this variable.
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool;
 
@@ -1,8 +1,9 @@
 /// Trait for transforming a variable
 ///
 /// At the moment this allows creating a scaled version of a
-/// variable using [`TransformableVariable::scaled`] or creating a variable with a constant offset
-/// based on the original variable using [`TransformableVariable::offset`].
+/// variable using [`TransformableVariable::scaled`], creating a variable with a constant offset
+/// based on the original variable using [`TransformableVariable::offset`], or creating the
+/// negation of a variable using [`TransformableVariable::negated`].
 pub trait TransformableVariable<View> {
     /// Get a variable which domain is scaled compared to the domain of self.
     ///
@@ -12,4 +13,12 @@ pub trait TransformableVariable<View> {
 
     /// Get a variable which domain has a constant offset to the domain of self.
     fn offset(&self, offset: i32) -> View;
+
+    /// Get a variable which domain is the negation of the domain of self; e.g. if we have
+    /// `dom(x) = {1, 3}`, then `dom(x.negated()) = {-3, -1}`.
+    ///
+    /// This is equivalent to `self.scaled(-1)`.
+    fn negated(&self) -> View {
+        self.scaled(-1)
+    }
 }
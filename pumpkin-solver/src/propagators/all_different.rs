@@ -0,0 +1,350 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Bounds-consistent propagator for the constraint `all_different([x_1, \ldots, x_n])` based on
+/// the detection of Hall intervals \[1\].
+///
+/// A Hall interval is an interval `[a, b]` which contains the domains of exactly `b - a + 1`
+/// variables; these variables take all the values in `[a, b]`, so the bounds of the other
+/// variables are pushed out of the interval. If an interval `[a, b]` contains the domains of more
+/// than `b - a + 1` variables, then a conflict is reported. Additionally, the value of a fixed
+/// variable is removed from the domains of the other variables, which also removes values from the
+/// inside of their domains.
+///
+/// Following Puget \[1\], the lower-bounds are updated in a single pass over the variables in
+/// order of non-decreasing upper-bound, after which the upper-bounds are updated by the same pass
+/// over the negated variables. The Hall intervals are found incrementally during the pass, which
+/// results in a time complexity of `O(n^2)` per call (rather than the `O(n log n)` of Puget, which
+/// relies on a balanced tree).
+///
+/// # Bibliography
+/// \[1\] J.-F. Puget, ‘A fast algorithm for the bound consistency of alldiff constraints’, in
+/// AAAI/IAAI, 1998, pp. 359–366.
+#[derive(Clone, Debug)]
+pub(crate) struct AllDifferentPropagator<Var> {
+    variables: Box<[Var]>,
+}
+
+/// A Hall interval `[lower, upper]` together with the explanation of why the variables whose
+/// domains are contained in it take all of its values.
+struct HallInterval {
+    lower: i32,
+    upper: i32,
+    explanation: PropositionalConjunction,
+}
+
+impl<Var: IntegerVariable> AllDifferentPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>) -> Self {
+        AllDifferentPropagator { variables }
+    }
+}
+
+/// Removes the value of every fixed variable from the domains of the other `variables`.
+fn remove_fixed_values<Var: IntegerVariable>(
+    variables: &[Var],
+    context: &mut PropagationContextMut,
+) -> PropagationStatusCP {
+    for (index, variable) in variables.iter().enumerate() {
+        if !context.is_fixed(variable) {
+            continue;
+        }
+
+        let value = context.lower_bound(variable);
+        for (other_index, other) in variables.iter().enumerate() {
+            if other_index != index && context.contains(other, value) {
+                context.remove(other, value, conjunction!([variable == value]))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pushes the lower-bounds of the `variables` out of the Hall intervals; the upper-bounds are
+/// updated by calling this function with the negated variables.
+fn update_lower_bounds<Var: IntegerVariable>(
+    variables: &[Var],
+    context: &mut PropagationContextMut,
+) -> PropagationStatusCP {
+    let mut by_upper_bound = (0..variables.len()).collect::<Vec<_>>();
+    by_upper_bound.sort_by_key(|&index| context.upper_bound(&variables[index]));
+
+    // The lower-bound and the index of the visited variables, by non-increasing lower-bound
+    let mut visited: Vec<(i32, usize)> = Vec::with_capacity(variables.len());
+    // The Hall intervals found so far, by non-decreasing upper-bound
+    let mut hall_intervals: Vec<HallInterval> = Vec::new();
+
+    for (position, &index) in by_upper_bound.iter().enumerate() {
+        let variable = &variables[index];
+        let upper = context.upper_bound(variable);
+
+        // Since the Hall intervals are ordered by their upper-bound, a single pass over them
+        // suffices to push the lower-bound out of all of them
+        for hall_interval in hall_intervals.iter() {
+            let lower = context.lower_bound(variable);
+            if lower < hall_interval.lower || lower > hall_interval.upper {
+                continue;
+            }
+
+            let mut reason = hall_interval.explanation.clone();
+            reason.add(predicate![variable >= hall_interval.lower]);
+
+            if upper <= hall_interval.upper {
+                // The Hall interval contains one domain too many
+                reason.add(predicate![variable <= hall_interval.upper]);
+                return Err(reason.into());
+            }
+
+            context.set_lower_bound(variable, hall_interval.upper + 1, reason)?;
+        }
+
+        let lower = context.lower_bound(variable);
+        let insertion_point = visited.partition_point(|&(other_lower, _)| other_lower > lower);
+        visited.insert(insertion_point, (lower, index));
+
+        // Only consider the intervals ending at `upper` once all variables with this upper-bound
+        // are visited
+        let is_last_with_upper_bound = by_upper_bound
+            .get(position + 1)
+            .is_none_or(|&next| context.upper_bound(&variables[next]) != upper);
+        if !is_last_with_upper_bound {
+            continue;
+        }
+
+        // The interval `[a, upper]` contains the domains of the visited variables with a
+        // lower-bound of at least `a`; of the Hall intervals ending at `upper`, the largest one
+        // is kept since it contains all of the others.
+        let mut largest_hall_interval = None;
+        for (num_visited, &(a, _)) in visited.iter().enumerate() {
+            let num_contained = num_visited as i64 + 1;
+            if visited
+                .get(num_visited + 1)
+                .is_some_and(|&(next_lower, _)| next_lower == a)
+            {
+                continue;
+            }
+
+            let interval_size = upper as i64 - a as i64 + 1;
+            if num_contained > interval_size {
+                return Err(
+                    interval_explanation(variables, &visited[..=num_visited], a, upper).into(),
+                );
+            }
+
+            if num_contained == interval_size {
+                largest_hall_interval = Some((a, num_visited));
+            }
+        }
+
+        if let Some((a, num_visited)) = largest_hall_interval {
+            hall_intervals.push(HallInterval {
+                lower: a,
+                upper,
+                explanation: interval_explanation(variables, &visited[..=num_visited], a, upper),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// The explanation for the domains of the `contained` variables being contained in the interval
+/// `[lower, upper]`.
+fn interval_explanation<Var: IntegerVariable>(
+    variables: &[Var],
+    contained: &[(i32, usize)],
+    lower: i32,
+    upper: i32,
+) -> PropositionalConjunction {
+    contained
+        .iter()
+        .flat_map(|&(_, index)| {
+            let variable = &variables[index];
+            [predicate![variable >= lower], predicate![variable <= upper]]
+        })
+        .collect()
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for AllDifferentPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::BOUNDS,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "AllDifferent"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        remove_fixed_values(&self.variables, &mut context)?;
+        update_lower_bounds(&self.variables, &mut context)?;
+
+        let negated_variables = self
+            .variables
+            .iter()
+            .map(|variable| variable.scaled(-1))
+            .collect::<Vec<_>>();
+        update_lower_bounds(&negated_variables, &mut context)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn hall_interval_pushes_lower_bounds_of_other_variables() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 4);
+        let w = solver.new_variable(2, 5);
+
+        let _ = solver
+            .new_propagator(AllDifferentPropagator::new([x, y, z, w].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 1, 2);
+        solver.assert_bounds(y, 1, 2);
+        solver.assert_bounds(z, 3, 4);
+        solver.assert_bounds(w, 3, 5);
+
+        let reason = solver.get_reason_int(predicate![z >= 3].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1]),
+            *reason
+        );
+
+        let reason = solver.get_reason_int(predicate![w >= 3].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [w >= 1]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn hall_interval_pushes_upper_bounds_of_other_variables() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let w = solver.new_variable(0, 2);
+
+        let _ = solver
+            .new_propagator(AllDifferentPropagator::new([x, y, w].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(w, 0, 0);
+
+        let reason = solver.get_reason_int(predicate![w <= 0].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [w <= 2]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn hall_intervals_are_chained_within_a_single_pass() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(AllDifferentPropagator::new([x, y, z].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 1, 1);
+        solver.assert_bounds(y, 2, 2);
+        solver.assert_bounds(z, 3, 3);
+    }
+
+    #[test]
+    fn too_many_variables_in_interval_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 2);
+        let w = solver.new_variable(0, 5);
+
+        let result = solver.new_propagator(AllDifferentPropagator::new([x, y, z, w].into()));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(
+                conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1] & [z <= 2]),
+                conjunction
+            );
+        }
+    }
+
+    #[test]
+    fn lower_and_upper_bounds_are_tightened_in_a_single_call() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(3, 3);
+        let z = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(AllDifferentPropagator::new([x, y, z].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(z, 2, 2);
+    }
+
+    #[test]
+    fn value_of_fixed_variable_is_removed_from_inside_other_domains() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 3);
+        let y = solver.new_variable(1, 5);
+
+        let _ = solver
+            .new_propagator(AllDifferentPropagator::new([x, y].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 1, 5);
+        assert!(!solver.contains(y, 3));
+
+        let reason = solver.get_reason_int(predicate![y != 3].try_into().unwrap());
+        assert_eq!(conjunction!([x == 3]), *reason);
+    }
+}
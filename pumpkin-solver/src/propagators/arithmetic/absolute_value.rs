@@ -24,7 +24,9 @@ impl<VA, VB> AbsoluteValuePropagator<VA, VB> {
     }
 }
 
-impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropagator<VA, VB> {
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Propagator
+    for AbsoluteValuePropagator<VA, VB>
+{
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -116,12 +118,17 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
 
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
 
     #[test]
     fn absolute_bounds_are_propagated_at_initialise() {
@@ -206,4 +213,39 @@ mod tests {
 
         solver.assert_bounds(signed, 3, 5);
     }
+
+    #[test]
+    fn upper_bound_of_absolute_is_explained_by_bounds_of_straddling_signed() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-6, 4);
+        let absolute = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(AbsoluteValuePropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        solver.assert_bounds(absolute, 0, 6);
+        let reason = solver.get_reason_int(predicate![absolute <= 6].try_into().unwrap());
+        assert_eq!(conjunction!([signed >= -6] & [signed <= 4]), *reason);
+    }
+
+    #[test]
+    fn bounds_of_signed_are_explained_by_upper_bound_of_absolute() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-10, 10);
+        let absolute = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(AbsoluteValuePropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, absolute, 7);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(signed, -7, 7);
+        let reason = solver.get_reason_int(predicate![signed >= -7].try_into().unwrap());
+        assert_eq!(conjunction!([absolute <= 7]), *reason);
+    }
 }
@@ -8,12 +8,12 @@ use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::DomainEvents;
 use crate::predicates::PropositionalConjunction;
-use crate::pumpkin_assert_simple;
 
 /// A propagator for maintaining the constraint `numerator / denominator = rhs`; note that this
 /// propagator performs truncating division (i.e. rounding towards 0).
 ///
-/// The propagator assumes that the `denominator` is a (non-zero) number.
+/// The propagator enforces that the `denominator` is non-zero; if the `denominator` is fixed to 0
+/// then a conflict is reported.
 ///
 /// The implementation is ported from [OR-tools](https://github.com/google/or-tools/blob/870edf6f7bff6b8ff0d267d936be7e331c5b8c2d/ortools/sat/integer_expr.cc#L1209C1-L1209C19).
 #[derive(Clone, Debug)]
@@ -39,9 +39,9 @@ impl<VA, VB, VC> DivisionPropagator<VA, VB, VC> {
 
 impl<VA, VB, VC> Propagator for DivisionPropagator<VA, VB, VC>
 where
-    VA: IntegerVariable,
-    VB: IntegerVariable,
-    VC: IntegerVariable,
+    VA: IntegerVariable + 'static,
+    VB: IntegerVariable + 'static,
+    VC: IntegerVariable + 'static,
 {
     fn priority(&self) -> u32 {
         0
@@ -55,10 +55,6 @@ where
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
-        pumpkin_assert_simple!(
-            !context.contains(&self.denominator, 0),
-            "Denominator cannot contain 0"
-        );
         let _ = context.register(self.numerator.clone(), DomainEvents::BOUNDS, ID_NUMERATOR);
         let _ = context.register(
             self.denominator.clone(),
@@ -73,6 +69,10 @@ where
     fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
         perform_propagation(context, &self.numerator, &self.denominator, &self.rhs)
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable>(
@@ -81,11 +81,16 @@ fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVari
     denominator: &VB,
     rhs: &VC,
 ) -> PropagationStatusCP {
+    // The division is undefined when the denominator is 0, this is implied by the constraint
+    // itself and therefore does not require any further explanation
+    if context.contains(denominator, 0) {
+        context.remove(denominator, 0, conjunction!())?;
+    }
+
     if context.lower_bound(denominator) < 0 && context.upper_bound(denominator) > 0 {
-        // For now we don't do anything in this case, note that this will not lead to incorrect
-        // behaviour since any solution to this constraint will necessarily have to fix the
-        // denominator.
-        return Ok(());
+        // The sign of the denominator is not known, so we only make use of the fact that the
+        // absolute value of the denominator is at least 1, i.e. |rhs| <= |numerator|.
+        return propagate_unknown_denominator_sign(&mut context, numerator, rhs);
     }
 
     let mut negated_numerator = &numerator.scaled(-1);
@@ -136,6 +141,37 @@ fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVari
     Ok(())
 }
 
+/// Propagates the bounds of the right-hand side when the sign of the denominator is not known; since
+/// the absolute value of the denominator is at least 1, the absolute value of the right-hand side
+/// can be at most the largest absolute value that the numerator can take on.
+fn propagate_unknown_denominator_sign<VA: IntegerVariable, VC: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    numerator: &VA,
+    rhs: &VC,
+) -> PropagationStatusCP {
+    let numerator_min = context.lower_bound(numerator);
+    let numerator_max = context.upper_bound(numerator);
+    let largest_absolute_value = numerator_min.abs().max(numerator_max.abs());
+
+    if context.upper_bound(rhs) > largest_absolute_value {
+        context.set_upper_bound(
+            rhs,
+            largest_absolute_value,
+            conjunction!([numerator >= numerator_min] & [numerator <= numerator_max]),
+        )?;
+    }
+
+    if context.lower_bound(rhs) < -largest_absolute_value {
+        context.set_lower_bound(
+            rhs,
+            -largest_absolute_value,
+            conjunction!([numerator >= numerator_min] & [numerator <= numerator_max]),
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Propagates the domains of variables if all the domains are positive (if the variables are
 /// sign-fixed then we simply transform them to positive domains using [`AffineView`]s); it performs
 /// the following propagations:
@@ -323,6 +359,7 @@ fn propagate_signs<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable
 mod tests {
     use super::*;
     use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
 
     #[test]
     fn detects_conflicts() {
@@ -336,4 +373,74 @@ mod tests {
 
         assert!(propagator.is_err());
     }
+
+    #[test]
+    fn positive_numerator_and_denominator() {
+        let mut solver = TestSolver::default();
+        let numerator = solver.new_variable(10, 20);
+        let denominator = solver.new_variable(2, 5);
+        let rhs = solver.new_variable(0, 100);
+
+        let _ = solver
+            .new_propagator(DivisionPropagator::new(numerator, denominator, rhs))
+            .expect("no empty domains");
+
+        solver.assert_bounds(rhs, 2, 10);
+
+        let reason = solver.get_reason_int(predicate![rhs >= 2].try_into().unwrap());
+        assert_eq!(
+            conjunction!([numerator >= 10] & [denominator <= 5] & [denominator >= 1]),
+            *reason
+        );
+        let reason = solver.get_reason_int(predicate![rhs <= 10].try_into().unwrap());
+        assert_eq!(
+            conjunction!([numerator <= 20] & [denominator >= 2]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn negative_numerator_and_positive_denominator() {
+        let mut solver = TestSolver::default();
+        let numerator = solver.new_variable(-20, -10);
+        let denominator = solver.new_variable(2, 5);
+        let rhs = solver.new_variable(-100, 100);
+
+        let _ = solver
+            .new_propagator(DivisionPropagator::new(numerator, denominator, rhs))
+            .expect("no empty domains");
+
+        // Truncating division rounds towards 0
+        solver.assert_bounds(rhs, -10, -2);
+    }
+
+    #[test]
+    fn denominator_with_unknown_sign() {
+        let mut solver = TestSolver::default();
+        let numerator = solver.new_variable(-7, 3);
+        let denominator = solver.new_variable(-2, 2);
+        let rhs = solver.new_variable(-20, 20);
+
+        let _ = solver
+            .new_propagator(DivisionPropagator::new(numerator, denominator, rhs))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(denominator, 0));
+        solver.assert_bounds(rhs, -7, 7);
+
+        let reason = solver.get_reason_int(predicate![rhs <= 7].try_into().unwrap());
+        assert_eq!(conjunction!([numerator >= -7] & [numerator <= 3]), *reason);
+    }
+
+    #[test]
+    fn denominator_fixed_to_zero_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let numerator = solver.new_variable(0, 10);
+        let denominator = solver.new_variable(0, 0);
+        let rhs = solver.new_variable(0, 10);
+
+        let result = solver.new_propagator(DivisionPropagator::new(numerator, denominator, rhs));
+
+        assert!(result.is_err());
+    }
 }
@@ -36,9 +36,9 @@ where
 
 impl<VA, VB, VC> Propagator for IntegerMultiplicationPropagator<VA, VB, VC>
 where
-    VA: IntegerVariable,
-    VB: IntegerVariable,
-    VC: IntegerVariable,
+    VA: IntegerVariable + 'static,
+    VB: IntegerVariable + 'static,
+    VC: IntegerVariable + 'static,
 {
     fn initialise_at_root(
         &mut self,
@@ -62,6 +62,10 @@ where
     fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
         perform_propagation(context, &self.a, &self.b, &self.c)
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable>(
@@ -1,3 +1,4 @@
+use crate::basic_types::HashSet;
 use crate::basic_types::PropagationStatusCP;
 use crate::basic_types::PropositionalConjunction;
 use crate::engine::cp::propagation::ReadDomains;
@@ -23,6 +24,9 @@ pub(crate) struct LinearLessOrEqualPropagator<Var> {
     lower_bound_left_hand_side: i64,
     /// The value at index `i` is the bound for `x[i]`.
     current_bounds: Box<[i32]>,
+    /// Whether the terms are over pairwise distinct domains; otherwise, tightening the upper bound
+    /// of one term can raise the lower bound of another term over the same domain.
+    has_distinct_domains: bool,
 }
 
 impl<Var> LinearLessOrEqualPropagator<Var>
@@ -38,9 +42,30 @@ where
             c,
             lower_bound_left_hand_side: 0,
             current_bounds,
+            has_distinct_domains: false,
         }
     }
 
+    /// Creates an explanation consisting of the lower bounds of the terms, except for the term at
+    /// index `excluded` (if provided).
+    ///
+    /// A term which is at its initial lower bound is omitted, since its lower bound is trivially
+    /// satisfied and does not contribute to justifying the propagation.
+    fn create_explanation(
+        &self,
+        context: PropagationContext,
+        excluded: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .enumerate()
+            .filter(|&(j, x_j)| {
+                Some(j) != excluded && context.lower_bound(x_j) > context.lower_bound_initial(x_j)
+            })
+            .map(|(_, x_j)| predicate![x_j >= context.lower_bound(x_j)])
+            .collect()
+    }
+
     /// Recalculates the incremental state from scratch.
     fn recalculate_incremental_state(&mut self, context: PropagationContext) {
         self.lower_bound_left_hand_side = self
@@ -60,7 +85,7 @@ where
 
 impl<Var> Propagator for LinearLessOrEqualPropagator<Var>
 where
-    Var: IntegerVariable,
+    Var: IntegerVariable + 'static,
 {
     fn initialise_at_root(
         &mut self,
@@ -74,6 +99,14 @@ where
             );
         });
 
+        // The predicate is only used to find the domain of the term
+        let mut domains: HashSet<_> = HashSet::default();
+        self.has_distinct_domains = self.x.iter().all(|x_i| {
+            predicate![x_i >= 0]
+                .get_domain()
+                .is_some_and(|domain| domains.insert(domain))
+        });
+
         self.recalculate_incremental_state(context.as_readonly());
 
         if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
@@ -88,12 +121,7 @@ where
         context: PropagationContext,
     ) -> Option<PropositionalConjunction> {
         if (self.c as i64) < self.lower_bound_left_hand_side {
-            let reason: PropositionalConjunction = self
-                .x
-                .iter()
-                .map(|var| predicate![var >= context.lower_bound(var)])
-                .collect();
-            Some(reason)
+            Some(self.create_explanation(context, None))
         } else {
             None
         }
@@ -134,6 +162,12 @@ where
         "LinearLeq"
     }
 
+    fn is_idempotent(&self) -> bool {
+        // Only upper bounds are tightened, which do not affect the lower bound of the left-hand side
+        // on which the propagation is based, unless a domain occurs in several terms
+        self.has_distinct_domains
+    }
+
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
             return Err(conjunction.into());
@@ -146,18 +180,7 @@ where
                 .expect("Could not fit the lower-bound of lhs in an i32");
 
             if context.upper_bound(x_i) > bound {
-                let reason: PropositionalConjunction = self
-                    .x
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+                let reason = self.create_explanation(context.as_readonly(), Some(i));
 
                 context.set_upper_bound(x_i, bound, reason)?;
             }
@@ -183,18 +206,7 @@ where
                 .expect("Could not fit the lower-bound of lhs in an i32");
 
             if context.upper_bound(x_i) > bound {
-                let reason: PropositionalConjunction = self
-                    .x
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+                let reason = self.create_explanation(context.as_readonly(), Some(i));
 
                 context.set_upper_bound(x_i, bound, reason)?;
             }
@@ -202,6 +214,10 @@ where
 
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +225,8 @@ mod tests {
     use super::*;
     use crate::conjunction;
     use crate::engine::test_helper::TestSolver;
+    use crate::engine::variables::TransformableVariable;
+    use crate::propagators::ReifiedPropagator;
 
     #[test]
     fn test_bounds_are_propagated() {
@@ -229,17 +247,100 @@ mod tests {
     #[test]
     fn test_explanations() {
         let mut solver = TestSolver::default();
-        let x = solver.new_variable(1, 5);
+        let x = solver.new_variable(0, 5);
         let y = solver.new_variable(0, 10);
 
         let mut propagator = solver
             .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), 7))
             .expect("no empty domains");
 
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 1);
         solver.propagate(&mut propagator).expect("non-empty domain");
 
         let reason = solver.get_reason_int(predicate![y <= 6].try_into().unwrap());
 
         assert_eq!(conjunction!([x >= 1]), *reason);
     }
+
+    #[test]
+    fn terms_at_their_initial_lower_bound_are_not_in_the_explanation() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+        let y = solver.new_variable(2, 10);
+        let z = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y, z].into(), 7))
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 3);
+        solver.propagate(&mut propagator).expect("non-empty domain");
+
+        solver.assert_bounds(z, 0, 2);
+        let reason = solver.get_reason_int(predicate![z <= 2].try_into().unwrap());
+        assert_eq!(conjunction!([x >= 3]), *reason);
+    }
+
+    #[test]
+    fn reified_constraint_is_falsified_when_lower_bounds_exceed_rhs() {
+        let mut solver = TestSolver::default();
+        let reification_literal = solver.new_literal();
+        let x = solver.new_variable(4, 5);
+        let y = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 7),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+        assert!(!solver.is_literal_false(reification_literal));
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, y, 4);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, false);
+        // The lower bound of `x` is its initial lower bound, which is trivially satisfied
+        assert_eq!(&conjunction!([y >= 4]), reason);
+    }
+
+    #[test]
+    fn terms_over_distinct_domains_are_idempotent() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(2), y.scaled(-1)].into(),
+                5,
+            ))
+            .expect("no empty domains");
+
+        assert!(propagator.is_idempotent());
+    }
+
+    #[test]
+    fn terms_over_the_same_domain_are_not_idempotent() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(2), x.scaled(-1)].into(),
+                5,
+            ))
+            .expect("no empty domains");
+        assert!(!propagator.is_idempotent());
+
+        // A single call only tightens the upper bound of `2x` based on the initial lower bound of
+        // `-x`, which is raised by the tightening
+        solver.assert_bounds(x, 0, 7);
+
+        solver
+            .propagate_until_fixed_point(&mut propagator)
+            .expect("no empty domains");
+        solver.assert_bounds(x, 0, 5);
+    }
 }
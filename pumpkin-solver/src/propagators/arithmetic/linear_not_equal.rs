@@ -1,5 +1,3 @@
-use std::rc::Rc;
-
 use enumset::enum_set;
 
 use crate::basic_types::PropagationStatusCP;
@@ -25,7 +23,7 @@ use crate::pumpkin_assert_simple;
 #[derive(Clone, Debug)]
 pub(crate) struct LinearNotEqualPropagator<Var> {
     /// The terms of the sum
-    terms: Rc<[Var]>,
+    terms: Box<[Var]>,
     /// The right-hand side of the sum
     rhs: i32,
 
@@ -48,7 +46,7 @@ where
 {
     pub(crate) fn new(terms: Box<[Var]>, rhs: i32) -> Self {
         LinearNotEqualPropagator {
-            terms: terms.into(),
+            terms,
             rhs,
             number_of_fixed_terms: 0,
             fixed_lhs: 0,
@@ -66,6 +64,12 @@ where
         0
     }
 
+    fn is_idempotent(&self) -> bool {
+        // The only propagation is the removal of a single value from the domain of the unfixed
+        // variable, after which no further propagation is possible
+        true
+    }
+
     fn name(&self) -> &str {
         "LinearNe"
     }
@@ -152,6 +156,28 @@ where
         Ok(())
     }
 
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        // The incremental state might be outdated, so the check is performed from scratch
+        if !self.terms.iter().all(|x_i| context.is_fixed(x_i)) {
+            return None;
+        }
+
+        let lhs = self
+            .terms
+            .iter()
+            .map(|x_i| context.lower_bound(x_i))
+            .sum::<i32>();
+        (lhs == self.rhs).then(|| {
+            self.terms
+                .iter()
+                .map(|x_i| predicate![x_i == context.lower_bound(x_i)])
+                .collect()
+        })
+    }
+
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         // If the left-hand side is out of date then we simply recalculate from scratch; we only do
         // this when we can propagate or check for a conflict
@@ -256,6 +282,10 @@ where
 
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl<Var: IntegerVariable + 'static> LinearNotEqualPropagator<Var> {
@@ -312,7 +342,7 @@ impl<Var: IntegerVariable + 'static> LinearNotEqualPropagator<Var> {
         let number_of_fixed_terms_is_correct =
             self.number_of_fixed_terms == expected_number_of_fixed_terms;
 
-        let expected_fixed_lhs = self
+        let expected_fixed_lhs: i32 = self
             .terms
             .iter()
             .filter_map(|x_i| {
@@ -337,6 +367,7 @@ mod tests {
     use crate::conjunction;
     use crate::engine::test_helper::TestSolver;
     use crate::engine::variables::TransformableVariable;
+    use crate::propagators::ReifiedPropagator;
 
     #[test]
     fn test_value_is_removed() {
@@ -358,6 +389,31 @@ mod tests {
         assert!(!solver.contains(y, 2));
     }
 
+    #[test]
+    fn fixing_all_but_one_term_removes_a_value_from_the_last_term() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1);
+        let y = solver.new_variable(2, 4);
+        let z = solver.new_variable(0, 5);
+
+        let mut propagator = solver
+            .new_propagator(LinearNotEqualPropagator::new(
+                [x.scaled(1), y.scaled(1), z.scaled(1)].into(),
+                6,
+            ))
+            .expect("non-empty domain");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 1);
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, y, 2);
+        solver.propagate(&mut propagator).expect("non-empty domain");
+
+        solver.assert_bounds(z, 0, 5);
+        assert!(!solver.contains(z, 3));
+
+        let reason = solver.get_reason_int(predicate![z != 3].try_into().unwrap());
+        assert_eq!(conjunction!([x == 1] & [y == 2]), *reason);
+    }
+
     #[test]
     fn test_empty_domain_is_detected() {
         let mut solver = TestSolver::default();
@@ -417,4 +473,23 @@ mod tests {
 
         solver.propagate(&mut propagator).expect("non-empty domain");
     }
+
+    #[test]
+    fn reified_constraint_is_falsified_by_assignment_with_equal_sum() {
+        let mut solver = TestSolver::default();
+        let reification_literal = solver.new_literal();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(3, 3);
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                LinearNotEqualPropagator::new([x, y].into(), 5),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, false);
+        assert_eq!(&conjunction!([x == 2] & [y == 3]), reason);
+    }
 }
@@ -24,7 +24,7 @@ impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> MaximumPropagator<Elemen
     }
 }
 
-impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> Propagator
+impl<ElementVar: IntegerVariable + 'static, Rhs: IntegerVariable + 'static> Propagator
     for MaximumPropagator<ElementVar, Rhs>
 {
     fn initialise_at_root(
@@ -132,12 +132,17 @@ impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> Propagator
 
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::engine::test_helper::TestSolver;
+    use crate::engine::variables::TransformableVariable;
 
     #[test]
     fn upper_bound_of_rhs_matches_maximum_upper_bound_of_array_at_initialise() {
@@ -217,4 +222,57 @@ mod tests {
         solver.assert_bounds(*array.last().unwrap(), 45, 51);
         solver.assert_bounds(rhs, 45, 51);
     }
+
+    #[test]
+    fn minimum_through_negated_views_bounds_rhs() {
+        let mut solver = TestSolver::default();
+
+        let a = solver.new_variable(3, 8);
+        let b = solver.new_variable(4, 6);
+        let c = solver.new_variable(5, 10);
+
+        let rhs = solver.new_variable(0, 10);
+
+        // min(array) = rhs is equivalent to max(-array) = -rhs
+        let _ = solver
+            .new_propagator(MaximumPropagator::new(
+                [a, b, c].map(|var| var.negated()).into(),
+                rhs.negated(),
+            ))
+            .expect("no empty domain");
+
+        solver.assert_bounds(rhs, 3, 6);
+
+        let reason = solver.get_reason_int(predicate![rhs >= 3].try_into().unwrap());
+        assert_eq!(conjunction!([a >= 3] & [b >= 3] & [c >= 3]), reason.clone());
+        let reason = solver.get_reason_int(predicate![rhs <= 6].try_into().unwrap());
+        assert_eq!(conjunction!([b <= 6]), reason.clone());
+    }
+
+    #[test]
+    fn minimum_through_negated_views_bounds_array() {
+        let mut solver = TestSolver::default();
+
+        let array = (0..3)
+            .map(|idx| solver.new_variable(idx, 10))
+            .collect::<Box<_>>();
+
+        let rhs = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(MaximumPropagator::new(
+                array.iter().map(|var| var.negated()).collect(),
+                rhs.negated(),
+            ))
+            .expect("no empty domain");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, rhs, 4);
+        solver.propagate(&mut propagator).expect("no empty domain");
+
+        for var in array.iter() {
+            solver.assert_bounds(*var, 4, 10);
+            let reason = solver.get_reason_int(predicate![var >= 4].try_into().unwrap());
+            assert_eq!(conjunction!([rhs >= 4]), reason.clone());
+        }
+    }
 }
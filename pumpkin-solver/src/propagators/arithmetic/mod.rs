@@ -4,3 +4,5 @@ pub(crate) mod integer_multiplication;
 pub(crate) mod linear_less_or_equal;
 pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
+pub(crate) mod pseudo_boolean_less_or_equal;
+pub(crate) mod square;
@@ -0,0 +1,260 @@
+use crate::basic_types::HashSet;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::Literal;
+
+/// Propagator for the constraint `\sum w_i * l_i <= k`, where every literal `l_i` is interpreted
+/// as a 0/1 variable.
+///
+/// Terms with a negative weight are normalised upon construction by rewriting `w * l` to
+/// `-w * !l + w`, such that the propagator only needs to reason over non-negative weights.
+#[derive(Clone, Debug)]
+pub(crate) struct PseudoBooleanLessOrEqualPropagator {
+    /// The terms `(w_i, l_i)` of the left-hand side; all weights are non-negative.
+    terms: Box<[(i64, Literal)]>,
+    k: i64,
+    /// Whether the literals are over pairwise distinct propositional variables; otherwise, setting
+    /// a literal to false can set the literal of another term to true.
+    has_distinct_variables: bool,
+}
+
+impl PseudoBooleanLessOrEqualPropagator {
+    pub(crate) fn new(terms: impl IntoIterator<Item = (i32, Literal)>, k: i32) -> Self {
+        let mut k = k as i64;
+        let terms = terms
+            .into_iter()
+            .map(|(weight, literal)| {
+                let weight = weight as i64;
+                if weight < 0 {
+                    k -= weight;
+                    (-weight, !literal)
+                } else {
+                    (weight, literal)
+                }
+            })
+            .collect();
+
+        PseudoBooleanLessOrEqualPropagator {
+            terms,
+            k,
+            has_distinct_variables: false,
+        }
+    }
+
+    /// Returns the minimum value of the left-hand side, i.e. the sum of the weights of the
+    /// literals which are assigned to true.
+    fn minimum_sum(&self, context: PropagationContext) -> i64 {
+        self.terms
+            .iter()
+            .filter(|(_, literal)| context.is_literal_true(*literal))
+            .map(|(weight, _)| weight)
+            .sum()
+    }
+
+    /// The literals which are assigned to true; these explain both conflicts and propagations.
+    fn true_literals(&self, context: PropagationContext) -> PropositionalConjunction {
+        self.terms
+            .iter()
+            .filter(|(_, literal)| context.is_literal_true(*literal))
+            .map(|(_, literal)| (*literal).into())
+            .collect()
+    }
+}
+
+impl Propagator for PseudoBooleanLessOrEqualPropagator {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.terms
+            .iter()
+            .enumerate()
+            .for_each(|(index, (_, literal))| {
+                let _ = context.register_literal(
+                    *literal,
+                    DomainEvents::ASSIGNED_TRUE,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        let mut variables: HashSet<_> = HashSet::default();
+        self.has_distinct_variables = self
+            .terms
+            .iter()
+            .all(|(_, literal)| variables.insert(literal.get_propositional_variable()));
+
+        if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
+            Err(conjunction)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        if self.minimum_sum(context) > self.k {
+            Some(self.true_literals(context))
+        } else {
+            None
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "PseudoBooleanLeq"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        // Only literals are set to false, which does not affect the minimum sum on which the
+        // propagation is based, unless a propositional variable occurs in several terms
+        self.has_distinct_variables
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let minimum_sum = self.minimum_sum(context.as_readonly());
+        if minimum_sum > self.k {
+            return Err(self.true_literals(context.as_readonly()).into());
+        }
+
+        // Any unassigned literal whose weight exceeds the slack cannot be set to true
+        let slack = self.k - minimum_sum;
+        let mut reason = None;
+        for &(weight, literal) in self.terms.iter() {
+            if weight > slack && !context.is_literal_fixed(literal) {
+                let reason = reason
+                    .get_or_insert_with(|| self.true_literals(context.as_readonly()))
+                    .clone();
+                context.assign_literal(literal, false, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn fixing_literals_to_true_forces_remaining_literals_false() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+        let d = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                [(2, a), (2, b), (3, c), (1, d)],
+                5,
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(a, true);
+        solver.set_literal(b, true);
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        assert!(solver.is_literal_false(c));
+        assert!(!solver.is_literal_false(d));
+
+        let reason = solver.get_reason_bool(c, false);
+        assert_eq!(
+            PropositionalConjunction::from_iter([a.into(), b.into()]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn negative_weights_are_normalised() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        // 2a - 3b <= -1 holds only when b is true
+        let mut propagator = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                [(2, a), (-3, b)],
+                -1,
+            ))
+            .expect("no empty domains");
+
+        solver.propagate(&mut propagator).expect("no conflict");
+        assert!(solver.is_literal_false(!b));
+    }
+
+    #[test]
+    fn too_many_true_literals_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                [(1, a), (1, b), (1, c)],
+                1,
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(a, true);
+        solver.set_literal(b, true);
+        let result = solver.propagate(&mut propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(
+                PropositionalConjunction::from_iter([a.into(), b.into()]),
+                conjunction
+            );
+        }
+    }
+
+    #[test]
+    fn terms_over_the_same_variable_are_not_idempotent() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                [(2, b), (3, a), (1, !a)],
+                2,
+            ))
+            .expect("no empty domains");
+        assert!(!propagator.is_idempotent());
+
+        // Setting `a` to false sets `!a` to true, which reduces the slack for `b`
+        assert!(solver.is_literal_false(a));
+        assert!(!solver.is_literal_false(b));
+
+        solver.propagate(&mut propagator).expect("no conflict");
+        assert!(solver.is_literal_false(b));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for `square = base * base`, where `base` and `square` are integer variables.
+///
+/// The propagator is bounds consistent wrt base. That means that if `base \in {-2, -1, 1, 2}`, the
+/// propagator will not propagate `[square >= 1]`.
+#[derive(Clone, Debug)]
+pub(crate) struct SquarePropagator<VA, VB> {
+    base: VA,
+    square: VB,
+}
+
+impl<VA, VB> SquarePropagator<VA, VB> {
+    pub(crate) fn new(base: VA, square: VB) -> Self {
+        SquarePropagator { base, square }
+    }
+}
+
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Propagator
+    for SquarePropagator<VA, VB>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), crate::predicates::PropositionalConjunction> {
+        let _ = context.register(self.base.clone(), DomainEvents::BOUNDS, LocalId::from(0));
+        let _ = context.register(self.square.clone(), DomainEvents::BOUNDS, LocalId::from(1));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "IntSquare"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // A square is never negative
+        context.set_lower_bound(&self.square, 0, conjunction!())?;
+
+        // Similar to the absolute value, the bounds of `square` depend on whether `base` is
+        // sign-fixed; if it is not, then the lower bound of `square` cannot be tightened without
+        // looking into specific domain values of `base`.
+        let base_lb = context.lower_bound(&self.base);
+        let base_ub = context.upper_bound(&self.base);
+
+        let square_ub = i64::max(squared(base_lb), squared(base_ub));
+        context.set_upper_bound(
+            &self.square,
+            clamp_to_i32(square_ub),
+            conjunction!([self.base >= base_lb] & [self.base <= base_ub]),
+        )?;
+
+        if base_lb > 0 {
+            context.set_lower_bound(
+                &self.square,
+                clamp_to_i32(squared(base_lb)),
+                conjunction!([self.base >= base_lb]),
+            )?;
+        } else if base_ub < 0 {
+            context.set_lower_bound(
+                &self.square,
+                clamp_to_i32(squared(base_ub)),
+                conjunction!([self.base <= base_ub]),
+            )?;
+        }
+
+        // The magnitude of `base` is at most the (rounded down) square root of the upper bound of
+        // `square`.
+        let square_ub = context.upper_bound(&self.square);
+        let magnitude_ub = (square_ub as i64).isqrt() as i32;
+        context.set_lower_bound(
+            &self.base,
+            -magnitude_ub,
+            conjunction!([self.square <= square_ub]),
+        )?;
+        context.set_upper_bound(
+            &self.base,
+            magnitude_ub,
+            conjunction!([self.square <= square_ub]),
+        )?;
+
+        // The magnitude of `base` is at least the (rounded up) square root of the lower bound of
+        // `square`; if `base` cannot reach this magnitude on one side of zero, then it has to be on
+        // the other side.
+        let square_lb = context.lower_bound(&self.square);
+        let magnitude_lb = ceil_sqrt(square_lb as i64) as i32;
+        if magnitude_lb > 0 {
+            let base_lb = context.lower_bound(&self.base);
+            let base_ub = context.upper_bound(&self.base);
+
+            if base_lb > -magnitude_lb {
+                context.set_lower_bound(
+                    &self.base,
+                    magnitude_lb,
+                    conjunction!([self.square >= square_lb] & [self.base >= -magnitude_lb + 1]),
+                )?;
+            }
+            if base_ub < magnitude_lb {
+                context.set_upper_bound(
+                    &self.base,
+                    -magnitude_lb,
+                    conjunction!([self.square >= square_lb] & [self.base <= magnitude_lb - 1]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+fn squared(value: i32) -> i64 {
+    value as i64 * value as i64
+}
+
+fn clamp_to_i32(value: i64) -> i32 {
+    value.min(i32::MAX as i64) as i32
+}
+
+/// Returns the smallest non-negative integer `r` such that `r * r >= value`.
+fn ceil_sqrt(value: i64) -> i64 {
+    if value <= 0 {
+        return 0;
+    }
+
+    let root = value.isqrt();
+    if root * root == value {
+        root
+    } else {
+        root + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn positive_base_bounds_are_propagated_to_square() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(2, 4);
+        let square = solver.new_variable(-5, 100);
+
+        let _ = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+
+        solver.assert_bounds(square, 4, 16);
+    }
+
+    #[test]
+    fn negative_base_bounds_are_propagated_to_square() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(-5, -3);
+        let square = solver.new_variable(0, 100);
+
+        let _ = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+
+        solver.assert_bounds(square, 9, 25);
+        let reason = solver.get_reason_int(predicate![square >= 9].try_into().unwrap());
+        assert_eq!(conjunction!([base <= -3]), *reason);
+    }
+
+    #[test]
+    fn base_straddling_zero_does_not_tighten_lower_bound_of_square() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(-2, 5);
+        let square = solver.new_variable(-10, 100);
+
+        let _ = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+
+        solver.assert_bounds(square, 0, 25);
+        let reason = solver.get_reason_int(predicate![square <= 25].try_into().unwrap());
+        assert_eq!(conjunction!([base >= -2] & [base <= 5]), *reason);
+    }
+
+    #[test]
+    fn upper_bound_of_square_bounds_the_magnitude_of_base() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(-10, 10);
+        let square = solver.new_variable(0, 100);
+
+        let mut propagator = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, square, 20);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(base, -4, 4);
+        let reason = solver.get_reason_int(predicate![base <= 4].try_into().unwrap());
+        assert_eq!(conjunction!([square <= 20]), *reason);
+    }
+
+    #[test]
+    fn lower_bound_of_square_excludes_small_magnitudes_of_sign_fixed_base() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(-1, 10);
+        let square = solver.new_variable(10, 100);
+
+        let mut propagator = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+        solver
+            .propagate_until_fixed_point(&mut propagator)
+            .expect("no empty domains");
+
+        // base cannot be at most -4, so it has to be at least 4
+        solver.assert_bounds(base, 4, 10);
+        solver.assert_bounds(square, 16, 100);
+        let reason = solver.get_reason_int(predicate![base >= 4].try_into().unwrap());
+        assert_eq!(conjunction!([square >= 10] & [base >= -3]), *reason);
+    }
+
+    #[test]
+    fn lower_bound_of_square_excludes_small_magnitudes_of_negative_base() {
+        let mut solver = TestSolver::default();
+
+        let base = solver.new_variable(-10, 2);
+        let square = solver.new_variable(9, 100);
+
+        let _ = solver
+            .new_propagator(SquarePropagator::new(base, square))
+            .expect("no empty domains");
+
+        solver.assert_bounds(base, -10, -3);
+    }
+
+    #[test]
+    fn ceil_sqrt_rounds_up() {
+        assert_eq!(0, ceil_sqrt(0));
+        assert_eq!(1, ceil_sqrt(1));
+        assert_eq!(2, ceil_sqrt(2));
+        assert_eq!(3, ceil_sqrt(9));
+        assert_eq!(4, ceil_sqrt(10));
+    }
+}
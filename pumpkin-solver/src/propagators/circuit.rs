@@ -0,0 +1,227 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `circuit([x_0, \ldots, x_{n-1}])`, which states that the arcs
+/// `i -> x_i` form a single Hamiltonian cycle over the nodes `0..n`.
+///
+/// The fixed successors form partial chains; the propagator maintains these chains and performs
+/// the following reasoning:
+/// - A node can be the successor of at most one node; if `x_j = v` then `v` is removed from the
+///   domains of the other successor variables.
+/// - The arc from the end of a chain back to its start would close a sub-tour; if the chain does
+///   not cover all of the nodes then this arc is removed. The explanation consists of the fixed
+///   successors which form the chain.
+/// - If the fixed successors form a cycle which does not cover all of the nodes, then the chains
+///   cannot be merged into a single cycle anymore and a conflict is reported, which is explained
+///   by the fixed successors forming the cycle.
+#[derive(Clone, Debug)]
+pub(crate) struct CircuitPropagator<Var> {
+    successors: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> CircuitPropagator<Var> {
+    pub(crate) fn new(successors: Box<[Var]>) -> Self {
+        CircuitPropagator { successors }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for CircuitPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.successors
+            .iter()
+            .enumerate()
+            .for_each(|(index, successor)| {
+                let _ = context.register(
+                    successor.clone(),
+                    DomainEvents::ASSIGN,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Circuit"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let num_nodes = self.successors.len();
+
+        // The successor of a node is another node; a single node is its own successor
+        for (node, successor) in self.successors.iter().enumerate() {
+            context.set_lower_bound(successor, 0, conjunction!())?;
+            context.set_upper_bound(successor, num_nodes as i32 - 1, conjunction!())?;
+            if num_nodes > 1 {
+                context.remove(successor, node as i32, conjunction!())?;
+            }
+        }
+
+        let fixed_successors = self
+            .successors
+            .iter()
+            .map(|successor| {
+                context
+                    .is_fixed(successor)
+                    .then(|| context.lower_bound(successor) as usize)
+            })
+            .collect::<Vec<_>>();
+
+        // Every node has at most one predecessor; if two nodes have the same fixed successor then
+        // removing it from the domain of the other results in an empty domain
+        let mut predecessors: Vec<Option<usize>> = vec![None; num_nodes];
+        for (node, fixed_successor) in fixed_successors.iter().enumerate() {
+            let Some(value) = *fixed_successor else {
+                continue;
+            };
+
+            for (other, successor) in self.successors.iter().enumerate() {
+                if other != node {
+                    context.remove(
+                        successor,
+                        value as i32,
+                        conjunction!([self.successors[node] == value as i32]),
+                    )?;
+                }
+            }
+
+            predecessors[value] = Some(node);
+        }
+
+        let mut visited = vec![false; num_nodes];
+
+        // Every node without a predecessor is the start of a chain, which should not be closed
+        // before it covers all of the nodes
+        for start in 0..num_nodes {
+            if predecessors[start].is_some() {
+                continue;
+            }
+
+            let mut chain = PropositionalConjunction::default();
+            let mut end = start;
+            let mut chain_length = 1;
+            visited[start] = true;
+
+            while let Some(next) = fixed_successors[end] {
+                chain.add(predicate![self.successors[end] == next as i32]);
+                end = next;
+                chain_length += 1;
+                visited[end] = true;
+            }
+
+            if chain_length < num_nodes {
+                context.remove(&self.successors[end], start as i32, chain)?;
+            }
+        }
+
+        // The nodes which are not part of a chain lie on a cycle of fixed successors
+        for node in 0..num_nodes {
+            if visited[node] {
+                continue;
+            }
+
+            let mut cycle = PropositionalConjunction::default();
+            let mut cycle_length = 0;
+            let mut current = node;
+
+            loop {
+                visited[current] = true;
+                let next =
+                    fixed_successors[current].expect("a node on a cycle has a fixed successor");
+                cycle.add(predicate![self.successors[current] == next as i32]);
+                cycle_length += 1;
+                current = next;
+
+                if current == node {
+                    break;
+                }
+            }
+
+            if cycle_length < num_nodes {
+                return Err(cycle.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn arc_closing_a_sub_tour_is_removed() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(1, 1);
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(0, 3);
+        let x3 = solver.new_variable(0, 3);
+
+        let _ = solver
+            .new_propagator(CircuitPropagator::new([x0, x1, x2, x3].into()))
+            .expect("no empty domains");
+
+        // The chain 0 -> 1 -> 2 should not be closed since it does not visit node 3
+        assert!(!solver.contains(x2, 0));
+        let reason = solver.get_reason_int(predicate![x2 != 0].try_into().unwrap());
+        assert_eq!(conjunction!([x0 == 1] & [x1 == 2]), *reason);
+    }
+
+    #[test]
+    fn final_arc_is_forced_when_all_others_are_fixed() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(1, 1);
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(3, 3);
+        let x3 = solver.new_variable(0, 3);
+
+        let _ = solver
+            .new_propagator(CircuitPropagator::new([x0, x1, x2, x3].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x3, 0, 0);
+    }
+
+    #[test]
+    fn cycle_not_covering_all_nodes_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(1, 1);
+        let x1 = solver.new_variable(0, 0);
+        let x2 = solver.new_variable(0, 3);
+        let x3 = solver.new_variable(0, 3);
+
+        let result = solver.new_propagator(CircuitPropagator::new([x0, x1, x2, x3].into()));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(conjunction!([x0 == 1] & [x1 == 0]), conjunction);
+        }
+    }
+}
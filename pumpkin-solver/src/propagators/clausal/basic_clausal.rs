@@ -16,7 +16,7 @@ use crate::engine::Preprocessor;
 use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub(crate) struct BasicClausalPropagator {
     pub(crate) watch_lists: KeyedVec<Literal, Vec<ClauseWatcher>>,
     pub(crate) next_position_on_trail_to_propagate: usize,
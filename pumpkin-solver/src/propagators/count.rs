@@ -0,0 +1,278 @@
+use crate::basic_types::HashSet;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `count(xs, value) = c`, which states that the number of variables
+/// in `xs` which take the value `value` is equal to `c`.
+///
+/// The propagator is based on counting the variables which are fixed to `value` and the variables
+/// which can still take `value`:
+/// - The lower bound of `c` is the number of variables fixed to `value`, and its upper bound is the
+///   number of variables which can still take `value`.
+/// - If the number of variables fixed to `value` is equal to the upper bound of `c`, then `value`
+///   is removed from the domains of the other variables.
+/// - If the number of variables which can still take `value` is equal to the lower bound of `c`,
+///   then these variables are fixed to `value`.
+#[derive(Clone, Debug)]
+pub(crate) struct CountPropagator<Var, CVar> {
+    variables: Box<[Var]>,
+    value: i32,
+    count: CVar,
+    /// Whether the variables and `count` are over pairwise distinct domains; otherwise, a change to
+    /// one of them can change the occurrences of `value` which the propagation is based on.
+    has_distinct_domains: bool,
+}
+
+impl<Var, CVar> CountPropagator<Var, CVar> {
+    pub(crate) fn new(variables: Box<[Var]>, value: i32, count: CVar) -> Self {
+        CountPropagator {
+            variables,
+            value,
+            count,
+            has_distinct_domains: false,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static, CVar: IntegerVariable + 'static> Propagator
+    for CountPropagator<Var, CVar>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+        let _ = context.register(
+            self.count.clone(),
+            DomainEvents::BOUNDS,
+            LocalId::from(self.variables.len() as u32),
+        );
+
+        // The predicates are only used to find the domains of the variables
+        let mut domains: HashSet<_> = HashSet::default();
+        self.has_distinct_domains = self
+            .variables
+            .iter()
+            .map(|variable| predicate![variable >= 0].get_domain())
+            .chain(std::iter::once(predicate![self.count >= 0].get_domain()))
+            .all(|domain| domain.is_some_and(|domain| domains.insert(domain)));
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Count"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        // At most one of the rules which modify the variables applies, and it fixes the bounds of
+        // `count` which follow from the number of fixed and possible occurrences, unless a domain
+        // occurs several times
+        self.has_distinct_domains
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let value = self.value;
+
+        let fixed_to_value = self
+            .variables
+            .iter()
+            .filter(|variable| {
+                context.is_fixed(*variable) && context.lower_bound(*variable) == value
+            })
+            .collect::<Vec<_>>();
+        let value_removed = self
+            .variables
+            .iter()
+            .filter(|variable| !context.contains(*variable, value))
+            .collect::<Vec<_>>();
+        let num_fixed = fixed_to_value.len() as i32;
+        let num_possible = (self.variables.len() - value_removed.len()) as i32;
+
+        let occurrences_explanation = || {
+            fixed_to_value
+                .iter()
+                .map(|variable| predicate![variable == value])
+                .collect::<PropositionalConjunction>()
+        };
+        let removals_explanation = || {
+            value_removed
+                .iter()
+                .map(|variable| predicate![variable != value])
+                .collect::<PropositionalConjunction>()
+        };
+
+        if context.lower_bound(&self.count) < num_fixed {
+            context.set_lower_bound(&self.count, num_fixed, occurrences_explanation())?;
+        }
+        if context.upper_bound(&self.count) > num_possible {
+            context.set_upper_bound(&self.count, num_possible, removals_explanation())?;
+        }
+
+        let count_max = context.upper_bound(&self.count);
+        if num_fixed == count_max && num_possible > num_fixed {
+            // No other variable can take the value
+            let mut reason = occurrences_explanation();
+            reason.add(predicate![self.count <= count_max]);
+            for variable in self.variables.iter() {
+                if context.contains(variable, value) && !context.is_fixed(variable) {
+                    context.remove(variable, value, reason.clone())?;
+                }
+            }
+        }
+
+        let count_min = context.lower_bound(&self.count);
+        if num_possible == count_min && num_possible > num_fixed {
+            // Every variable which can take the value has to take it
+            let mut reason = removals_explanation();
+            reason.add(predicate![self.count >= count_min]);
+            for variable in self.variables.iter() {
+                if context.contains(variable, value) && !context.is_fixed(variable) {
+                    context.set_bounds(variable, value, value, reason.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn fixed_occurrences_raise_lower_bound_of_count() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(2, 2);
+        let z = solver.new_variable(1, 3);
+        let count = solver.new_variable(0, 3);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([x, y, z].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 2, 3);
+
+        let reason = solver.get_reason_int(predicate![count >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([x == 2] & [y == 2]), *reason);
+    }
+
+    #[test]
+    fn removed_values_lower_upper_bound_of_count() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 5);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+        let count = solver.new_variable(0, 3);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([x, y, z].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 0, 2);
+
+        let reason = solver.get_reason_int(predicate![count <= 2].try_into().unwrap());
+        assert_eq!(conjunction!([x != 2]), *reason);
+    }
+
+    #[test]
+    fn possible_occurrences_are_forced_when_count_is_saturated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 5);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(2, 3);
+        let count = solver.new_variable(2, 3);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([x, y, z].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 2, 2);
+        solver.assert_bounds(z, 2, 2);
+        solver.assert_bounds(count, 2, 2);
+
+        let reason = solver.get_reason_int(predicate![y >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([x != 2] & [count >= 2]), *reason);
+    }
+
+    #[test]
+    fn value_is_removed_when_fixed_occurrences_reach_count() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(2, 3);
+        let count = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([x, y, z].into(), 2, count))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(y, 2));
+        solver.assert_bounds(z, 3, 3);
+
+        let reason = solver.get_reason_int(predicate![y != 2].try_into().unwrap());
+        assert_eq!(conjunction!([x == 2] & [count <= 1]), *reason);
+    }
+
+    #[test]
+    fn too_many_occurrences_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(2, 2);
+        let count = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(CountPropagator::new([x, y].into(), 2, count));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn variables_over_the_same_domain_are_not_idempotent() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let count = solver.new_variable(0, 2);
+
+        let propagator = solver
+            .new_propagator(CountPropagator::new([x, y].into(), 2, count))
+            .expect("no empty domains");
+        assert!(propagator.is_idempotent());
+
+        let propagator = solver
+            .new_propagator(CountPropagator::new([x, count].into(), 2, count))
+            .expect("no empty domains");
+        assert!(!propagator.is_idempotent());
+    }
+}
@@ -73,14 +73,18 @@ mod explanations;
 mod over_interval_incremental_propagator;
 mod per_point_incremental_propagator;
 mod propagation_handler;
+mod time_table_optional_tasks;
 mod time_table_over_interval;
 mod time_table_per_point;
 mod time_table_util;
+mod time_table_variable_tasks;
 pub use explanations::CumulativeExplanationType;
 pub(crate) use over_interval_incremental_propagator::*;
 pub(crate) use per_point_incremental_propagator::*;
+pub(crate) use time_table_optional_tasks::*;
 pub(crate) use time_table_over_interval::*;
 pub(crate) use time_table_per_point::*;
+pub(crate) use time_table_variable_tasks::*;
 
 #[cfg(doc)]
 use crate::propagators::cumulative::time_table::time_table_util::*;
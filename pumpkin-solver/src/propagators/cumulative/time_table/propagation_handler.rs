@@ -18,6 +18,7 @@ use crate::engine::cp::propagation::propagation_context::ReadDomains;
 use crate::engine::propagation::propagation_context::HasAssignments;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
+use crate::engine::reason::CloneableLazyReason;
 use crate::engine::EmptyDomain;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::explanations::pointwise;
@@ -213,11 +214,11 @@ impl CumulativePropagationHandler {
                 context.set_lower_bound(
                     &propagating_task.start_variable,
                     profile.end + 1,
-                    move |_context: PropagationContext| {
+                    CloneableLazyReason(move |_context: PropagationContext| {
                         let mut reason = (*explanation).clone();
                         reason.add(lower_bound_predicate_propagating_task);
                         reason
-                    },
+                    }),
                 )
             }
             CumulativeExplanationType::Pointwise => {
@@ -264,11 +265,11 @@ impl CumulativePropagationHandler {
                 context.set_upper_bound(
                     &propagating_task.start_variable,
                     profile.start - propagating_task.processing_time,
-                    move |_context: PropagationContext| {
+                    CloneableLazyReason(move |_context: PropagationContext| {
                         let mut reason = (*explanation).clone();
                         reason.add(upper_bound_predicate_propagating_task);
                         reason
-                    },
+                    }),
                 )
             }
             CumulativeExplanationType::Pointwise => {
@@ -332,7 +333,9 @@ impl CumulativePropagationHandler {
                     context.remove(
                         &propagating_task.start_variable,
                         time_point,
-                        move |_context: PropagationContext| (*explanation).clone(),
+                        CloneableLazyReason(move |_context: PropagationContext| {
+                            (*explanation).clone()
+                        }),
                     )?;
                 }
                 CumulativeExplanationType::Pointwise => {
@@ -0,0 +1,434 @@
+//! [`Propagator`] for the Cumulative constraint over optional tasks; a task is only present (and
+//! thus only consumes the resource) if its presence literal is true. See
+//! [`TimeTableOptionalTasksPropagator`] for more information.
+
+use std::collections::BTreeMap;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::propagators::ArgTask;
+
+/// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
+/// constraint where every task is optional; the task at index `i` is present if and only if the
+/// literal `presence_literals[i]` is true.
+///
+/// The time-table is built per time-point (similar to
+/// [`TimeTablePerPointPropagator`][crate::propagators::TimeTablePerPointPropagator]) from the
+/// mandatory parts of the tasks which are known to be present; tasks which are absent or of
+/// which it is not yet known whether they are present do not contribute to the time-table.
+///
+/// Based on this time-table, the propagator performs the following reasoning:
+/// - The bounds of the start times of present tasks are updated such that they do not overflow
+///   the resource capacity.
+/// - If a task of which it is not yet known whether it is present cannot be scheduled at any of
+///   its start times without overflowing the resource capacity, then its presence literal is
+///   propagated to false.
+///
+/// The propagator recalculates the time-table from scratch upon every call.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeTableOptionalTasksPropagator<Var> {
+    tasks: Box<[ArgTask<Var>]>,
+    presence_literals: Box<[Literal]>,
+    capacity: i32,
+}
+
+/// The cumulative resource usage at a single time-point together with the indices of the tasks
+/// which (mandatorily) execute at that time-point.
+#[derive(Debug, Default)]
+struct ProfilePoint {
+    height: i32,
+    profile_tasks: Vec<usize>,
+}
+
+impl<Var: IntegerVariable + 'static> TimeTableOptionalTasksPropagator<Var> {
+    pub(crate) fn new(
+        tasks: Box<[ArgTask<Var>]>,
+        presence_literals: Box<[Literal]>,
+        capacity: i32,
+    ) -> Self {
+        TimeTableOptionalTasksPropagator {
+            tasks,
+            presence_literals,
+            capacity,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TimeTableOptionalTasksPropagator<Var> {
+    fn name(&self) -> &str {
+        "CumulativeTimeTableOptionalTasks"
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let num_tasks = self.tasks.len();
+        for (index, (task, &presence)) in self
+            .tasks
+            .iter()
+            .zip(self.presence_literals.iter())
+            .enumerate()
+        {
+            let _ = context.register(
+                task.start_time.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+            let _ = context.register_literal(
+                presence,
+                DomainEvents::ANY_BOOL,
+                LocalId::from((num_tasks + index) as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_from_scratch(&mut context)
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_from_scratch(&mut context)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl<Var: IntegerVariable + 'static> TimeTableOptionalTasksPropagator<Var> {
+    fn propagate_from_scratch(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let time_table = self.create_time_table(context.as_readonly())?;
+
+        for task_index in 0..self.tasks.len() {
+            let presence = self.presence_literals[task_index];
+            if context.is_literal_false(presence) {
+                continue;
+            }
+
+            let task = &self.tasks[task_index];
+            let lower_bound = context.lower_bound(&task.start_time);
+            let upper_bound = context.upper_bound(&task.start_time);
+
+            let (earliest_feasible_start, mut reason) =
+                self.find_earliest_feasible_start(context.as_readonly(), &time_table, task_index);
+
+            if context.is_literal_true(presence) {
+                if earliest_feasible_start > lower_bound {
+                    reason.add(predicate![task.start_time >= lower_bound]);
+                    reason.add(presence.into());
+                    context.set_lower_bound(&task.start_time, earliest_feasible_start, reason)?;
+                }
+
+                let upper_bound = context.upper_bound(&task.start_time);
+                let (latest_feasible_start, mut reason) =
+                    self.find_latest_feasible_start(context.as_readonly(), &time_table, task_index);
+                if latest_feasible_start < upper_bound {
+                    reason.add(predicate![task.start_time <= upper_bound]);
+                    reason.add(presence.into());
+                    context.set_upper_bound(&task.start_time, latest_feasible_start, reason)?;
+                }
+            } else if earliest_feasible_start > upper_bound {
+                // The task cannot be scheduled at any of its start times, it cannot be present
+                reason.add(predicate![task.start_time >= lower_bound]);
+                reason.add(predicate![task.start_time <= upper_bound]);
+                context.assign_literal(presence, false, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the time-table from the mandatory parts of the tasks which are present; if the
+    /// resource capacity is exceeded at any time-point then the tasks which are responsible for
+    /// the overflow are returned as a conflict.
+    fn create_time_table(
+        &self,
+        context: PropagationContext,
+    ) -> Result<BTreeMap<i32, ProfilePoint>, PropositionalConjunction> {
+        let mut time_table: BTreeMap<i32, ProfilePoint> = BTreeMap::new();
+
+        for (task_index, task) in self.tasks.iter().enumerate() {
+            if !context.is_literal_true(self.presence_literals[task_index]) {
+                continue;
+            }
+
+            let lower_bound = context.lower_bound(&task.start_time);
+            let upper_bound = context.upper_bound(&task.start_time);
+
+            for time_point in upper_bound..lower_bound + task.processing_time {
+                let profile_point = time_table.entry(time_point).or_default();
+                profile_point.height += task.resource_usage;
+                profile_point.profile_tasks.push(task_index);
+
+                if profile_point.height > self.capacity {
+                    return Err(self.explain_time_point(profile_point, time_point, None));
+                }
+            }
+        }
+
+        Ok(time_table)
+    }
+
+    /// Returns whether the task at `task_index` would overflow the resource capacity if it
+    /// executes at `time_point`.
+    fn overflows_at(&self, profile_point: &ProfilePoint, task_index: usize) -> bool {
+        let own_usage = if profile_point.profile_tasks.contains(&task_index) {
+            self.tasks[task_index].resource_usage
+        } else {
+            0
+        };
+
+        profile_point.height - own_usage + self.tasks[task_index].resource_usage > self.capacity
+    }
+
+    /// Returns the earliest start time (which is at least the current lower-bound) at which the
+    /// task at `task_index` does not overflow the resource capacity together with the
+    /// explanation for why all of the start times before it are infeasible.
+    ///
+    /// If no such start time exists, a value larger than the upper-bound is returned.
+    fn find_earliest_feasible_start(
+        &self,
+        context: PropagationContext,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> (i32, PropositionalConjunction) {
+        let task = &self.tasks[task_index];
+        let upper_bound = context.upper_bound(&task.start_time);
+
+        let mut reason = PropositionalConjunction::default();
+        let mut start = context.lower_bound(&task.start_time);
+
+        while start <= upper_bound {
+            let overflowing_point = time_table
+                .range(start..start + task.processing_time)
+                .find(|(_, profile_point)| self.overflows_at(profile_point, task_index));
+
+            match overflowing_point {
+                Some((&time_point, profile_point)) => {
+                    // All start times in [start, time_point] overlap with the overflowing point
+                    self.explain_time_point(profile_point, time_point, Some(task_index))
+                        .into_iter()
+                        .for_each(|predicate| reason.add(predicate));
+                    start = time_point + 1;
+                }
+                None => break,
+            }
+        }
+
+        (start, reason)
+    }
+
+    /// Returns the latest start time (which is at most the current upper-bound) at which the
+    /// task at `task_index` does not overflow the resource capacity together with the
+    /// explanation for why all of the start times after it are infeasible.
+    ///
+    /// If no such start time exists, a value smaller than the lower-bound is returned.
+    fn find_latest_feasible_start(
+        &self,
+        context: PropagationContext,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> (i32, PropositionalConjunction) {
+        let task = &self.tasks[task_index];
+        let lower_bound = context.lower_bound(&task.start_time);
+
+        let mut reason = PropositionalConjunction::default();
+        let mut start = context.upper_bound(&task.start_time);
+
+        while start >= lower_bound {
+            let overflowing_point = time_table
+                .range(start..start + task.processing_time)
+                .rev()
+                .find(|(_, profile_point)| self.overflows_at(profile_point, task_index));
+
+            match overflowing_point {
+                Some((&time_point, profile_point)) => {
+                    // All start times in [time_point - p + 1, start] overlap with the overflowing
+                    // point
+                    self.explain_time_point(profile_point, time_point, Some(task_index))
+                        .into_iter()
+                        .for_each(|predicate| reason.add(predicate));
+                    start = time_point - task.processing_time;
+                }
+                None => break,
+            }
+        }
+
+        (start, reason)
+    }
+
+    /// Creates the explanation for the resource usage at `time_point` using a pointwise
+    /// explanation; the task at `excluded_task` (if any) is not included in the explanation.
+    fn explain_time_point(
+        &self,
+        profile_point: &ProfilePoint,
+        time_point: i32,
+        excluded_task: Option<usize>,
+    ) -> PropositionalConjunction {
+        profile_point
+            .profile_tasks
+            .iter()
+            .filter(|&&task_index| Some(task_index) != excluded_task)
+            .flat_map(|&task_index| {
+                let task = &self.tasks[task_index];
+                [
+                    predicate![task.start_time >= time_point + 1 - task.processing_time],
+                    predicate![task.start_time <= time_point],
+                    Predicate::from(self.presence_literals[task_index]),
+                ]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::variables::DomainId;
+
+    fn create_propagator(
+        solver: &mut TestSolver,
+        tasks: Vec<(i32, i32, i32, i32, Literal)>,
+        capacity: i32,
+    ) -> TimeTableOptionalTasksPropagator<DomainId> {
+        let (tasks, presence_literals): (Vec<_>, Vec<_>) = tasks
+            .into_iter()
+            .map(
+                |(lower_bound, upper_bound, processing_time, resource_usage, presence)| {
+                    (
+                        ArgTask {
+                            start_time: solver.new_variable(lower_bound, upper_bound),
+                            processing_time,
+                            resource_usage,
+                        },
+                        presence,
+                    )
+                },
+            )
+            .unzip();
+
+        TimeTableOptionalTasksPropagator::new(tasks.into(), presence_literals.into(), capacity)
+    }
+
+    #[test]
+    fn absent_tasks_do_not_consume_resource() {
+        let mut solver = TestSolver::default();
+        let present = solver.new_literal();
+        let absent = solver.new_literal();
+        solver.set_literal(present, true);
+        solver.set_literal(absent, false);
+
+        let propagator = create_propagator(
+            &mut solver,
+            vec![(0, 0, 3, 1, absent), (0, 5, 2, 1, present)],
+            1,
+        );
+        let start = propagator.tasks[1].start_time;
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 0, 5);
+    }
+
+    #[test]
+    fn present_task_bounds_are_propagated() {
+        let mut solver = TestSolver::default();
+        let present_0 = solver.new_literal();
+        let present_1 = solver.new_literal();
+        solver.set_literal(present_0, true);
+        solver.set_literal(present_1, true);
+
+        let propagator = create_propagator(
+            &mut solver,
+            vec![(1, 1, 4, 1, present_0), (0, 6, 2, 1, present_1)],
+            1,
+        );
+        let start = propagator.tasks[1].start_time;
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 5, 6);
+
+        let reason = solver.get_reason_int(predicate![start >= 5].try_into().unwrap());
+        assert!(reason.contains(&present_0.into()));
+        assert!(reason.contains(&present_1.into()));
+    }
+
+    #[test]
+    fn presence_is_propagated_to_false_if_task_cannot_fit() {
+        let mut solver = TestSolver::default();
+        let present = solver.new_literal();
+        let optional = solver.new_literal();
+        solver.set_literal(present, true);
+
+        let propagator = create_propagator(
+            &mut solver,
+            vec![(0, 0, 5, 2, present), (1, 3, 2, 1, optional)],
+            2,
+        );
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        assert!(solver.is_literal_false(optional));
+
+        let reason = solver.get_reason_bool(optional, false);
+        assert!(reason.contains(&present.into()));
+        assert!(!reason.contains(&optional.into()));
+    }
+
+    #[test]
+    fn unknown_presence_is_not_propagated_if_task_can_fit() {
+        let mut solver = TestSolver::default();
+        let present = solver.new_literal();
+        let optional = solver.new_literal();
+        solver.set_literal(present, true);
+
+        let propagator = create_propagator(
+            &mut solver,
+            vec![(0, 0, 5, 2, present), (1, 5, 2, 1, optional)],
+            2,
+        );
+        let start = propagator.tasks[1].start_time;
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        assert!(!solver.is_literal_false(optional));
+        // Optional tasks are not filtered as long as their presence is unknown
+        solver.assert_bounds(start, 1, 5);
+    }
+
+    #[test]
+    fn overflow_of_present_tasks_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let present_0 = solver.new_literal();
+        let present_1 = solver.new_literal();
+        solver.set_literal(present_0, true);
+        solver.set_literal(present_1, true);
+
+        let propagator = create_propagator(
+            &mut solver,
+            vec![(0, 0, 3, 1, present_0), (1, 1, 3, 1, present_1)],
+            1,
+        );
+        let result = solver.new_propagator(propagator);
+        assert!(result.is_err());
+    }
+}
@@ -42,7 +42,7 @@ use crate::pumpkin_assert_extreme;
 ///
 /// \[1\] A. Schutt, Improving scheduling by learning. University of Melbourne, Department of
 /// Computer Science and Software Engineering, 2011.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[allow(unused)]
 pub(crate) struct TimeTablePerPointPropagator<Var> {
     /// Stores whether the time-table is empty
@@ -708,6 +708,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn propagator_propagates_from_mandatory_part_reason() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(0, 1);
+        let s2 = solver.new_variable(1, 8);
+
+        let _ = solver
+            .new_propagator(TimeTablePerPointPropagator::new(
+                &[
+                    ArgTask {
+                        start_time: s1,
+                        processing_time: 4,
+                        resource_usage: 1,
+                    },
+                    ArgTask {
+                        start_time: s2,
+                        processing_time: 3,
+                        resource_usage: 1,
+                    },
+                ]
+                .into_iter()
+                .collect::<Vec<_>>(),
+                1,
+                CumulativePropagatorOptions {
+                    explanation_type: CumulativeExplanationType::Naive,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        // The mandatory part of the first task is [1, 4)
+        assert_eq!(solver.lower_bound(s2), 4);
+        assert_eq!(solver.upper_bound(s2), 8);
+        assert_eq!(solver.lower_bound(s1), 0);
+        assert_eq!(solver.upper_bound(s1), 1);
+
+        let reason = solver
+            .get_reason_int(predicate!(s2 >= 4).try_into().unwrap())
+            .clone();
+        assert!(reason
+            .iter()
+            .any(|predicate| *predicate == predicate!(s1 >= 0)));
+        assert!(reason
+            .iter()
+            .any(|predicate| *predicate == predicate!(s1 <= 1)));
+    }
+
     #[test]
     fn propagator_propagates_generic_bounds() {
         let mut solver = TestSolver::default();
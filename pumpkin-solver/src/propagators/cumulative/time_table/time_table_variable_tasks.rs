@@ -0,0 +1,576 @@
+//! [`Propagator`] for the Cumulative constraint where the durations and resource usages of the
+//! tasks are variables. See [`TimeTableVariableTasksPropagator`] for more information.
+
+use std::collections::BTreeMap;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
+/// constraint where the duration and the resource usage of every task are variables.
+///
+/// The time-table is built per time-point (similar to
+/// [`TimeTablePerPointPropagator`][crate::propagators::TimeTablePerPointPropagator]) from the
+/// mandatory parts of the tasks; the mandatory part of a task is computed using the lower-bound
+/// of its duration, and it contributes the lower-bound of its resource usage to the time-table.
+///
+/// Based on this time-table, the propagator performs the following reasoning:
+/// - The bounds of the start times are updated such that the tasks (with their minimum duration
+///   and resource usage) do not overflow the resource capacity.
+/// - The resource usage of a task is bounded by the capacity which is left during its mandatory
+///   part.
+/// - The duration of a task is bounded such that it does not reach the first time-point after
+///   its latest start time at which it would overflow the resource capacity.
+///
+/// Every explanation contains the bounds of the durations and resource usages which were used to
+/// derive it. The propagator recalculates the time-table from scratch upon every call.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeTableVariableTasksPropagator<Var, Duration, Usage> {
+    start_times: Box<[Var]>,
+    durations: Box<[Duration]>,
+    resource_usages: Box<[Usage]>,
+    capacity: i32,
+}
+
+/// The cumulative resource usage at a single time-point together with the indices of the tasks
+/// which (mandatorily) execute at that time-point.
+#[derive(Debug, Default)]
+struct ProfilePoint {
+    height: i32,
+    profile_tasks: Vec<usize>,
+}
+
+impl<Var, Duration, Usage> TimeTableVariableTasksPropagator<Var, Duration, Usage>
+where
+    Var: IntegerVariable + 'static,
+    Duration: IntegerVariable + 'static,
+    Usage: IntegerVariable + 'static,
+{
+    pub(crate) fn new(
+        start_times: Box<[Var]>,
+        durations: Box<[Duration]>,
+        resource_usages: Box<[Usage]>,
+        capacity: i32,
+    ) -> Self {
+        TimeTableVariableTasksPropagator {
+            start_times,
+            durations,
+            resource_usages,
+            capacity,
+        }
+    }
+}
+
+impl<Var, Duration, Usage> Propagator for TimeTableVariableTasksPropagator<Var, Duration, Usage>
+where
+    Var: IntegerVariable + 'static,
+    Duration: IntegerVariable + 'static,
+    Usage: IntegerVariable + 'static,
+{
+    fn name(&self) -> &str {
+        "CumulativeTimeTableVariableTasks"
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let num_tasks = self.start_times.len() as u32;
+        for index in 0..self.start_times.len() {
+            let _ = context.register(
+                self.start_times[index].clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+            let _ = context.register(
+                self.durations[index].clone(),
+                DomainEvents::LOWER_BOUND,
+                LocalId::from(num_tasks + index as u32),
+            );
+            let _ = context.register(
+                self.resource_usages[index].clone(),
+                DomainEvents::LOWER_BOUND,
+                LocalId::from(2 * num_tasks + index as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_from_scratch(&mut context)
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_from_scratch(&mut context)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl<Var, Duration, Usage> TimeTableVariableTasksPropagator<Var, Duration, Usage>
+where
+    Var: IntegerVariable + 'static,
+    Duration: IntegerVariable + 'static,
+    Usage: IntegerVariable + 'static,
+{
+    fn propagate_from_scratch(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let time_table = self.create_time_table(context.as_readonly())?;
+
+        for task_index in 0..self.start_times.len() {
+            self.propagate_start_time(context, &time_table, task_index)?;
+            self.propagate_resource_usage(context, &time_table, task_index)?;
+            self.propagate_duration(context, &time_table, task_index)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the bounds of the start time of the task at `task_index` such that it does not
+    /// overflow the resource capacity with its minimum duration and resource usage.
+    fn propagate_start_time(
+        &self,
+        context: &mut PropagationContextMut,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> PropagationStatusCP {
+        let start_time = &self.start_times[task_index];
+        let lower_bound = context.lower_bound(start_time);
+        let (earliest_feasible_start, mut reason) =
+            self.find_earliest_feasible_start(context.as_readonly(), time_table, task_index);
+        if earliest_feasible_start > lower_bound {
+            reason.add(predicate![start_time >= lower_bound]);
+            self.add_task_bounds(context.as_readonly(), task_index, &mut reason);
+            context.set_lower_bound(start_time, earliest_feasible_start, reason)?;
+        }
+
+        let upper_bound = context.upper_bound(start_time);
+        let (latest_feasible_start, mut reason) =
+            self.find_latest_feasible_start(context.as_readonly(), time_table, task_index);
+        if latest_feasible_start < upper_bound {
+            reason.add(predicate![start_time <= upper_bound]);
+            self.add_task_bounds(context.as_readonly(), task_index, &mut reason);
+            context.set_upper_bound(start_time, latest_feasible_start, reason)?;
+        }
+
+        Ok(())
+    }
+
+    /// Bounds the resource usage of the task at `task_index` by the capacity which is left by the
+    /// other tasks during its mandatory part.
+    fn propagate_resource_usage(
+        &self,
+        context: &mut PropagationContextMut,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> PropagationStatusCP {
+        let resource_usage = &self.resource_usages[task_index];
+
+        let highest_point = time_table
+            .iter()
+            .filter(|(_, profile_point)| profile_point.profile_tasks.contains(&task_index))
+            .map(|(&time_point, profile_point)| {
+                let others_height = profile_point.height - context.lower_bound(resource_usage);
+                (time_point, profile_point, others_height)
+            })
+            .max_by_key(|&(_, _, others_height)| others_height);
+
+        let Some((time_point, profile_point, others_height)) = highest_point else {
+            return Ok(());
+        };
+
+        if self.capacity - others_height < context.upper_bound(resource_usage) {
+            let mut reason = self.explain_time_point(
+                context.as_readonly(),
+                profile_point,
+                time_point,
+                Some(task_index),
+            );
+            self.add_execution_at(context.as_readonly(), task_index, time_point, &mut reason);
+            context.set_upper_bound(resource_usage, self.capacity - others_height, reason)?;
+        }
+
+        Ok(())
+    }
+
+    /// Bounds the duration of the task at `task_index` such that the task does not reach the
+    /// first time-point after its latest start time at which it would overflow the resource
+    /// capacity.
+    fn propagate_duration(
+        &self,
+        context: &mut PropagationContextMut,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> PropagationStatusCP {
+        let start_time = &self.start_times[task_index];
+        let duration = &self.durations[task_index];
+        let resource_usage = &self.resource_usages[task_index];
+
+        let lower_bound = context.lower_bound(start_time);
+        let upper_bound = context.upper_bound(start_time);
+
+        let overflowing_point = time_table.range(upper_bound..).find(|(_, profile_point)| {
+            self.overflows_at(context.as_readonly(), profile_point, task_index)
+        });
+
+        let Some((&time_point, profile_point)) = overflowing_point else {
+            return Ok(());
+        };
+
+        // If the duration is larger than `time_point - lower_bound`, then the task executes at
+        // `time_point` regardless of its start time
+        if time_point - lower_bound < context.upper_bound(duration) {
+            let mut reason = self.explain_time_point(
+                context.as_readonly(),
+                profile_point,
+                time_point,
+                Some(task_index),
+            );
+            reason.add(predicate![start_time >= lower_bound]);
+            reason.add(predicate![start_time <= time_point]);
+            reason.add(predicate![
+                resource_usage >= context.lower_bound(resource_usage)
+            ]);
+            context.set_upper_bound(duration, time_point - lower_bound, reason)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the time-table from the mandatory parts of the tasks; if the resource capacity is
+    /// exceeded at any time-point then the tasks which are responsible for the overflow are
+    /// returned as a conflict.
+    fn create_time_table(
+        &self,
+        context: PropagationContext,
+    ) -> Result<BTreeMap<i32, ProfilePoint>, PropositionalConjunction> {
+        let mut time_table: BTreeMap<i32, ProfilePoint> = BTreeMap::new();
+
+        for task_index in 0..self.start_times.len() {
+            let resource_usage = context.lower_bound(&self.resource_usages[task_index]);
+            if resource_usage <= 0 {
+                continue;
+            }
+
+            let lower_bound = context.lower_bound(&self.start_times[task_index]);
+            let upper_bound = context.upper_bound(&self.start_times[task_index]);
+            let duration = context.lower_bound(&self.durations[task_index]);
+
+            for time_point in upper_bound..lower_bound + duration {
+                let profile_point = time_table.entry(time_point).or_default();
+                profile_point.height += resource_usage;
+                profile_point.profile_tasks.push(task_index);
+
+                if profile_point.height > self.capacity {
+                    return Err(self.explain_time_point(context, profile_point, time_point, None));
+                }
+            }
+        }
+
+        Ok(time_table)
+    }
+
+    /// Returns whether the task at `task_index` would overflow the resource capacity if it
+    /// executes at the time-point of `profile_point`.
+    fn overflows_at(
+        &self,
+        context: PropagationContext,
+        profile_point: &ProfilePoint,
+        task_index: usize,
+    ) -> bool {
+        let resource_usage = context.lower_bound(&self.resource_usages[task_index]);
+        let own_usage = if profile_point.profile_tasks.contains(&task_index) {
+            resource_usage
+        } else {
+            0
+        };
+
+        resource_usage > 0 && profile_point.height - own_usage + resource_usage > self.capacity
+    }
+
+    /// Returns the earliest start time (which is at least the current lower-bound) at which the
+    /// task at `task_index` does not overflow the resource capacity together with the
+    /// explanation for why all of the start times before it are infeasible.
+    ///
+    /// If no such start time exists, a value larger than the upper-bound is returned.
+    fn find_earliest_feasible_start(
+        &self,
+        context: PropagationContext,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> (i32, PropositionalConjunction) {
+        let start_time = &self.start_times[task_index];
+        let duration = context.lower_bound(&self.durations[task_index]);
+        let upper_bound = context.upper_bound(start_time);
+
+        let mut reason = PropositionalConjunction::default();
+        let mut start = context.lower_bound(start_time);
+
+        while start <= upper_bound {
+            let overflowing_point = time_table
+                .range(start..start + duration)
+                .find(|(_, profile_point)| self.overflows_at(context, profile_point, task_index));
+
+            match overflowing_point {
+                Some((&time_point, profile_point)) => {
+                    // All start times in [start, time_point] overlap with the overflowing point
+                    self.explain_time_point(context, profile_point, time_point, Some(task_index))
+                        .into_iter()
+                        .for_each(|predicate| reason.add(predicate));
+                    start = time_point + 1;
+                }
+                None => break,
+            }
+        }
+
+        (start, reason)
+    }
+
+    /// Returns the latest start time (which is at most the current upper-bound) at which the
+    /// task at `task_index` does not overflow the resource capacity together with the
+    /// explanation for why all of the start times after it are infeasible.
+    ///
+    /// If no such start time exists, a value smaller than the lower-bound is returned.
+    fn find_latest_feasible_start(
+        &self,
+        context: PropagationContext,
+        time_table: &BTreeMap<i32, ProfilePoint>,
+        task_index: usize,
+    ) -> (i32, PropositionalConjunction) {
+        let start_time = &self.start_times[task_index];
+        let duration = context.lower_bound(&self.durations[task_index]);
+        let lower_bound = context.lower_bound(start_time);
+
+        let mut reason = PropositionalConjunction::default();
+        let mut start = context.upper_bound(start_time);
+
+        while start >= lower_bound {
+            let overflowing_point = time_table
+                .range(start..start + duration)
+                .rev()
+                .find(|(_, profile_point)| self.overflows_at(context, profile_point, task_index));
+
+            match overflowing_point {
+                Some((&time_point, profile_point)) => {
+                    // All start times in [time_point - p + 1, start] overlap with the overflowing
+                    // point
+                    self.explain_time_point(context, profile_point, time_point, Some(task_index))
+                        .into_iter()
+                        .for_each(|predicate| reason.add(predicate));
+                    start = time_point - duration;
+                }
+                None => break,
+            }
+        }
+
+        (start, reason)
+    }
+
+    /// Adds the bounds on the duration and resource usage of the task at `task_index` which are
+    /// used to compute its mandatory part and its contribution to the time-table.
+    fn add_task_bounds(
+        &self,
+        context: PropagationContext,
+        task_index: usize,
+        reason: &mut PropositionalConjunction,
+    ) {
+        let duration = &self.durations[task_index];
+        let resource_usage = &self.resource_usages[task_index];
+
+        reason.add(predicate![duration >= context.lower_bound(duration)]);
+        reason.add(predicate![
+            resource_usage >= context.lower_bound(resource_usage)
+        ]);
+    }
+
+    /// Adds the predicates which explain that the task at `task_index` executes at `time_point`
+    /// (using its minimum duration).
+    fn add_execution_at(
+        &self,
+        context: PropagationContext,
+        task_index: usize,
+        time_point: i32,
+        reason: &mut PropositionalConjunction,
+    ) {
+        let start_time = &self.start_times[task_index];
+        let duration = &self.durations[task_index];
+        let min_duration = context.lower_bound(duration);
+
+        reason.add(predicate![start_time >= time_point + 1 - min_duration]);
+        reason.add(predicate![start_time <= time_point]);
+        reason.add(predicate![duration >= min_duration]);
+    }
+
+    /// Creates the explanation for the resource usage at `time_point` using a pointwise
+    /// explanation; the task at `excluded_task` (if any) is not included in the explanation.
+    fn explain_time_point(
+        &self,
+        context: PropagationContext,
+        profile_point: &ProfilePoint,
+        time_point: i32,
+        excluded_task: Option<usize>,
+    ) -> PropositionalConjunction {
+        let mut reason = PropositionalConjunction::default();
+
+        for &task_index in profile_point.profile_tasks.iter() {
+            if Some(task_index) == excluded_task {
+                continue;
+            }
+
+            let resource_usage = &self.resource_usages[task_index];
+            self.add_execution_at(context, task_index, time_point, &mut reason);
+            reason.add(predicate![
+                resource_usage >= context.lower_bound(resource_usage)
+            ]);
+        }
+
+        reason
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::variables::DomainId;
+
+    type Propagator = TimeTableVariableTasksPropagator<DomainId, DomainId, DomainId>;
+    /// The bounds of the start time, duration and resource usage of a task.
+    type TaskBounds = ((i32, i32), (i32, i32), (i32, i32));
+
+    /// Creates a propagator over the tasks `(start_time, duration, resource_usage)`, where every
+    /// entry gives the bounds of the corresponding variable.
+    fn create_propagator(
+        solver: &mut TestSolver,
+        tasks: Vec<TaskBounds>,
+        capacity: i32,
+    ) -> Propagator {
+        let mut start_times = vec![];
+        let mut durations = vec![];
+        let mut resource_usages = vec![];
+
+        for (start_time, duration, resource_usage) in tasks {
+            start_times.push(solver.new_variable(start_time.0, start_time.1));
+            durations.push(solver.new_variable(duration.0, duration.1));
+            resource_usages.push(solver.new_variable(resource_usage.0, resource_usage.1));
+        }
+
+        TimeTableVariableTasksPropagator::new(
+            start_times.into(),
+            durations.into(),
+            resource_usages.into(),
+            capacity,
+        )
+    }
+
+    #[test]
+    fn increasing_the_minimum_duration_delays_another_task() {
+        let mut solver = TestSolver::default();
+        let propagator = create_propagator(
+            &mut solver,
+            vec![((0, 0), (1, 5), (1, 1)), ((0, 10), (2, 2), (1, 1))],
+            1,
+        );
+        let duration = propagator.durations[0];
+        let start = propagator.start_times[1];
+        let mut propagator = solver.new_propagator(propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 1, 10);
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 2, duration, 4);
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 4, 10);
+
+        let reason = solver.get_reason_int(predicate![start >= 4].try_into().unwrap());
+        assert!(reason.contains(&predicate![duration >= 4]));
+    }
+
+    #[test]
+    fn increasing_the_minimum_usage_delays_another_task() {
+        let mut solver = TestSolver::default();
+        let propagator = create_propagator(
+            &mut solver,
+            vec![((0, 0), (3, 3), (1, 2)), ((0, 10), (2, 2), (2, 2))],
+            3,
+        );
+        let usage = propagator.resource_usages[0];
+        let start = propagator.start_times[1];
+        let mut propagator = solver.new_propagator(propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 0, 10);
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 4, usage, 2);
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        solver.assert_bounds(start, 3, 10);
+
+        let reason = solver.get_reason_int(predicate![start >= 3].try_into().unwrap());
+        assert!(reason.contains(&predicate![usage >= 2]));
+    }
+
+    #[test]
+    fn resource_usage_is_bounded_by_the_remaining_capacity() {
+        let mut solver = TestSolver::default();
+        let propagator = create_propagator(
+            &mut solver,
+            vec![((0, 0), (4, 4), (2, 2)), ((1, 1), (2, 2), (1, 5))],
+            3,
+        );
+        let usage = propagator.resource_usages[1];
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        solver.assert_bounds(usage, 1, 1);
+    }
+
+    #[test]
+    fn duration_is_bounded_by_the_next_overflowing_point() {
+        let mut solver = TestSolver::default();
+        let propagator = create_propagator(
+            &mut solver,
+            vec![((5, 5), (3, 3), (1, 1)), ((0, 2), (1, 10), (1, 1))],
+            1,
+        );
+        let start = propagator.start_times[1];
+        let duration = propagator.durations[1];
+        let _ = solver.new_propagator(propagator).expect("no conflict");
+
+        // The task can not execute at time-point 5, so it has to finish before it
+        solver.assert_bounds(start, 0, 2);
+        solver.assert_bounds(duration, 1, 5);
+
+        let reason = solver.get_reason_int(predicate![duration <= 5].try_into().unwrap());
+        assert!(reason.contains(&predicate![start >= 0]));
+    }
+
+    #[test]
+    fn overlapping_mandatory_parts_lead_to_a_conflict() {
+        let mut solver = TestSolver::default();
+        let propagator = create_propagator(
+            &mut solver,
+            vec![((0, 1), (3, 5), (1, 1)), ((1, 2), (3, 5), (1, 1))],
+            1,
+        );
+
+        let result = solver.new_propagator(propagator);
+        assert!(result.is_err());
+    }
+}
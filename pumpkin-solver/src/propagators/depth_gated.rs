@@ -0,0 +1,297 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::propagation_context::HasAssignments;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::engine::BooleanDomainEvent;
+use crate::predicates::PropositionalConjunction;
+use crate::statistics::StatisticLogger;
+
+/// Wrapper around a propagator which only propagates when the current decision level is at most
+/// `maximum_decision_level`; deeper in the search tree, the wrapped propagator is skipped.
+///
+/// This allows expensive propagators to only perform their reasoning near the root of the search
+/// tree, where it is the most effective. Note that a propagator which has been skipped is only
+/// enqueued again once one of its variables changes.
+///
+/// To ensure that no solution violating the wrapped propagator is accepted, the wrapped propagator
+/// is always invoked once all of the variables which it watches are assigned, regardless of the
+/// decision level. For this purpose, the wrapper additionally registers for the assignment of each
+/// of these variables.
+///
+/// The wrapped propagator is notified of every event (regardless of the decision level) such that
+/// its incremental state stays up-to-date.
+#[derive(Clone, Debug)]
+pub(crate) struct DepthGated<WrappedPropagator> {
+    propagator: WrappedPropagator,
+    maximum_decision_level: usize,
+    /// The domains which are watched by the wrapped propagator.
+    domains: Box<[DomainId]>,
+    /// The literals which are watched by the wrapped propagator.
+    literals: Box<[Literal]>,
+    /// The [`LocalId`]s from this id onwards are used by the wrapper to be notified of the
+    /// assignment of the watched variables; the wrapped propagator is not notified of these.
+    first_assignment_id: LocalId,
+    /// The formatted name of the propagator.
+    name: String,
+}
+
+impl<WrappedPropagator: Propagator> DepthGated<WrappedPropagator> {
+    pub(crate) fn new(propagator: WrappedPropagator, maximum_decision_level: usize) -> Self {
+        let name = format!("DepthGated({})", propagator.name());
+        DepthGated {
+            propagator,
+            maximum_decision_level,
+            domains: Box::default(),
+            literals: Box::default(),
+            first_assignment_id: LocalId::from(0),
+            name,
+        }
+    }
+
+    fn is_enabled(&self, context: &impl HasAssignments) -> bool {
+        context.assignments_integer().get_decision_level() <= self.maximum_decision_level
+    }
+
+    fn is_assignment_complete(&self, context: &impl ReadDomains) -> bool {
+        self.domains.iter().all(|domain| context.is_fixed(domain))
+            && self
+                .literals
+                .iter()
+                .all(|&literal| context.is_literal_fixed(literal))
+    }
+
+    fn should_propagate(&self, context: &impl ReadDomains) -> bool {
+        self.is_enabled(context) || self.is_assignment_complete(context)
+    }
+
+    fn is_assignment_id(&self, local_id: LocalId) -> bool {
+        local_id.unpack() >= self.first_assignment_id.unpack()
+    }
+
+    fn filter_enqueue_decision(
+        &self,
+        context: PropagationContext,
+        decision: EnqueueDecision,
+    ) -> EnqueueDecision {
+        if self.should_propagate(&context) {
+            decision
+        } else {
+            EnqueueDecision::Skip
+        }
+    }
+}
+
+impl<WrappedPropagator: Propagator> Propagator for DepthGated<WrappedPropagator> {
+    fn notify(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        if self.is_assignment_id(local_id) {
+            // The wrapped propagator is enqueued by its own events while it is enabled
+            return if !self.is_enabled(&context) && self.is_assignment_complete(&context) {
+                EnqueueDecision::Enqueue
+            } else {
+                EnqueueDecision::Skip
+            };
+        }
+
+        let decision = self.propagator.notify(context, local_id, event);
+        self.filter_enqueue_decision(context, decision)
+    }
+
+    fn notify_backtrack(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) {
+        self.propagator.notify_backtrack(context, local_id, event)
+    }
+
+    fn notify_literal(
+        &mut self,
+        context: PropagationContext,
+        local_id: LocalId,
+        event: BooleanDomainEvent,
+    ) -> EnqueueDecision {
+        if self.is_assignment_id(local_id) {
+            return if !self.is_enabled(&context) && self.is_assignment_complete(&context) {
+                EnqueueDecision::Enqueue
+            } else {
+                EnqueueDecision::Skip
+            };
+        }
+
+        let decision = self.propagator.notify_literal(context, local_id, event);
+        self.filter_enqueue_decision(context, decision)
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.propagator.initialise_at_root(context)?;
+
+        self.domains = context.get_watched_domains().into();
+        self.literals = context.get_watched_literals().into();
+        self.first_assignment_id = context.get_next_local_id();
+
+        let first_assignment_id = self.first_assignment_id.unpack();
+        for (index, &domain) in self.domains.iter().enumerate() {
+            let _ = context.register(
+                domain,
+                DomainEvents::ASSIGN,
+                LocalId::from(first_assignment_id + index as u32),
+            );
+        }
+        let first_literal_id = first_assignment_id + self.domains.len() as u32;
+        for (index, &literal) in self.literals.iter().enumerate() {
+            let _ = context.register_literal(
+                literal,
+                DomainEvents::ANY_BOOL,
+                LocalId::from(first_literal_id + index as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        self.propagator.priority()
+    }
+
+    fn synchronise(&mut self, context: PropagationContext) {
+        self.propagator.synchronise(context)
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        if !self.should_propagate(&context) {
+            return Ok(());
+        }
+
+        self.propagator.propagate(context)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        if !self.should_propagate(&context) {
+            return Ok(());
+        }
+
+        self.propagator.debug_propagate_from_scratch(context)
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        self.propagator.detect_inconsistency(context)
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.propagator.log_statistics(statistic_logger)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(DepthGated {
+            propagator: self.propagator.clone_boxed()?,
+            maximum_decision_level: self.maximum_decision_level,
+            domains: self.domains.clone(),
+            literals: self.literals.clone(),
+            first_assignment_id: self.first_assignment_id,
+            name: self.name.clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+
+    #[test]
+    fn propagates_at_or_below_the_maximum_decision_level() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+
+        solver.increase_decision_level();
+
+        let _ = solver
+            .new_propagator(DepthGated::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 7),
+                1,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 0, 6);
+    }
+
+    #[test]
+    fn wrapped_propagator_is_not_invoked_beyond_the_maximum_decision_level() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(DepthGated::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 12),
+                0,
+            ))
+            .expect("no empty domains");
+        solver.assert_bounds(y, 0, 10);
+
+        solver.increase_decision_level();
+
+        let decision = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 5);
+        assert_eq!(EnqueueDecision::Skip, decision);
+
+        solver.propagate(&mut propagator).expect("no empty domains");
+        solver.assert_bounds(y, 0, 10);
+    }
+
+    #[test]
+    fn violating_complete_assignment_is_rejected_beyond_the_maximum_decision_level() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(DepthGated::new(
+                LinearLessOrEqualPropagator::new([x, y].into(), 12),
+                0,
+            ))
+            .expect("no empty domains");
+
+        solver.increase_decision_level();
+
+        let decision = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 6);
+        assert_eq!(EnqueueDecision::Skip, decision);
+        let decision = solver.increase_lower_bound_and_notify(&mut propagator, 1, y, 7);
+        assert_eq!(EnqueueDecision::Skip, decision);
+
+        // The wrapper is notified of the assignments of `x` and `y` with the local ids following
+        // those of the wrapped propagator
+        let decision = solver.decrease_upper_bound_and_notify(&mut propagator, 2, x, 6);
+        assert_eq!(EnqueueDecision::Skip, decision);
+        let decision = solver.decrease_upper_bound_and_notify(&mut propagator, 3, y, 7);
+        assert_eq!(EnqueueDecision::Enqueue, decision);
+
+        assert!(solver.propagate(&mut propagator).is_err());
+    }
+}
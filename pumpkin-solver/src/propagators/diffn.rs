@@ -0,0 +1,282 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// A rectangle with a variable origin `(x, y)` and a fixed width and height; it occupies the
+/// points in `[x, x + width) x [y, y + height)`.
+#[derive(Clone, Debug)]
+pub(crate) struct Rectangle<Var> {
+    pub(crate) x: Var,
+    pub(crate) y: Var,
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+}
+
+/// Propagator for the constraint `diffn(rectangles)`, which states that no two rectangles
+/// overlap.
+///
+/// Two rectangles `i` and `j` do not overlap if `i` is placed to the left of `j`, `j` to the left
+/// of `i`, `i` below `j`, or `j` below `i`. For every pair of rectangles, the propagator determines
+/// which of these placements are still possible given the bounds of the origins. If none of them
+/// is possible, a conflict is reported; if only one of them is possible (e.g. because the
+/// rectangles are forced to overlap in the other dimension), the bounds of the origins are
+/// tightened such that this placement holds.
+#[derive(Clone, Debug)]
+pub(crate) struct DiffnPropagator<Var> {
+    rectangles: Box<[Rectangle<Var>]>,
+}
+
+/// The placement in which the rectangle with origin coordinate `before` (and length `length` in
+/// that dimension) lies entirely before the rectangle with origin coordinate `after`, i.e.
+/// `before + length <= after`.
+struct Placement<'a, Var> {
+    before: &'a Var,
+    length: i32,
+    after: &'a Var,
+}
+
+impl<Var: IntegerVariable> Placement<'_, Var> {
+    fn is_possible(&self, context: PropagationContext) -> bool {
+        context.lower_bound(self.before) as i64 + self.length as i64
+            <= context.upper_bound(self.after) as i64
+    }
+
+    /// The explanation for why the placement is not possible; the earliest position of `before`
+    /// ends after the latest position of `after`.
+    fn impossibility_explanation(&self, context: PropagationContext) -> PropositionalConjunction {
+        let before = self.before;
+        let after = self.after;
+        PropositionalConjunction::from(vec![
+            predicate![before >= context.lower_bound(before)],
+            predicate![after <= context.upper_bound(after)],
+        ])
+    }
+}
+
+impl<Var: IntegerVariable> DiffnPropagator<Var> {
+    pub(crate) fn new(rectangles: Box<[Rectangle<Var>]>) -> Self {
+        DiffnPropagator { rectangles }
+    }
+
+    fn propagate_pair(
+        &self,
+        context: &mut PropagationContextMut,
+        first: &Rectangle<Var>,
+        second: &Rectangle<Var>,
+    ) -> PropagationStatusCP {
+        let placements = [
+            Placement {
+                before: &first.x,
+                length: first.width,
+                after: &second.x,
+            },
+            Placement {
+                before: &second.x,
+                length: second.width,
+                after: &first.x,
+            },
+            Placement {
+                before: &first.y,
+                length: first.height,
+                after: &second.y,
+            },
+            Placement {
+                before: &second.y,
+                length: second.height,
+                after: &first.y,
+            },
+        ];
+
+        let mut possible_placements = placements
+            .iter()
+            .filter(|placement| placement.is_possible(context.as_readonly()));
+        let Some(remaining_placement) = possible_placements.next() else {
+            return Err(placements
+                .iter()
+                .flat_map(|placement| placement.impossibility_explanation(context.as_readonly()))
+                .collect::<PropositionalConjunction>()
+                .into());
+        };
+        if possible_placements.next().is_some() {
+            return Ok(());
+        }
+
+        // Only one placement remains, which means that it has to hold
+        let mut reason = placements
+            .iter()
+            .filter(|placement| !placement.is_possible(context.as_readonly()))
+            .flat_map(|placement| placement.impossibility_explanation(context.as_readonly()))
+            .collect::<PropositionalConjunction>();
+
+        let before = remaining_placement.before;
+        let after = remaining_placement.after;
+        let length = remaining_placement.length;
+
+        let earliest_before = context.lower_bound(before);
+        let latest_after = context.upper_bound(after);
+
+        let mut lower_bound_reason = reason.clone();
+        lower_bound_reason.add(predicate![before >= earliest_before]);
+        context.set_lower_bound(after, earliest_before + length, lower_bound_reason)?;
+
+        reason.add(predicate![after <= latest_after]);
+        context.set_upper_bound(before, latest_after - length, reason)?;
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DiffnPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.rectangles
+            .iter()
+            .enumerate()
+            .for_each(|(index, rectangle)| {
+                let _ = context.register(
+                    rectangle.x.clone(),
+                    DomainEvents::BOUNDS,
+                    LocalId::from(2 * index as u32),
+                );
+                let _ = context.register(
+                    rectangle.y.clone(),
+                    DomainEvents::BOUNDS,
+                    LocalId::from(2 * index as u32 + 1),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "Diffn"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        for (index, first) in self.rectangles.iter().enumerate() {
+            for second in self.rectangles[index + 1..].iter() {
+                self.propagate_pair(&mut context, first, second)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn rectangles_which_overlap_vertically_are_pushed_apart_horizontally() {
+        let mut solver = TestSolver::default();
+        let x1 = solver.new_variable(0, 0);
+        let y1 = solver.new_variable(0, 0);
+        let x2 = solver.new_variable(0, 10);
+        let y2 = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(DiffnPropagator::new(
+                [
+                    Rectangle {
+                        x: x1,
+                        y: y1,
+                        width: 3,
+                        height: 2,
+                    },
+                    Rectangle {
+                        x: x2,
+                        y: y2,
+                        width: 2,
+                        height: 2,
+                    },
+                ]
+                .into(),
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x2, 3, 10);
+        solver.assert_bounds(y2, 0, 1);
+
+        let reason = solver.get_reason_int(predicate![x2 >= 3].try_into().unwrap());
+        assert_eq!(
+            conjunction!(
+                [x2 >= 0] & [x1 <= 0] & [y1 >= 0] & [y2 <= 1] & [y2 >= 0] & [y1 <= 0] & [x1 >= 0]
+            ),
+            *reason
+        );
+    }
+
+    #[test]
+    fn rectangles_which_must_overlap_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x1 = solver.new_variable(0, 1);
+        let y1 = solver.new_variable(0, 0);
+        let x2 = solver.new_variable(1, 2);
+        let y2 = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(DiffnPropagator::new(
+            [
+                Rectangle {
+                    x: x1,
+                    y: y1,
+                    width: 3,
+                    height: 2,
+                },
+                Rectangle {
+                    x: x2,
+                    y: y2,
+                    width: 2,
+                    height: 2,
+                },
+            ]
+            .into(),
+        ));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(
+                conjunction!(
+                    [x1 >= 0]
+                        & [x2 <= 2]
+                        & [x2 >= 1]
+                        & [x1 <= 1]
+                        & [y1 >= 0]
+                        & [y2 <= 1]
+                        & [y2 >= 0]
+                        & [y1 <= 0]
+                ),
+                conjunction
+            );
+        }
+    }
+}
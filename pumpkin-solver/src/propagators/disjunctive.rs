@@ -0,0 +1,232 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// A task with a variable start time and a fixed duration; it is executed in the interval
+/// `[start, start + duration)`. The duration is expected to be positive.
+#[derive(Clone, Debug)]
+pub(crate) struct DisjunctiveTask<Var> {
+    pub(crate) start: Var,
+    pub(crate) duration: i32,
+}
+
+/// Propagator for the constraint `disjunctive(tasks)`, which states that no two tasks are executed
+/// at the same time (i.e. the tasks share a resource with capacity 1).
+///
+/// The propagator implements the detectable precedences rule, which only updates the lower bounds
+/// of the start times. A task `j` is detected to precede task `i` if the earliest completion time
+/// of `i` exceeds the latest start time of `j`; then `i` cannot start before the earliest
+/// completion time of the set of tasks which are detected to precede it. The upper bounds of the
+/// start times can be updated by applying the propagator to the mirrored tasks (see
+/// [`crate::constraints::disjunctive`]).
+#[derive(Clone, Debug)]
+pub(crate) struct DisjunctivePropagator<Var> {
+    tasks: Box<[DisjunctiveTask<Var>]>,
+}
+
+impl<Var: IntegerVariable> DisjunctivePropagator<Var> {
+    pub(crate) fn new(tasks: Box<[DisjunctiveTask<Var>]>) -> Self {
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.duration > 0),
+            "the durations of the tasks should be positive"
+        );
+        DisjunctivePropagator { tasks }
+    }
+
+    fn propagate_task(
+        &self,
+        context: &mut PropagationContextMut,
+        task: &DisjunctiveTask<Var>,
+    ) -> PropagationStatusCP {
+        let earliest_start = context.lower_bound(&task.start);
+
+        // The tasks which cannot be scheduled after `task`, ordered by decreasing earliest start
+        let mut preceding = self
+            .tasks
+            .iter()
+            .filter(|other| !std::ptr::eq(*other, task))
+            .filter(|other| earliest_start + task.duration > context.upper_bound(&other.start))
+            .collect::<Vec<_>>();
+        preceding.sort_by_key(|other| std::cmp::Reverse(context.lower_bound(&other.start)));
+
+        // The earliest completion time of the preceding tasks is the largest value of
+        // `est(Omega) + p(Omega)` over the subsets `Omega` consisting of the tasks which start at
+        // or after a certain earliest start time
+        let mut total_duration = 0;
+        let mut earliest_completion = None;
+        for (index, other) in preceding.iter().enumerate() {
+            total_duration += other.duration;
+            let completion = context.lower_bound(&other.start) + total_duration;
+            if earliest_completion.is_none_or(|(_, best)| completion > best) {
+                earliest_completion = Some((index, completion));
+            }
+        }
+
+        let Some((last_index, completion)) = earliest_completion else {
+            return Ok(());
+        };
+        if completion <= earliest_start {
+            return Ok(());
+        }
+
+        let subset = &preceding[..=last_index];
+        let subset_earliest_start = context.lower_bound(&subset[last_index].start);
+        let mut reason = subset
+            .iter()
+            .flat_map(|other| {
+                [
+                    predicate![other.start >= subset_earliest_start],
+                    predicate![other.start <= context.upper_bound(&other.start)],
+                ]
+            })
+            .collect::<PropositionalConjunction>();
+        reason.add(predicate![task.start >= earliest_start]);
+
+        context.set_lower_bound(&task.start, completion, reason)?;
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DisjunctivePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.tasks.iter().enumerate().for_each(|(index, task)| {
+            let _ = context.register(
+                task.start.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+        });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Disjunctive"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        for task in self.tasks.iter() {
+            self.propagate_task(&mut context, task)?;
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::constraints;
+    use crate::engine::test_helper::TestSolver;
+    use crate::Solver;
+
+    fn tasks<Var>(tasks: impl IntoIterator<Item = (Var, i32)>) -> Box<[DisjunctiveTask<Var>]> {
+        tasks
+            .into_iter()
+            .map(|(start, duration)| DisjunctiveTask { start, duration })
+            .collect()
+    }
+
+    #[test]
+    fn unit_tasks_with_overlapping_windows_are_ordered() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(0, 0);
+        let s2 = solver.new_variable(0, 3);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(tasks([(s1, 1), (s2, 1)])))
+            .expect("no empty domains");
+
+        solver.assert_bounds(s2, 1, 3);
+
+        let reason = solver.get_reason_int(predicate![s2 >= 1].try_into().unwrap());
+        assert_eq!(conjunction!([s1 >= 0] & [s1 <= 0] & [s2 >= 0]), *reason);
+    }
+
+    #[test]
+    fn start_is_pushed_past_completion_of_preceding_set() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(0, 1);
+        let s2 = solver.new_variable(1, 2);
+        let s3 = solver.new_variable(2, 10);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(tasks([
+                (s1, 2),
+                (s2, 2),
+                (s3, 3),
+            ])))
+            .expect("no empty domains");
+
+        // The first task precedes the second one, and both precede the third one
+        solver.assert_bounds(s2, 2, 2);
+        solver.assert_bounds(s3, 4, 10);
+    }
+
+    #[test]
+    fn overlapping_fixed_tasks_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(0, 0);
+        let s2 = solver.new_variable(0, 0);
+
+        let result = solver.new_propagator(DisjunctivePropagator::new(tasks([(s1, 1), (s2, 1)])));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mirrored_tasks_update_upper_bounds() {
+        let mut solver = Solver::default();
+        let s1 = solver.new_bounded_integer(0, 5);
+        let s2 = solver.new_bounded_integer(5, 5);
+
+        solver
+            .add_constraint(constraints::disjunctive([s1, s2], [1, 1]))
+            .post()
+            .expect("no root-level conflict");
+
+        assert_eq!(0, solver.lower_bound(&s1));
+        assert_eq!(4, solver.upper_bound(&s1));
+    }
+
+    #[test]
+    fn tasks_with_zero_duration_are_not_constrained() {
+        let mut solver = Solver::default();
+        let j = solver.new_bounded_integer(0, 3);
+        let i = solver.new_bounded_integer(5, 20);
+
+        solver
+            .add_constraint(constraints::disjunctive([j, i], [10, 0]))
+            .post()
+            .expect("no root-level conflict");
+
+        assert_eq!(5, solver.lower_bound(&i));
+        assert_eq!(20, solver.upper_bound(&i));
+        assert_eq!(0, solver.lower_bound(&j));
+        assert_eq!(3, solver.upper_bound(&j));
+    }
+}
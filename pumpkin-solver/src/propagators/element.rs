@@ -12,6 +12,7 @@ use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::reason::CloneableLazyReason;
 use crate::engine::variables::IntegerVariable;
 use crate::predicate;
 
@@ -21,7 +22,7 @@ use crate::predicate;
 /// Note that this propagator is 0-indexed
 #[derive(Clone, Debug)]
 pub(crate) struct ElementPropagator<VX, VI, VE> {
-    array: Rc<[VX]>,
+    array: Box<[VX]>,
     index: VI,
     rhs: VE,
 }
@@ -57,16 +58,15 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable>
 {
     pub(crate) fn new(array: Box<[VX]>, index: VI, rhs: VE) -> Self {
         // local ids of array vars are shifted by ID_X_OFFSET
-        ElementPropagator {
-            array: array.into(),
-            index,
-            rhs,
-        }
+        ElementPropagator { array, index, rhs }
     }
 }
 
-impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Propagator
-    for ElementPropagator<VX, VI, VE>
+impl<VX, VI, VE> Propagator for ElementPropagator<VX, VI, VE>
+where
+    VX: IntegerVariable + 'static,
+    VI: IntegerVariable + 'static,
+    VE: IntegerVariable + 'static,
 {
     fn initialise_at_root(
         &mut self,
@@ -88,8 +88,8 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         // Ensure index is non-negative
         context.set_lower_bound(&self.index, 0, conjunction!())?;
-        // Ensure index <= no. of x_j
-        context.set_upper_bound(&self.index, self.array.len() as i32, conjunction!())?;
+        // Ensure index < no. of x_j
+        context.set_upper_bound(&self.index, self.array.len() as i32 - 1, conjunction!())?;
 
         // For incremental solving: use the doubly linked list data-structure
         if context.is_fixed(&self.index) {
@@ -140,14 +140,18 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
                         ))
                     }));
                     let x_i = (*x_i).clone();
-                    context.remove(&self.index, i, move |_context: PropagationContext| {
-                        let mut reason = reason_info.0.clone();
-                        reason_info
-                            .1
-                            .iter()
-                            .for_each(|e| reason.push(predicate![x_i != *e]));
-                        reason.into()
-                    })?;
+                    context.remove(
+                        &self.index,
+                        i,
+                        CloneableLazyReason(move |_context: PropagationContext| {
+                            let mut reason = reason_info.0.clone();
+                            reason_info
+                                .1
+                                .iter()
+                                .for_each(|e| reason.push(predicate![x_i != *e]));
+                            reason.into()
+                        }),
+                    )?;
                 }
             });
 
@@ -162,18 +166,23 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
                     let reason_info = Rc::clone(rhs_reason.get_or_init(|| {
                         Rc::new((
                             context.describe_domain(&self.index),
-                            iter_values(context.as_readonly(), &self.index).collect::<Vec<_>>(),
+                            iter_values(context.as_readonly(), &self.index)
+                                .map(|i| self.array[i as usize].clone())
+                                .collect::<Vec<_>>(),
                         ))
                     }));
-                    let array = Rc::clone(&self.array);
-                    context.remove(&self.rhs, e, move |_context: PropagationContext| {
-                        let mut reason = reason_info.0.clone();
-                        reason_info
-                            .1
-                            .iter()
-                            .for_each(|i| reason.push(predicate![array[*i as usize] != e]));
-                        reason.into()
-                    })?;
+                    context.remove(
+                        &self.rhs,
+                        e,
+                        CloneableLazyReason(move |_context: PropagationContext| {
+                            let mut reason = reason_info.0.clone();
+                            reason_info
+                                .1
+                                .iter()
+                                .for_each(|x_i| reason.push(predicate![x_i != e]));
+                            reason.into()
+                        }),
+                    )?;
                 }
             });
         }
@@ -196,16 +205,16 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
     ) -> PropagationStatusCP {
         // Ensure index is non-negative
         context.set_lower_bound(&self.index, 0, conjunction!())?;
-        // Ensure index <= no. of x_j
-        context.set_upper_bound(&self.index, self.array.len() as i32, conjunction!())?;
+        // Ensure index < no. of x_j
+        context.set_upper_bound(&self.index, self.array.len() as i32 - 1, conjunction!())?;
 
         // Close to duplicate of `propagate` for now, without saving reason stuff...
         if context.is_fixed(&self.index) {
             let i = context.lower_bound(&self.index);
             let x_i = &self.array[i as usize];
 
-            let lb = min(context.lower_bound(&self.rhs), context.lower_bound(x_i));
-            let ub = max(context.upper_bound(&self.rhs), context.upper_bound(x_i));
+            let lb = max(context.lower_bound(&self.rhs), context.lower_bound(x_i));
+            let ub = min(context.upper_bound(&self.rhs), context.upper_bound(x_i));
 
             context.set_lower_bound(&self.rhs, lb, conjunction!())?;
             context.set_lower_bound(x_i, lb, conjunction!())?;
@@ -243,6 +252,10 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
         }
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -342,4 +355,23 @@ mod tests {
         // reason for `rhs >= 7` is that `x_1 >= 7`
         assert_eq!(*rhs_reason, conjunction!([index == 1] & [x_1 >= 7]));
     }
+
+    #[test]
+    fn index_is_restricted_to_positions_in_array() {
+        let mut solver = TestSolver::default();
+        let x_0 = solver.new_variable(0, 5);
+        let x_1 = solver.new_variable(0, 5);
+        let index = solver.new_variable(-3, 10);
+        let rhs = solver.new_variable(0, 5);
+        let array = vec![x_0, x_1].into_boxed_slice();
+
+        let mut propagator = solver
+            .new_propagator(ElementPropagator::new(array, index, rhs))
+            .expect("no empty domains");
+
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert_eq!(0, solver.lower_bound(index));
+        assert_eq!(1, solver.upper_bound(index));
+    }
 }
@@ -0,0 +1,246 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// The number of occurrences of `value` should lie in the range `[lower_count, upper_count]`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ValueCardinality {
+    pub(crate) value: i32,
+    pub(crate) lower_count: usize,
+    pub(crate) upper_count: usize,
+}
+
+/// Propagator for the global cardinality constraint `gcc(xs, cardinalities)`, which states that
+/// for every [`ValueCardinality`], the number of variables in `xs` which take its value is between
+/// its lower and upper count.
+///
+/// The propagator is based on counting the occurrences of every value:
+/// - If the number of variables fixed to a value is equal to its upper count, then the value is
+///   removed from the domains of the other variables; the explanation consists of the fixed
+///   variables.
+/// - If the number of variables which can still take a value is equal to its lower count, then
+///   these variables are fixed to the value; the explanation consists of the variables from which
+///   the value has been removed.
+#[derive(Clone, Debug)]
+pub(crate) struct GccPropagator<Var> {
+    variables: Box<[Var]>,
+    cardinalities: Box<[ValueCardinality]>,
+}
+
+impl<Var: IntegerVariable> GccPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, cardinalities: Box<[ValueCardinality]>) -> Self {
+        pumpkin_assert_simple!(
+            cardinalities
+                .iter()
+                .all(|cardinality| cardinality.lower_count <= cardinality.upper_count),
+            "The lower count of a value should not exceed its upper count"
+        );
+
+        GccPropagator {
+            variables,
+            cardinalities,
+        }
+    }
+
+    fn propagate_cardinality(
+        &self,
+        context: &mut PropagationContextMut,
+        cardinality: ValueCardinality,
+    ) -> PropagationStatusCP {
+        let value = cardinality.value;
+
+        let fixed_to_value = self
+            .variables
+            .iter()
+            .filter(|variable| {
+                context.is_fixed(*variable) && context.lower_bound(*variable) == value
+            })
+            .collect::<Vec<_>>();
+        let value_removed = self
+            .variables
+            .iter()
+            .filter(|variable| !context.contains(*variable, value))
+            .collect::<Vec<_>>();
+        let num_possible = self.variables.len() - value_removed.len();
+
+        let occurrences_explanation = || {
+            fixed_to_value
+                .iter()
+                .map(|variable| predicate![variable == value])
+                .collect::<PropositionalConjunction>()
+        };
+        let removals_explanation = || {
+            value_removed
+                .iter()
+                .map(|variable| predicate![variable != value])
+                .collect::<PropositionalConjunction>()
+        };
+
+        if fixed_to_value.len() > cardinality.upper_count {
+            return Err(occurrences_explanation().into());
+        }
+        if num_possible < cardinality.lower_count {
+            return Err(removals_explanation().into());
+        }
+
+        if fixed_to_value.len() == cardinality.upper_count {
+            let reason = occurrences_explanation();
+            for variable in self.variables.iter() {
+                if context.contains(variable, value) && !context.is_fixed(variable) {
+                    context.remove(variable, value, reason.clone())?;
+                }
+            }
+        }
+
+        if num_possible == cardinality.lower_count {
+            let reason = removals_explanation();
+            for variable in self.variables.iter() {
+                if context.contains(variable, value) && !context.is_fixed(variable) {
+                    context.set_bounds(variable, value, value, reason.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for GccPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "GlobalCardinality"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        for &cardinality in self.cardinalities.iter() {
+            self.propagate_cardinality(&mut context, cardinality)?;
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn saturated_value_is_removed_from_other_variables() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(1, 1);
+        let z = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(GccPropagator::new(
+                [x, y, z].into(),
+                [ValueCardinality {
+                    value: 1,
+                    lower_count: 0,
+                    upper_count: 2,
+                }]
+                .into(),
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(z, 2, 3);
+
+        let reason = solver.get_reason_int(predicate![z != 1].try_into().unwrap());
+        assert_eq!(conjunction!([x == 1] & [y == 1]), *reason);
+    }
+
+    #[test]
+    fn remaining_variables_are_forced_to_value() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(GccPropagator::new(
+                [x, y, z].into(),
+                [ValueCardinality {
+                    value: 1,
+                    lower_count: 2,
+                    upper_count: 3,
+                }]
+                .into(),
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 1, 1);
+        solver.assert_bounds(z, 1, 1);
+
+        let reason = solver.get_reason_int(predicate![y <= 1].try_into().unwrap());
+        assert_eq!(conjunction!([x != 1]), *reason);
+    }
+
+    #[test]
+    fn too_few_possible_occurrences_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+
+        let mut propagator = solver
+            .new_propagator(GccPropagator::new(
+                [x, y, z].into(),
+                [ValueCardinality {
+                    value: 1,
+                    lower_count: 1,
+                    upper_count: 3,
+                }]
+                .into(),
+            ))
+            .expect("no empty domains");
+
+        solver.remove(y, 1).expect("non-empty domain");
+        solver.remove(z, 1).expect("non-empty domain");
+        let result = solver.propagate(&mut propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(conjunction!([x != 1] & [y != 1] & [z != 1]), conjunction);
+        }
+    }
+}
@@ -0,0 +1,475 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// Propagator for the cost-based global cardinality constraint, which states that every variable
+/// `xs[i]` takes a value `values[j]`, that every value `values[j]` is taken by at most
+/// `upper_counts[j]` variables, and that `total_cost` is equal to the sum of `costs[i][j]` over
+/// the assignments `xs[i] = values[j]`.
+///
+/// The propagator is based on the min-cost flow relaxation of the constraint \[1\]; the network
+/// consists of an arc from every variable to every value in its domain (with the cost of the
+/// assignment), and an arc from every value to the sink (with the upper count as its capacity).
+/// Note that the lower counts are not part of the relaxation; these are enforced by a separate
+/// counting propagator.
+///
+/// The cost of a minimum-cost flow is a lower bound on `total_cost` (and the cost of a
+/// maximum-cost flow is an upper bound), and an assignment `xs[i] = values[j]` is removed if
+/// forcing it would increase the cost of the flow beyond the bounds of `total_cost`; this
+/// increase is bounded from below by the reduced cost of the arc.
+///
+/// The explanations are based on the reduced costs with respect to the optimal potentials of the
+/// network: an arc which is not in the network only needs to be part of an explanation if its
+/// reduced cost is negative, since adding an arc with a non-negative reduced cost cannot improve
+/// the flow.
+///
+/// # Bibliography
+/// \[1\] J.-C. Régin, ‘Cost-based arc consistency for global cardinality constraints’,
+/// Constraints, vol. 7, no. 3, pp. 387–405, 2002.
+#[derive(Clone, Debug)]
+pub(crate) struct GccCostPropagator<Var, CostVar> {
+    variables: Box<[Var]>,
+    values: Box<[i32]>,
+    upper_counts: Box<[usize]>,
+    /// The cost of assigning `variables[i]` to `values[j]` is `costs[i][j]`.
+    costs: Box<[Box<[i32]>]>,
+    total_cost: CostVar,
+}
+
+impl<Var: IntegerVariable, CostVar: IntegerVariable> GccCostPropagator<Var, CostVar> {
+    pub(crate) fn new(
+        variables: Box<[Var]>,
+        values: Box<[i32]>,
+        upper_counts: Box<[usize]>,
+        costs: Box<[Box<[i32]>]>,
+        total_cost: CostVar,
+    ) -> Self {
+        pumpkin_assert_simple!(
+            values.len() == upper_counts.len(),
+            "Every value should have exactly one upper count"
+        );
+        pumpkin_assert_simple!(
+            costs.len() == variables.len() && costs.iter().all(|row| row.len() == values.len()),
+            "There should be a cost for every variable and every value"
+        );
+
+        GccCostPropagator {
+            variables,
+            values,
+            upper_counts,
+            costs,
+            total_cost,
+        }
+    }
+
+    /// Builds the flow network over the current domains of the variables; the returned arcs
+    /// contain, for every variable, the index of the arc to every value (if it is in the domain).
+    fn create_network(
+        &self,
+        context: &PropagationContextMut,
+        sign: i64,
+    ) -> (FlowNetwork, Vec<Vec<Option<usize>>>) {
+        let num_variables = self.variables.len();
+        let mut network = FlowNetwork::new(num_variables + self.values.len() + 2);
+
+        let arcs = self
+            .variables
+            .iter()
+            .enumerate()
+            .map(|(i, variable)| {
+                let _ = network.add_arc(SOURCE, variable_node(i), 1, 0);
+
+                self.values
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &value)| {
+                        context.contains(variable, value).then(|| {
+                            network.add_arc(
+                                variable_node(i),
+                                value_node(num_variables, j),
+                                1,
+                                sign * self.costs[i][j] as i64,
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let sink = network.sink();
+        for (j, &upper_count) in self.upper_counts.iter().enumerate() {
+            let _ = network.add_arc(value_node(num_variables, j), sink, upper_count, 0);
+        }
+
+        (network, arcs)
+    }
+
+    /// Explains the absence of the assignments for which the predicate returns true.
+    fn removed_assignments_explanation(
+        &self,
+        context: &PropagationContextMut,
+        mut include: impl FnMut(usize, usize) -> bool,
+    ) -> PropositionalConjunction {
+        let mut explanation = PropositionalConjunction::default();
+        for (i, variable) in self.variables.iter().enumerate() {
+            for (j, &value) in self.values.iter().enumerate() {
+                if !context.contains(variable, value) && include(i, j) {
+                    explanation.add(predicate![variable != value]);
+                }
+            }
+        }
+        explanation
+    }
+
+    /// Propagates the lower bound of `total_cost` if `sign` is 1 and its upper bound if `sign` is
+    /// -1, after which the assignments which cannot be part of a flow within the bounds are
+    /// removed.
+    fn propagate_bound(
+        &self,
+        context: &mut PropagationContextMut,
+        sign: i64,
+    ) -> PropagationStatusCP {
+        let num_variables = self.variables.len();
+        let (mut network, arcs) = self.create_network(context, sign);
+
+        let Some(optimal_cost) = network.min_cost_flow(num_variables) else {
+            // The upper counts do not allow every variable to be assigned
+            return Err(self
+                .removed_assignments_explanation(context, |_, _| true)
+                .into());
+        };
+        let potentials = network.potentials();
+
+        let reduced_cost = |i: usize, j: usize| {
+            sign * self.costs[i][j] as i64 + potentials[variable_node(i)]
+                - potentials[value_node(num_variables, j)]
+        };
+        let explanation =
+            self.removed_assignments_explanation(context, |i, j| reduced_cost(i, j) < 0);
+
+        // The optimal cost is in terms of `sign * total_cost`
+        let bound = (sign * optimal_cost) as i32;
+        let bound_predicate = if sign > 0 {
+            context.set_lower_bound(&self.total_cost, bound, explanation.clone())?;
+            predicate![self.total_cost <= context.upper_bound(&self.total_cost)]
+        } else {
+            context.set_upper_bound(&self.total_cost, bound, explanation.clone())?;
+            predicate![self.total_cost >= context.lower_bound(&self.total_cost)]
+        };
+        let slack = if sign > 0 {
+            context.upper_bound(&self.total_cost) as i64 - optimal_cost
+        } else {
+            -optimal_cost - context.lower_bound(&self.total_cost) as i64
+        };
+
+        for (i, variable) in self.variables.iter().enumerate() {
+            for (j, &value) in self.values.iter().enumerate() {
+                let Some(arc) = arcs[i][j] else {
+                    continue;
+                };
+                // Assignments which are part of the optimal flow cannot be removed
+                if network.has_flow(arc) {
+                    continue;
+                }
+
+                if reduced_cost(i, j) > slack {
+                    let mut reason = explanation.clone();
+                    reason.add(bound_predicate);
+                    context.remove(variable, value, reason)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var, CostVar> Propagator for GccCostPropagator<Var, CostVar>
+where
+    Var: IntegerVariable + 'static,
+    CostVar: IntegerVariable + 'static,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+        let _ = context.register(
+            self.total_cost.clone(),
+            DomainEvents::BOUNDS,
+            LocalId::from(self.variables.len() as u32),
+        );
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "GlobalCardinalityCost"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // Every variable should take one of the values, otherwise its cost is not defined
+        for variable in self.variables.iter() {
+            for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+                if context.contains(variable, value) && !self.values.contains(&value) {
+                    context.remove(variable, value, PropositionalConjunction::default())?;
+                }
+            }
+        }
+
+        self.propagate_bound(&mut context, 1)?;
+        self.propagate_bound(&mut context, -1)
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+const SOURCE: usize = 0;
+
+fn variable_node(index: usize) -> usize {
+    index + 1
+}
+
+fn value_node(num_variables: usize, index: usize) -> usize {
+    num_variables + index + 1
+}
+
+/// An arc in the residual graph; the reverse of the arc at index `i` is stored at index `i ^ 1`.
+#[derive(Clone, Copy, Debug)]
+struct Arc {
+    to: usize,
+    residual_capacity: usize,
+    cost: i64,
+}
+
+/// A flow network in which a minimum-cost flow is computed using successive shortest paths.
+#[derive(Clone, Debug)]
+struct FlowNetwork {
+    arcs: Vec<Arc>,
+    outgoing: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    /// Creates a network with the provided number of nodes; the first node is the source and the
+    /// last node is the sink.
+    fn new(num_nodes: usize) -> Self {
+        FlowNetwork {
+            arcs: vec![],
+            outgoing: vec![vec![]; num_nodes],
+        }
+    }
+
+    fn sink(&self) -> usize {
+        self.outgoing.len() - 1
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: usize, cost: i64) -> usize {
+        let index = self.arcs.len();
+        self.arcs.push(Arc {
+            to,
+            residual_capacity: capacity,
+            cost,
+        });
+        self.arcs.push(Arc {
+            to: from,
+            residual_capacity: 0,
+            cost: -cost,
+        });
+        self.outgoing[from].push(index);
+        self.outgoing[to].push(index + 1);
+        index
+    }
+
+    fn has_flow(&self, arc: usize) -> bool {
+        self.arcs[arc ^ 1].residual_capacity > 0
+    }
+
+    /// Computes the shortest distances in the residual graph from the provided nodes using
+    /// Bellman-Ford, together with the arc through which every node was reached.
+    fn shortest_paths(&self, initial: &[Option<i64>]) -> (Vec<Option<i64>>, Vec<Option<usize>>) {
+        let mut distances = initial.to_vec();
+        let mut predecessors = vec![None; self.outgoing.len()];
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (from, outgoing) in self.outgoing.iter().enumerate() {
+                let Some(distance) = distances[from] else {
+                    continue;
+                };
+                for &index in outgoing {
+                    let arc = self.arcs[index];
+                    if arc.residual_capacity == 0 {
+                        continue;
+                    }
+                    let candidate = distance + arc.cost;
+                    if distances[arc.to].is_none_or(|current| candidate < current) {
+                        distances[arc.to] = Some(candidate);
+                        predecessors[arc.to] = Some(index);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+
+    /// Sends `amount` units of flow from the source to the sink with minimum cost, and returns
+    /// this cost; if this amount of flow cannot be sent, then [`None`] is returned.
+    fn min_cost_flow(&mut self, amount: usize) -> Option<i64> {
+        let sink = self.sink();
+        let mut initial = vec![None; self.outgoing.len()];
+        initial[SOURCE] = Some(0);
+
+        let mut cost = 0;
+        let mut remaining = amount;
+        while remaining > 0 {
+            let (distances, predecessors) = self.shortest_paths(&initial);
+            let _ = distances[sink]?;
+
+            let mut path = vec![];
+            let mut node = sink;
+            while node != SOURCE {
+                let index = predecessors[node].expect("every node on the path has a predecessor");
+                path.push(index);
+                node = self.arcs[index ^ 1].to;
+            }
+
+            let bottleneck = path
+                .iter()
+                .map(|&index| self.arcs[index].residual_capacity)
+                .min()
+                .expect("the path is non-empty")
+                .min(remaining);
+            for &index in path.iter() {
+                self.arcs[index].residual_capacity -= bottleneck;
+                self.arcs[index ^ 1].residual_capacity += bottleneck;
+                cost += bottleneck as i64 * self.arcs[index].cost;
+            }
+            remaining -= bottleneck;
+        }
+
+        Some(cost)
+    }
+
+    /// Returns potentials for which every arc in the residual graph has a non-negative reduced
+    /// cost; these exist since the residual graph of a minimum-cost flow has no negative cycles.
+    fn potentials(&self) -> Vec<i64> {
+        let (distances, _) = self.shortest_paths(&vec![Some(0); self.outgoing.len()]);
+        distances
+            .into_iter()
+            .map(|distance| distance.expect("every node has a potential"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn expensive_assignment_is_pruned_by_cost_bound() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let total_cost = solver.new_variable(0, 5);
+
+        let _ = solver
+            .new_propagator(GccCostPropagator::new(
+                [x, y].into(),
+                [1, 2].into(),
+                [1, 1].into(),
+                [[1, 10].into(), [1, 2].into()].into(),
+                total_cost,
+            ))
+            .expect("no empty domains");
+
+        // Assigning x to 2 would cost at least 11
+        solver.assert_bounds(x, 1, 1);
+        solver.assert_bounds(total_cost, 3, 3);
+
+        let reason = solver.get_reason_int(predicate![x != 2].try_into().unwrap());
+        assert_eq!(conjunction!([total_cost <= 5]), *reason);
+    }
+
+    #[test]
+    fn lower_bound_explanation_contains_removed_cheaper_assignments() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let total_cost = solver.new_variable(0, 100);
+
+        let mut propagator = solver
+            .new_propagator(GccCostPropagator::new(
+                [x, y].into(),
+                [1, 2, 3].into(),
+                [2, 2, 2].into(),
+                [[1, 5, 9].into(), [1, 5, 9].into()].into(),
+                total_cost,
+            ))
+            .expect("no empty domains");
+        solver.assert_bounds(total_cost, 2, 18);
+
+        solver.remove(x, 1).expect("non-empty domain");
+        solver.remove(x, 3).expect("non-empty domain");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(total_cost, 6, 14);
+
+        // Only the removal of the cheaper value is required to explain the lower bound
+        let reason = solver.get_reason_int(predicate![total_cost >= 6].try_into().unwrap());
+        assert_eq!(conjunction!([x != 1]), *reason);
+    }
+
+    #[test]
+    fn insufficient_upper_counts_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(1, 1);
+        let total_cost = solver.new_variable(0, 10);
+
+        let result = solver.new_propagator(GccCostPropagator::new(
+            [x, y].into(),
+            [1].into(),
+            [1].into(),
+            [[0].into(), [0].into()].into(),
+            total_cost,
+        ));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(PropositionalConjunction::default(), conjunction);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::basic_types::HashSet;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+
+/// Propagator which channels between an integer variable `x` and the indicator literals
+/// `[l_0, \ldots, l_{n-1}]`, such that `l_v <-> [x == v]`.
+///
+/// As a consequence, `x` can only take values in `0..n` and exactly one of the indicators is true.
+#[derive(Clone, Debug)]
+pub(crate) struct IndicatorChannelingPropagator<Var> {
+    variable: Var,
+    indicators: Box<[Literal]>,
+    /// Whether the indicators are over pairwise distinct propositional variables; otherwise,
+    /// setting the indicator of one value also sets the indicator of another value.
+    has_distinct_indicators: bool,
+}
+
+const ID_VARIABLE: LocalId = LocalId::from(0);
+// local ids of the indicators are shifted by ID_INDICATOR_OFFSET
+const ID_INDICATOR_OFFSET: u32 = 1;
+
+impl<Var: IntegerVariable> IndicatorChannelingPropagator<Var> {
+    pub(crate) fn new(variable: Var, indicators: Box<[Literal]>) -> Self {
+        IndicatorChannelingPropagator {
+            variable,
+            indicators,
+            has_distinct_indicators: false,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for IndicatorChannelingPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.variable.clone(), DomainEvents::ANY_INT, ID_VARIABLE);
+        self.indicators
+            .iter()
+            .enumerate()
+            .for_each(|(index, indicator)| {
+                let _ = context.register_literal(
+                    *indicator,
+                    DomainEvents::ANY_BOOL,
+                    LocalId::from(index as u32 + ID_INDICATOR_OFFSET),
+                );
+            });
+
+        let mut variables: HashSet<_> = HashSet::default();
+        self.has_distinct_indicators = self
+            .indicators
+            .iter()
+            .all(|indicator| variables.insert(indicator.get_propositional_variable()));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "IndicatorChanneling"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        // The variable is updated from the indicators before the indicators are updated from the
+        // variable, which only sets indicators of values that were already removed or fixed, unless
+        // an indicator is shared by several values
+        self.has_distinct_indicators
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // The variable can only take the values which have an indicator
+        context.set_lower_bound(&self.variable, 0, conjunction!())?;
+        context.set_upper_bound(
+            &self.variable,
+            self.indicators.len() as i32 - 1,
+            conjunction!(),
+        )?;
+
+        // From the indicators to the variable
+        for (value, &indicator) in self.indicators.iter().enumerate() {
+            let value = value as i32;
+
+            if context.is_literal_true(indicator) {
+                context.set_lower_bound(
+                    &self.variable,
+                    value,
+                    PropositionalConjunction::from(vec![indicator.into()]),
+                )?;
+                context.set_upper_bound(
+                    &self.variable,
+                    value,
+                    PropositionalConjunction::from(vec![indicator.into()]),
+                )?;
+            } else if context.is_literal_false(indicator) {
+                context.remove(
+                    &self.variable,
+                    value,
+                    PropositionalConjunction::from(vec![(!indicator).into()]),
+                )?;
+            }
+        }
+
+        // From the variable to the indicators
+        for (value, &indicator) in self.indicators.iter().enumerate() {
+            let value = value as i32;
+            let variable = &self.variable;
+
+            if !context.contains(variable, value) {
+                context.assign_literal(indicator, false, conjunction!([variable != value]))?;
+            } else if context.is_fixed_to(variable, value) {
+                context.assign_literal(indicator, true, conjunction!([variable == value]))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn fixing_the_variable_fixes_the_indicators() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+        let indicators = (0..5).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+        let mut propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(
+                x,
+                indicators.clone().into(),
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 0, 4);
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 3);
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 0, x, 3);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        for (value, &indicator) in indicators.iter().enumerate() {
+            if value == 3 {
+                assert!(solver.is_literal_false(!indicator));
+            } else {
+                assert!(solver.is_literal_false(indicator));
+            }
+        }
+
+        let reason = solver.get_reason_bool(indicators[3], true);
+        assert_eq!(conjunction!([x == 3]), *reason);
+    }
+
+    #[test]
+    fn fixing_an_indicator_fixes_the_variable() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 4);
+        let indicators = (0..5).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+        let mut propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(
+                x,
+                indicators.clone().into(),
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(indicators[2], true);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(x, 2, 2);
+        assert!(solver.is_literal_false(indicators[0]));
+        assert!(solver.is_literal_false(indicators[4]));
+
+        let reason = solver.get_reason_int(predicate![x <= 2].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::from(vec![indicators[2].into()]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn false_indicator_removes_value() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 2);
+        let indicators = (0..3).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+        let mut propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(
+                x,
+                indicators.clone().into(),
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(indicators[1], false);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert!(!solver.contains(x, 1));
+        solver.assert_bounds(x, 0, 2);
+
+        let reason = solver.get_reason_int(predicate![x != 1].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::from(vec![(!indicators[1]).into()]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn two_true_indicators_lead_to_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 3);
+        let indicators = (0..4).map(|_| solver.new_literal()).collect::<Vec<_>>();
+
+        let mut propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(
+                x,
+                indicators.clone().into(),
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(indicators[1], true);
+        solver.set_literal(indicators[3], true);
+
+        let result = solver.propagate(&mut propagator);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn shared_indicators_are_not_idempotent() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 2);
+        let y = solver.new_variable(0, 2);
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        let propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(x, [a, b, c].into()))
+            .expect("no empty domains");
+        assert!(propagator.is_idempotent());
+
+        let propagator = solver
+            .new_propagator(IndicatorChannelingPropagator::new(y, [a, b, !a].into()))
+            .expect("no empty domains");
+        assert!(!propagator.is_idempotent());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// Propagator for the constraint `lhs <=_lex rhs`, which states that the sequence `lhs` is
+/// lexicographically smaller than or equal to the sequence `rhs`.
+///
+/// The propagator determines the most significant position `alpha` at which `lhs` and `rhs` are
+/// not (yet) fixed to the same value; all positions before `alpha` are fixed to be equal, which
+/// means that `lhs[alpha] <= rhs[alpha]` should hold. The bounds of the variables at `alpha` are
+/// propagated accordingly, where the explanation consists of the equalities of the prefix
+/// positions together with the bound which is propagated.
+#[derive(Clone, Debug)]
+pub(crate) struct LexLessEqualPropagator<Var> {
+    lhs: Box<[Var]>,
+    rhs: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> LexLessEqualPropagator<Var> {
+    pub(crate) fn new(lhs: Box<[Var]>, rhs: Box<[Var]>) -> Self {
+        pumpkin_assert_simple!(
+            lhs.len() == rhs.len(),
+            "The sequences which are compared should have the same length"
+        );
+
+        LexLessEqualPropagator { lhs, rhs }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for LexLessEqualPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.lhs
+            .iter()
+            .chain(self.rhs.iter())
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::BOUNDS,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "LexLessEqual"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let mut prefix_equalities = PropositionalConjunction::default();
+
+        for (left, right) in self.lhs.iter().zip(self.rhs.iter()) {
+            let left_lower_bound = context.lower_bound(left);
+            let right_upper_bound = context.upper_bound(right);
+
+            if context.is_fixed(left)
+                && context.is_fixed(right)
+                && left_lower_bound == right_upper_bound
+            {
+                prefix_equalities.add(predicate![left == left_lower_bound]);
+                prefix_equalities.add(predicate![right == right_upper_bound]);
+                continue;
+            }
+
+            // This is the most significant position at which the sequences can differ
+            if left_lower_bound > right_upper_bound {
+                let mut conflict = prefix_equalities;
+                conflict.add(predicate![left >= left_lower_bound]);
+                conflict.add(predicate![right <= right_upper_bound]);
+                return Err(conflict.into());
+            }
+
+            let mut upper_bound_reason = prefix_equalities.clone();
+            upper_bound_reason.add(predicate![right <= right_upper_bound]);
+            context.set_upper_bound(left, right_upper_bound, upper_bound_reason)?;
+
+            let mut lower_bound_reason = prefix_equalities;
+            lower_bound_reason.add(predicate![left >= left_lower_bound]);
+            context.set_lower_bound(right, left_lower_bound, lower_bound_reason)?;
+
+            break;
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn first_differing_position_is_propagated() {
+        let mut solver = TestSolver::default();
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(0, 8);
+        let x3 = solver.new_variable(0, 8);
+        let y1 = solver.new_variable(2, 2);
+        let y2 = solver.new_variable(3, 5);
+        let y3 = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(LexLessEqualPropagator::new(
+                [x1, x2, x3].into(),
+                [y1, y2, y3].into(),
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x2, 0, 5);
+        // Positions after the first differing position are not propagated
+        solver.assert_bounds(x3, 0, 8);
+
+        let reason = solver.get_reason_int(predicate![x2 <= 5].try_into().unwrap());
+        assert_eq!(conjunction!([x1 == 2] & [y1 == 2] & [y2 <= 5]), *reason);
+    }
+
+    #[test]
+    fn prefix_forcing_lhs_to_be_greater_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x1 = solver.new_variable(1, 1);
+        let x2 = solver.new_variable(3, 5);
+        let y1 = solver.new_variable(1, 1);
+        let y2 = solver.new_variable(0, 2);
+
+        let result = solver.new_propagator(LexLessEqualPropagator::new(
+            [x1, x2].into(),
+            [y1, y2].into(),
+        ));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(
+                conjunction!([x1 == 1] & [y1 == 1] & [x2 >= 3] & [y2 <= 2]),
+                conjunction
+            );
+        }
+    }
+}
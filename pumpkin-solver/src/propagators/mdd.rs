@@ -0,0 +1,393 @@
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// A layered multi-valued decision diagram (MDD) which represents a set of tuples of a fixed
+/// arity in a compressed form.
+///
+/// Layer `i` contains the nodes from which the value of the `i`-th variable is chosen; every edge
+/// from a node in layer `i` is labelled with a value and leads to a node in layer `i + 1`. The
+/// final layer consists of a single terminal node, and every path from the root to the terminal
+/// node corresponds to a tuple.
+#[derive(Clone, Debug)]
+pub struct Mdd {
+    /// `layers[i][node]` contains the edges `(value, target)` of `node` in layer `i`, where
+    /// `target` is a node in layer `i + 1`.
+    layers: Vec<Vec<Vec<(i32, usize)>>>,
+}
+
+impl Mdd {
+    /// Creates a reduced [`Mdd`] which represents exactly the provided `tuples`; every tuple
+    /// should contain `arity` values.
+    ///
+    /// Nodes which have the same outgoing edges are merged, such that tuples which share a suffix
+    /// also share the nodes representing that suffix.
+    pub fn from_tuples(arity: usize, tuples: impl IntoIterator<Item = Vec<i32>>) -> Mdd {
+        // First the tuples are inserted into a trie...
+        let mut trie: Vec<Vec<Vec<(i32, usize)>>> = vec![vec![]; arity];
+        if arity > 0 {
+            // The root node
+            trie[0].push(vec![]);
+        }
+        for tuple in tuples {
+            pumpkin_assert_simple!(
+                tuple.len() == arity,
+                "Every tuple should contain exactly one value per variable"
+            );
+
+            let mut node = 0;
+            for (layer, &value) in tuple.iter().enumerate() {
+                let existing_edge = trie[layer][node]
+                    .iter()
+                    .find(|&&(edge_value, _)| edge_value == value)
+                    .map(|&(_, target)| target);
+
+                node = match existing_edge {
+                    Some(target) => target,
+                    None if layer + 1 == arity => {
+                        trie[layer][node].push((value, 0));
+                        0
+                    }
+                    None => {
+                        let target = trie[layer + 1].len();
+                        trie[layer + 1].push(vec![]);
+                        trie[layer][node].push((value, target));
+                        target
+                    }
+                };
+            }
+        }
+
+        // ...after which the equivalent nodes are merged, starting from the final layer
+        let mut layers = vec![vec![]; arity];
+        let mut next_layer_ids = vec![0];
+        for layer in (0..arity).rev() {
+            let mut node_ids: HashMap<Vec<(i32, usize)>, usize> = HashMap::default();
+            let mut layer_ids = Vec::with_capacity(trie[layer].len());
+
+            for edges in trie[layer].iter() {
+                let mut edges = edges
+                    .iter()
+                    .map(|&(value, target)| (value, next_layer_ids[target]))
+                    .collect::<Vec<_>>();
+                edges.sort_unstable();
+
+                let next_id = node_ids.len();
+                let id = *node_ids.entry(edges.clone()).or_insert_with(|| {
+                    layers[layer].push(edges);
+                    next_id
+                });
+                layer_ids.push(id);
+            }
+
+            next_layer_ids = layer_ids;
+        }
+
+        Mdd { layers }
+    }
+
+    /// The number of variables in the tuples represented by the [`Mdd`].
+    pub fn arity(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// The number of nodes in the [`Mdd`], excluding the terminal node.
+    pub fn num_nodes(&self) -> usize {
+        self.layers.iter().map(|layer| layer.len()).sum()
+    }
+
+    /// The number of nodes in the given layer; the terminal node forms the final layer.
+    fn layer_size(&self, layer: usize) -> usize {
+        self.layers.get(layer).map_or(1, |nodes| nodes.len())
+    }
+}
+
+/// Propagator for the constraint `mdd([x_1, \ldots, x_n], M)`, which states that the assignment
+/// of `[x_1, \ldots, x_n]` corresponds to a path from the root to the terminal node of the
+/// [`Mdd`] `M`.
+///
+/// The propagator achieves generalised arc consistency by computing which nodes are reachable
+/// from the root and which nodes can reach the terminal node using the edges whose value is in
+/// the domain of the corresponding variable; a value is supported if it labels an edge on such a
+/// path. The explanation for removing a value from `x_i` consists of the removed values of the
+/// other variables which label an edge in their layer of the [`Mdd`].
+#[derive(Clone, Debug)]
+pub(crate) struct MddPropagator<Var> {
+    variables: Box<[Var]>,
+    mdd: Mdd,
+    /// `layer_values[i]` contains the values which label an edge in layer `i`.
+    layer_values: Box<[Box<[i32]>]>,
+}
+
+impl<Var: IntegerVariable> MddPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, mdd: Mdd) -> Self {
+        pumpkin_assert_simple!(
+            variables.len() == mdd.arity(),
+            "The number of variables should equal the arity of the MDD"
+        );
+
+        let layer_values = mdd
+            .layers
+            .iter()
+            .map(|layer| {
+                let mut values = layer
+                    .iter()
+                    .flat_map(|edges| edges.iter().map(|&(value, _)| value))
+                    .collect::<Vec<_>>();
+                values.sort_unstable();
+                values.dedup();
+                values.into_boxed_slice()
+            })
+            .collect();
+
+        MddPropagator {
+            variables,
+            mdd,
+            layer_values,
+        }
+    }
+
+    /// The explanation for the (lack of) support in the [`Mdd`] in terms of the removed values of
+    /// all variables except the one at `excluded_layer`.
+    fn removed_values_explanation(
+        &self,
+        context: PropagationContext,
+        excluded_layer: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.variables
+            .iter()
+            .zip(self.layer_values.iter())
+            .enumerate()
+            .filter(|&(layer, _)| Some(layer) != excluded_layer)
+            .flat_map(|(_, (variable, values))| {
+                values
+                    .iter()
+                    .filter(|&&value| !context.contains(variable, value))
+                    .map(|&value| predicate![variable != value])
+            })
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for MddPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Mdd"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let num_layers = self.variables.len();
+
+        // Determine the nodes which can be reached from the root
+        let mut reachable = (0..=num_layers)
+            .map(|layer| vec![false; self.mdd.layer_size(layer)])
+            .collect::<Vec<_>>();
+        reachable[0][0] = true;
+        for (layer, variable) in self.variables.iter().enumerate() {
+            for (node, edges) in self.mdd.layers[layer].iter().enumerate() {
+                if !reachable[layer][node] {
+                    continue;
+                }
+
+                for &(value, target) in edges.iter() {
+                    if context.contains(variable, value) {
+                        reachable[layer + 1][target] = true;
+                    }
+                }
+            }
+        }
+
+        if !reachable[num_layers][0] {
+            return Err(self
+                .removed_values_explanation(context.as_readonly(), None)
+                .into());
+        }
+
+        // Determine the values which label an edge on a path from the root to the terminal node
+        let mut reaches_terminal = reachable[num_layers].clone();
+        let mut supported_values: Vec<HashSet<i32>> = vec![HashSet::default(); num_layers];
+        for (layer, variable) in self.variables.iter().enumerate().rev() {
+            let mut layer_reaches_terminal = vec![false; self.mdd.layer_size(layer)];
+
+            for (node, edges) in self.mdd.layers[layer].iter().enumerate() {
+                if !reachable[layer][node] {
+                    continue;
+                }
+
+                for &(value, target) in edges.iter() {
+                    if context.contains(variable, value) && reaches_terminal[target] {
+                        layer_reaches_terminal[node] = true;
+                        let _ = supported_values[layer].insert(value);
+                    }
+                }
+            }
+
+            reaches_terminal = layer_reaches_terminal;
+        }
+
+        for (layer, variable) in self.variables.iter().enumerate() {
+            for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+                if !context.contains(variable, value) || supported_values[layer].contains(&value) {
+                    continue;
+                }
+
+                let reason = if self.layer_values[layer].binary_search(&value).is_ok() {
+                    self.removed_values_explanation(context.as_readonly(), Some(layer))
+                } else {
+                    // The value does not occur in any tuple
+                    conjunction!()
+                };
+                context.remove(variable, value, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+    use crate::propagators::table::TablePropagator;
+
+    fn tuples() -> Vec<Vec<i32>> {
+        vec![
+            vec![1, 2, 3],
+            vec![1, 3, 3],
+            vec![2, 2, 3],
+            vec![2, 3, 3],
+            vec![3, 1, 1],
+            vec![3, 1, 2],
+        ]
+    }
+
+    #[test]
+    fn equivalent_nodes_are_merged() {
+        let mdd = Mdd::from_tuples(3, tuples());
+
+        // The root; one node after choosing 1 or 2 (merged since their suffixes are equal) and
+        // one after choosing 3; in the final layer one node for the value 3 (shared by the
+        // prefixes [1, _] and [2, _]) and one for the values 1 and 2
+        assert_eq!(3, mdd.arity());
+        assert_eq!(5, mdd.num_nodes());
+    }
+
+    #[test]
+    fn no_path_to_terminal_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 4);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+
+        let mut propagator = solver
+            .new_propagator(MddPropagator::new(
+                [x, y, z].into(),
+                Mdd::from_tuples(3, tuples()),
+            ))
+            .expect("no empty domains");
+
+        solver.remove(z, 3).expect("non-empty domain");
+        solver.remove(y, 1).expect("non-empty domain");
+        let result = solver.propagate(&mut propagator);
+
+        // Every tuple requires either z = 3 or y = 1
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(conjunction!([y != 1] & [z != 3]), conjunction);
+        }
+    }
+
+    #[test]
+    fn pruning_is_equal_to_pruning_of_table() {
+        // Both propagators are applied to identical domains
+        let create_solver = || {
+            let mut solver = TestSolver::default();
+            let x = solver.new_variable(0, 4);
+            let y = solver.new_variable(1, 3);
+            let z = solver.new_variable(1, 3);
+            solver.remove(y, 1).expect("non-empty domain");
+            solver.remove(z, 1).expect("non-empty domain");
+            (solver, [x, y, z])
+        };
+
+        let (mut mdd_solver, variables) = create_solver();
+        let _ = mdd_solver
+            .new_propagator(MddPropagator::new(
+                variables.into(),
+                Mdd::from_tuples(3, tuples()),
+            ))
+            .expect("no empty domains");
+
+        let (mut table_solver, table_variables) = create_solver();
+        let _ = table_solver
+            .new_propagator(TablePropagator::new(
+                table_variables.into(),
+                tuples()
+                    .into_iter()
+                    .map(|tuple| tuple.into_boxed_slice())
+                    .collect(),
+            ))
+            .expect("no empty domains");
+
+        for (layer, (&variable, &table_variable)) in
+            variables.iter().zip(table_variables.iter()).enumerate()
+        {
+            for value in 0..=4 {
+                assert_eq!(
+                    table_solver.contains(table_variable, value),
+                    mdd_solver.contains(variable, value),
+                    "value {value} of variable {layer}"
+                );
+            }
+        }
+
+        let reason = mdd_solver.get_reason_int(predicate![variables[0] != 3].try_into().unwrap());
+        assert_eq!(
+            conjunction!([variables[1] != 1] & [variables[2] != 1]),
+            *reason
+        );
+    }
+}
@@ -2,11 +2,26 @@
 //!
 //! See the [`crate::engine::cp::propagation`] for info on propagators.
 
+pub(crate) mod all_different;
 pub(crate) mod arithmetic;
+pub(crate) mod circuit;
 pub(crate) mod clausal;
+pub(crate) mod count;
 mod cumulative;
+pub(crate) mod depth_gated;
+pub(crate) mod diffn;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+pub(crate) mod gcc;
+pub(crate) mod gcc_cost;
+pub(crate) mod indicator_channeling;
+pub(crate) mod lex_less_equal;
+pub(crate) mod mdd;
+pub(crate) mod objective_bound;
 mod reified_propagator;
+pub(crate) mod subcircuit;
+pub(crate) mod table;
+pub(crate) mod value_precede;
 pub(crate) use arithmetic::*;
 pub use cumulative::CumulativeExplanationType;
 pub use cumulative::CumulativeOptions;
@@ -0,0 +1,275 @@
+use crate::basic_types::Function;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Bounding propagator for branch-and-bound optimisation of a [`Function`] which is linked to the
+/// `objective` variable; when minimising, the upper bound of `objective` is tied to the incumbent
+/// (i.e. it is strictly smaller than the objective value of the best solution found so far), and
+/// when maximising, the lower bound of `objective` is tied to the incumbent.
+///
+/// The lower bound of the [`Function`] over the current partial assignment is a lower bound on
+/// the objective value of every solution in the current subtree. If it exceeds the upper bound of
+/// `objective`, then no solution in the subtree improves on the incumbent and a conflict is
+/// reported, causing the solver to backtrack. Otherwise, the lower bound of `objective` is
+/// tightened to the lower bound of the [`Function`]. The upper bound of the [`Function`] is used
+/// symmetrically for maximisation.
+///
+/// The explanations consist of the bounds of the integer terms and the weighted literals which
+/// are assigned.
+#[derive(Clone, Debug)]
+pub(crate) struct ObjectiveBoundPropagator<Var> {
+    function: Function,
+    objective: Var,
+}
+
+const ID_OBJECTIVE: LocalId = LocalId::from(0);
+// local ids of the terms of the function are shifted by ID_TERM_OFFSET
+const ID_TERM_OFFSET: u32 = 1;
+
+impl<Var: IntegerVariable> ObjectiveBoundPropagator<Var> {
+    pub(crate) fn new(function: Function, objective: Var) -> Self {
+        ObjectiveBoundPropagator {
+            function,
+            objective,
+        }
+    }
+
+    /// The explanation for the lower bound of the function over the current partial assignment.
+    fn lower_bound_explanation(&self, context: &PropagationContextMut) -> PropositionalConjunction {
+        self.function
+            .get_weighted_integers()
+            .map(|(domain_id, _)| predicate![domain_id >= context.lower_bound(domain_id)])
+            .chain(
+                self.function
+                    .get_weighted_literals()
+                    .filter(|(&literal, _)| context.is_literal_true(literal))
+                    .map(|(&literal, _)| literal.into()),
+            )
+            .collect()
+    }
+
+    /// The explanation for the upper bound of the function over the current partial assignment.
+    fn upper_bound_explanation(&self, context: &PropagationContextMut) -> PropositionalConjunction {
+        self.function
+            .get_weighted_integers()
+            .map(|(domain_id, _)| predicate![domain_id <= context.upper_bound(domain_id)])
+            .chain(
+                self.function
+                    .get_weighted_literals()
+                    .filter(|(&literal, _)| context.is_literal_false(literal))
+                    .map(|(&literal, _)| (!literal).into()),
+            )
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for ObjectiveBoundPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.objective.clone(), DomainEvents::BOUNDS, ID_OBJECTIVE);
+
+        let num_integers = self.function.get_weighted_integers().len() as u32;
+        self.function
+            .get_weighted_integers()
+            .enumerate()
+            .for_each(|(index, (&domain_id, _))| {
+                let _ = context.register(
+                    domain_id,
+                    DomainEvents::BOUNDS,
+                    LocalId::from(index as u32 + ID_TERM_OFFSET),
+                );
+            });
+        self.function
+            .get_weighted_literals()
+            .enumerate()
+            .for_each(|(index, (&literal, _))| {
+                let _ = context.register_literal(
+                    literal,
+                    DomainEvents::ANY_BOOL,
+                    LocalId::from(index as u32 + num_integers + ID_TERM_OFFSET),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "ObjectiveBound"
+    }
+
+    fn is_idempotent(&self) -> bool {
+        // Tightening the bounds of the objective does not affect the bounds of the function
+        true
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let lower_bound = self.function.lower_bound(&context);
+        let objective_upper_bound = context.upper_bound(&self.objective);
+
+        // When minimising, the subtree cannot contain a solution which improves on the incumbent
+        if lower_bound > objective_upper_bound as i64 {
+            let mut conflict = self.lower_bound_explanation(&context);
+            conflict.add(predicate![self.objective <= objective_upper_bound]);
+            return Err(conflict.into());
+        }
+
+        let upper_bound = self.function.upper_bound(&context);
+        let objective_lower_bound = context.lower_bound(&self.objective);
+
+        // When maximising, the subtree cannot contain a solution which improves on the incumbent
+        if upper_bound < objective_lower_bound as i64 {
+            let mut conflict = self.upper_bound_explanation(&context);
+            conflict.add(predicate![self.objective >= objective_lower_bound]);
+            return Err(conflict.into());
+        }
+
+        let reason = self.lower_bound_explanation(&context);
+        context.set_lower_bound(&self.objective, lower_bound as i32, reason)?;
+
+        let reason = self.upper_bound_explanation(&context);
+        context.set_upper_bound(&self.objective, upper_bound as i32, reason)?;
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicates::Predicate;
+
+    #[test]
+    fn objective_bounds_follow_partial_assignment() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 5);
+        let literal = solver.new_literal();
+        let objective = solver.new_variable(0, 20);
+
+        // 2 * x + 3 * literal + 1
+        let mut function = Function::default();
+        function
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        function.add_weighted_literal(literal, 3);
+        function.add_constant_term(1);
+
+        let mut propagator = solver
+            .new_propagator(ObjectiveBoundPropagator::new(function, objective))
+            .expect("no empty domains");
+        solver.assert_bounds(objective, 7, 14);
+
+        solver.set_literal(literal, true);
+        solver.propagate(&mut propagator).expect("no empty domains");
+        solver.assert_bounds(objective, 10, 14);
+
+        let reason = solver.get_reason_int(predicate![objective >= 10].try_into().unwrap());
+        assert!(reason
+            .iter()
+            .any(|&predicate| predicate == predicate![x >= 3]));
+        assert!(reason
+            .iter()
+            .any(|&predicate| predicate == Predicate::from(literal)));
+    }
+
+    #[test]
+    fn dominated_subtree_is_pruned_when_minimising() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+        let y = solver.new_variable(0, 5);
+        // The incumbent has objective value 8
+        let objective = solver.new_variable(0, 7);
+
+        // 2 * x + y
+        let mut function = Function::default();
+        function
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        function
+            .add_weighted_integer(y, 1)
+            .expect("the term is non-negative");
+
+        let mut propagator = solver
+            .new_propagator(ObjectiveBoundPropagator::new(function, objective))
+            .expect("no empty domains");
+
+        // Any solution with x >= 4 has an objective value of at least 8
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, x, 4);
+        let result = solver.propagate(&mut propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![x >= 4]));
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![y >= 0]));
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![objective <= 7]));
+        }
+    }
+
+    #[test]
+    fn dominated_subtree_is_pruned_when_maximising() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+        let y = solver.new_variable(0, 5);
+        // The incumbent has objective value 12
+        let objective = solver.new_variable(13, 15);
+
+        // 2 * x + y
+        let mut function = Function::default();
+        function
+            .add_weighted_integer(x, 2)
+            .expect("the term is non-negative");
+        function
+            .add_weighted_integer(y, 1)
+            .expect("the term is non-negative");
+
+        let mut propagator = solver
+            .new_propagator(ObjectiveBoundPropagator::new(function, objective))
+            .expect("no empty domains");
+
+        // Any solution with x <= 3 has an objective value of at most 11
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, x, 3);
+        let result = solver.propagate(&mut propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![x <= 3]));
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![y <= 5]));
+            assert!(conjunction
+                .iter()
+                .any(|&predicate| predicate == predicate![objective >= 13]));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::basic_types::ConflictInfo;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::PropagationStatusCP;
+#[cfg(doc)]
+use crate::constraints::ConstraintPoster;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
@@ -21,6 +23,13 @@ use crate::variables::Literal;
 /// the propagator implements [`Propagator::detect_inconsistency`], the result of that method may
 /// be used to propagate `r` to false. If that method is not implemented, `r` will never be
 /// propagated to false.
+///
+/// Propagating `r` to false is the contrapositive of `r -> p` (i.e. `!p -> !r`), so it does not
+/// change the semantics of the constraint; it only strengthens the propagation. It can be disabled
+/// using [`ReifiedPropagator::without_literal_propagation`] (see
+/// [`ConstraintPoster::implied_by_without_literal_propagation`]), in which case `r` is never assigned by
+/// this propagator; an inconsistency of `p` is then only reported once `r` is set to true. Note
+/// that the constraint `p -> r` is never enforced, regardless of this setting.
 #[derive(Clone, Debug)]
 pub(crate) struct ReifiedPropagator<WrappedPropagator> {
     propagator: WrappedPropagator,
@@ -32,6 +41,8 @@ pub(crate) struct ReifiedPropagator<WrappedPropagator> {
     /// The `LocalId` of the reification literal. Is guaranteed to be a larger ID than any of the
     /// registered ids of the wrapped propagator.
     reification_literal_id: LocalId,
+    /// Whether `r` is propagated to false when the wrapped propagator detects an inconsistency.
+    propagates_reification_literal: bool,
 }
 
 impl<WrappedPropagator: Propagator> ReifiedPropagator<WrappedPropagator> {
@@ -44,8 +55,16 @@ impl<WrappedPropagator: Propagator> ReifiedPropagator<WrappedPropagator> {
             name,
             reification_literal_id: LocalId::from(0), /* Place-holder, will be set in
                                                        * `initialise_at_root` */
+            propagates_reification_literal: true,
         }
     }
+
+    /// Disables propagating the reification literal to false when the wrapped propagator detects
+    /// an inconsistency; the reification literal is then never assigned by this propagator.
+    pub(crate) fn without_literal_propagation(mut self) -> Self {
+        self.propagates_reification_literal = false;
+        self
+    }
 }
 
 impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedPropagator> {
@@ -98,7 +117,9 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         // identifies at the root, and propagate the reification literal to false in the
         // `propagate` method.
         if let Err(conjunction) = self.propagator.initialise_at_root(context) {
-            self.inconsistency = Some(conjunction);
+            if self.propagates_reification_literal {
+                self.inconsistency = Some(conjunction);
+            }
         }
 
         self.reification_literal_id = context.get_next_local_id();
@@ -161,6 +182,17 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
 
         Ok(())
     }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(ReifiedPropagator {
+            propagator: self.propagator.clone_boxed()?,
+            reification_literal: self.reification_literal,
+            inconsistency: self.inconsistency.clone(),
+            name: self.name.clone(),
+            reification_literal_id: self.reification_literal_id,
+            propagates_reification_literal: self.propagates_reification_literal,
+        }))
+    }
 }
 
 impl<Prop: Propagator> ReifiedPropagator<Prop> {
@@ -175,7 +207,9 @@ impl<Prop: Propagator> ReifiedPropagator<Prop> {
     where
         Prop: Propagator,
     {
-        if !context.is_literal_fixed(self.reification_literal) {
+        if self.propagates_reification_literal
+            && !context.is_literal_fixed(self.reification_literal)
+        {
             if let Some(conjunction) = self.propagator.detect_inconsistency(context.as_readonly()) {
                 context.assign_literal(self.reification_literal, false, conjunction)?;
             }
@@ -208,7 +242,10 @@ impl<Prop: Propagator> ReifiedPropagator<Prop> {
             return EnqueueDecision::Enqueue;
         }
 
-        if !context.is_literal_false(self.reification_literal) && self.find_inconsistency(context) {
+        if self.propagates_reification_literal
+            && !context.is_literal_false(self.reification_literal)
+            && self.find_inconsistency(context)
+        {
             // Or the literal is not false already and there the propagator has found an
             // inconsistency (i.e. we should and can propagate the reification variable)
             return EnqueueDecision::Enqueue;
@@ -259,6 +296,41 @@ mod tests {
         assert_eq!(reason, &triggered_conflict);
     }
 
+    #[test]
+    fn a_detected_inconsistency_does_not_assign_literal_without_literal_propagation() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let a = solver.new_variable(1, 1);
+        let b = solver.new_variable(2, 2);
+
+        let triggered_conflict = conjunction!([a == 1] & [b == 2]);
+        let t1 = triggered_conflict.clone();
+        let t2 = triggered_conflict.clone();
+
+        let mut propagator = solver
+            .new_propagator(
+                ReifiedPropagator::new(
+                    GenericPropagator::new(
+                        move |_: PropagationContextMut| Err(t1.clone().into()),
+                        move |_: PropagationContext| Some(t2.clone()),
+                        |_: &mut PropagatorInitialisationContext| Ok(()),
+                    ),
+                    reification_literal,
+                )
+                .without_literal_propagation(),
+            )
+            .expect("no conflict");
+
+        assert!(!solver.is_literal_false(reification_literal));
+        assert!(!solver.is_literal_false(!reification_literal));
+
+        // The inconsistency is still reported once the reification literal is true
+        solver.set_literal(reification_literal, true);
+        let result = solver.propagate(&mut propagator);
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+    }
+
     #[test]
     fn a_true_literal_is_added_to_reason_for_propagation() {
         let mut solver = TestSolver::default();
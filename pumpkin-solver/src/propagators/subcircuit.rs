@@ -0,0 +1,302 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `subcircuit([x_0, \ldots, x_{n-1}])`, which states that the arcs
+/// `i -> x_i` with `x_i != i` form a single cycle; the nodes with `x_i = i` are not visited.
+///
+/// The propagator follows the reasoning of the [`super::circuit::CircuitPropagator`], except that
+/// self-loops are ignored when tracing the chains and cycles of fixed successors:
+/// - A node can be the successor of at most one node; if `x_j = v` then `v` is removed from the
+///   domains of the other successor variables.
+/// - The arc from the end of a chain back to its start would close a cycle; if there is a node
+///   `k` outside of the chain which is known to be visited (i.e. `x_k != k`), then this arc is
+///   removed. The explanation consists of the fixed successors which form the chain and
+///   `x_k != k`.
+/// - If the fixed successors form a cycle, then all of the nodes outside of the cycle are not
+///   visited, which is explained by the fixed successors forming the cycle. If they form more than
+///   one cycle then a conflict is reported, which is explained by the fixed successors forming
+///   two of the cycles.
+#[derive(Clone, Debug)]
+pub(crate) struct SubcircuitPropagator<Var> {
+    successors: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> SubcircuitPropagator<Var> {
+    pub(crate) fn new(successors: Box<[Var]>) -> Self {
+        SubcircuitPropagator { successors }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for SubcircuitPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.successors
+            .iter()
+            .enumerate()
+            .for_each(|(index, successor)| {
+                let _ = context.register(
+                    successor.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Subcircuit"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let num_nodes = self.successors.len();
+
+        // The successor of a node is another node, or the node itself if it is not visited
+        for successor in self.successors.iter() {
+            context.set_lower_bound(successor, 0, conjunction!())?;
+            context.set_upper_bound(successor, num_nodes as i32 - 1, conjunction!())?;
+        }
+
+        let fixed_successors = self
+            .successors
+            .iter()
+            .map(|successor| {
+                context
+                    .is_fixed(successor)
+                    .then(|| context.lower_bound(successor) as usize)
+            })
+            .collect::<Vec<_>>();
+
+        // Every node has at most one predecessor (which is the node itself if it is not visited);
+        // if two nodes have the same fixed successor then removing it from the domain of the other
+        // results in an empty domain
+        let mut predecessors: Vec<Option<usize>> = vec![None; num_nodes];
+        for (node, fixed_successor) in fixed_successors.iter().enumerate() {
+            let Some(value) = *fixed_successor else {
+                continue;
+            };
+
+            for (other, successor) in self.successors.iter().enumerate() {
+                if other != node {
+                    context.remove(
+                        successor,
+                        value as i32,
+                        conjunction!([self.successors[node] == value as i32]),
+                    )?;
+                }
+            }
+
+            if value != node {
+                predecessors[value] = Some(node);
+            }
+        }
+
+        // The self-loops are not part of a chain or a cycle
+        let mut visited = fixed_successors
+            .iter()
+            .enumerate()
+            .map(|(node, fixed_successor)| *fixed_successor == Some(node))
+            .collect::<Vec<_>>();
+
+        // Every node without a predecessor is the start of a chain, which should not be closed
+        // while another node is visited
+        for start in 0..num_nodes {
+            if visited[start] || predecessors[start].is_some() {
+                continue;
+            }
+
+            let mut chain = PropositionalConjunction::default();
+            let mut nodes_in_chain = vec![start];
+            let mut end = start;
+            visited[start] = true;
+
+            while let Some(next) = fixed_successors[end] {
+                chain.add(predicate![self.successors[end] == next as i32]);
+                end = next;
+                nodes_in_chain.push(end);
+                visited[end] = true;
+            }
+
+            // A chain consisting of a single node is closed by a self-loop, which is allowed
+            if nodes_in_chain.len() == 1 {
+                continue;
+            }
+
+            let visited_elsewhere = (0..num_nodes).find(|&node| {
+                !nodes_in_chain.contains(&node)
+                    && !context.contains(&self.successors[node], node as i32)
+            });
+            if let Some(other) = visited_elsewhere {
+                chain.add(predicate![self.successors[other] != other as i32]);
+                context.remove(&self.successors[end], start as i32, chain)?;
+            }
+        }
+
+        // The nodes which are not part of a chain or a self-loop lie on a cycle of fixed
+        // successors
+        let mut cycles = vec![];
+        for node in 0..num_nodes {
+            if visited[node] {
+                continue;
+            }
+
+            let mut cycle = PropositionalConjunction::default();
+            let mut nodes_in_cycle = vec![];
+            let mut current = node;
+
+            loop {
+                visited[current] = true;
+                nodes_in_cycle.push(current);
+                let next =
+                    fixed_successors[current].expect("a node on a cycle has a fixed successor");
+                cycle.add(predicate![self.successors[current] == next as i32]);
+                current = next;
+
+                if current == node {
+                    break;
+                }
+            }
+
+            cycles.push((cycle, nodes_in_cycle));
+        }
+
+        match cycles.as_slice() {
+            [] => {}
+            [(cycle, nodes_in_cycle)] => {
+                for (node, successor) in self.successors.iter().enumerate() {
+                    if !nodes_in_cycle.contains(&node) {
+                        context.set_lower_bound(successor, node as i32, cycle.clone())?;
+                        context.set_upper_bound(successor, node as i32, cycle.clone())?;
+                    }
+                }
+            }
+            [(first, _), (second, _), ..] => {
+                let mut conflict = first.clone();
+                second.iter().for_each(|&predicate| conflict.add(predicate));
+                return Err(conflict.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn self_loops_are_allowed() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 0);
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(1, 1);
+        let x3 = solver.new_variable(3, 3);
+
+        let result = solver.new_propagator(SubcircuitPropagator::new([x0, x1, x2, x3].into()));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn nodes_outside_of_a_cycle_are_not_visited() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 4);
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(3, 3);
+        let x3 = solver.new_variable(1, 1);
+        let x4 = solver.new_variable(0, 4);
+
+        let _ = solver
+            .new_propagator(SubcircuitPropagator::new([x0, x1, x2, x3, x4].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x0, 0, 0);
+        solver.assert_bounds(x4, 4, 4);
+        let reason = solver.get_reason_int(predicate![x0 <= 0].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::from(vec![
+                predicate![x1 == 2],
+                predicate![x2 == 3],
+                predicate![x3 == 1],
+            ]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn chain_is_not_closed_while_another_node_is_visited() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(1, 1);
+        let x1 = solver.new_variable(0, 3);
+        let x2 = solver.new_variable(0, 3);
+        let x3 = solver.new_variable(0, 3);
+
+        let mut propagator = solver
+            .new_propagator(SubcircuitPropagator::new([x0, x1, x2, x3].into()))
+            .expect("no empty domains");
+
+        // Closing the chain 0 -> 1 is allowed as long as nodes 2 and 3 can be self-loops
+        assert!(solver.contains(x1, 0));
+
+        solver.remove(x3, 3).expect("non-empty domain");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert!(!solver.contains(x1, 0));
+        let reason = solver.get_reason_int(predicate![x1 != 0].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::from(vec![predicate![x0 == 1], predicate![x3 != 3]]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn second_cycle_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(1, 1);
+        let x1 = solver.new_variable(0, 0);
+        let x2 = solver.new_variable(2, 2);
+        let x3 = solver.new_variable(4, 4);
+        let x4 = solver.new_variable(3, 3);
+
+        let result = solver.new_propagator(SubcircuitPropagator::new([x0, x1, x2, x3, x4].into()));
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(
+                PropositionalConjunction::from(vec![
+                    predicate![x0 == 1],
+                    predicate![x1 == 0],
+                    predicate![x3 == 4],
+                    predicate![x4 == 3],
+                ]),
+                conjunction
+            );
+        }
+    }
+}
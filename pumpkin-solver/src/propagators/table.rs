@@ -0,0 +1,206 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// Propagator for the constraint `table([x_1, \ldots, x_n], T)`, which states that the assignment
+/// of `[x_1, \ldots, x_n]` is one of the (allowed) tuples in `T`.
+///
+/// A tuple is valid if every one of its values is in the domain of the corresponding variable;
+/// the propagator removes the values which are not supported by any valid tuple, which achieves
+/// generalised arc consistency. The explanation for removing a value consists of, for every tuple
+/// containing that value, a predicate `[x_j != v]` which shows that the tuple is not valid.
+#[derive(Clone, Debug)]
+pub(crate) struct TablePropagator<Var> {
+    variables: Box<[Var]>,
+    tuples: Box<[Box<[i32]>]>,
+}
+
+impl<Var: IntegerVariable> TablePropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, tuples: Box<[Box<[i32]>]>) -> Self {
+        pumpkin_assert_simple!(
+            tuples.iter().all(|tuple| tuple.len() == variables.len()),
+            "Every tuple should contain exactly one value per variable"
+        );
+
+        TablePropagator { variables, tuples }
+    }
+
+    /// Returns a predicate which shows that the `tuple` is not valid, while ignoring the variable
+    /// at `excluded_index`; if the tuple is valid, then [`None`] is returned.
+    fn eliminating_predicate(
+        &self,
+        context: PropagationContext,
+        tuple: &[i32],
+        excluded_index: Option<usize>,
+    ) -> Option<Predicate> {
+        self.variables
+            .iter()
+            .zip(tuple)
+            .enumerate()
+            .filter(|&(index, _)| Some(index) != excluded_index)
+            .find(|(_, (variable, &value))| !context.contains(*variable, value))
+            .map(|(_, (variable, &value))| predicate![variable != value])
+    }
+
+    /// The explanation for why none of the tuples for which `is_relevant` holds is valid; the
+    /// variable at `excluded_index` is not considered.
+    fn eliminated_tuples_explanation(
+        &self,
+        context: PropagationContext,
+        excluded_index: Option<usize>,
+        is_relevant: impl Fn(&[i32]) -> bool,
+    ) -> PropositionalConjunction {
+        let mut explanation: Vec<Predicate> = vec![];
+        for tuple in self.tuples.iter().filter(|tuple| is_relevant(tuple)) {
+            let predicate = self
+                .eliminating_predicate(context, tuple, excluded_index)
+                .expect("the tuple should not be valid");
+            if !explanation.contains(&predicate) {
+                explanation.push(predicate);
+            }
+        }
+
+        explanation.into()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TablePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "Table"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let valid_tuples = self
+            .tuples
+            .iter()
+            .filter(|tuple| {
+                self.eliminating_predicate(context.as_readonly(), tuple, None)
+                    .is_none()
+            })
+            .collect::<Vec<_>>();
+
+        if valid_tuples.is_empty() {
+            return Err(self
+                .eliminated_tuples_explanation(context.as_readonly(), None, |_| true)
+                .into());
+        }
+
+        for (index, variable) in self.variables.iter().enumerate() {
+            for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+                if !context.contains(variable, value)
+                    || valid_tuples.iter().any(|tuple| tuple[index] == value)
+                {
+                    continue;
+                }
+
+                let reason = self.eliminated_tuples_explanation(
+                    context.as_readonly(),
+                    Some(index),
+                    |tuple| tuple[index] == value,
+                );
+                context.remove(variable, value, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    fn tuples() -> Box<[Box<[i32]>]> {
+        [[1, 2, 3], [2, 2, 1], [3, 1, 2]]
+            .into_iter()
+            .map(|tuple| tuple.into())
+            .collect()
+    }
+
+    #[test]
+    fn unsupported_value_is_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 3);
+
+        let mut propagator = solver
+            .new_propagator(TablePropagator::new([x, y, z].into(), tuples()))
+            .expect("no empty domains");
+
+        solver.remove(y, 2).expect("non-empty domain");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        // Only the tuple [3, 1, 2] remains
+        solver.assert_bounds(x, 3, 3);
+        solver.assert_bounds(z, 2, 2);
+
+        let reason = solver.get_reason_int(predicate![x != 1].try_into().unwrap());
+        assert_eq!(conjunction!([y != 2]), *reason);
+    }
+
+    #[test]
+    fn no_remaining_tuple_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 3);
+
+        let mut propagator = solver
+            .new_propagator(TablePropagator::new([x, y, z].into(), tuples()))
+            .expect("no empty domains");
+
+        solver.remove(x, 3).expect("non-empty domain");
+        solver.remove(z, 3).expect("non-empty domain");
+        solver.remove(z, 1).expect("non-empty domain");
+        let result = solver.propagate(&mut propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(conjunction!([z != 3] & [z != 1] & [x != 3]), conjunction);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// Propagator for the constraint `value_precede(s, t, [x_1, \ldots, x_n])`, which states that if
+/// the value `t` is taken by one of the variables, then the value `s` is taken by a variable which
+/// precedes the first occurrence of `t`. If `requires_occurrence` is set, then the value `s` is
+/// additionally required to be taken by one of the variables.
+///
+/// The propagator tracks the first position `alpha` at which `s` can occur; no variable up to and
+/// including `alpha` can take the value `t`, which is explained by `s` not being in the domains of
+/// the preceding variables. Furthermore, if there is a single position before the first
+/// occurrence of `t` (or before the end of the sequence if `s` is required to occur) at which `s`
+/// can occur, then the variable at that position is fixed to `s`.
+#[derive(Clone, Debug)]
+pub(crate) struct ValuePrecedePropagator<Var> {
+    variables: Box<[Var]>,
+    s: i32,
+    t: i32,
+    requires_occurrence: bool,
+}
+
+impl<Var: IntegerVariable> ValuePrecedePropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, s: i32, t: i32, requires_occurrence: bool) -> Self {
+        pumpkin_assert_simple!(
+            s != t,
+            "The values of a value precedence should be distinct"
+        );
+
+        ValuePrecedePropagator {
+            variables,
+            s,
+            t,
+            requires_occurrence,
+        }
+    }
+
+    /// The position before which `s` should occur; this is the first position which is fixed to
+    /// `t` or, if `s` is required to occur, the end of the sequence.
+    fn deadline(&self, context: PropagationContext) -> Option<usize> {
+        self.variables
+            .iter()
+            .position(|variable| context.is_fixed_to(variable, self.t))
+            .or(self.requires_occurrence.then_some(self.variables.len()))
+    }
+
+    /// The explanation for `s` not being in the domains of the variables before `position`.
+    fn absence_of_s_before(&self, position: usize) -> PropositionalConjunction {
+        self.variables[..position]
+            .iter()
+            .map(|variable| predicate![variable != self.s])
+            .collect()
+    }
+
+    /// The explanation for `s` not being able to occur before the `deadline` other than at the
+    /// position `candidate`; this consists of `s` not being in the domains of the other variables
+    /// before the deadline and, if the deadline is not the end of the sequence, the variable at
+    /// the deadline being fixed to `t`.
+    fn deadline_explanation(
+        &self,
+        deadline: usize,
+        candidate: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.variables[..deadline]
+            .iter()
+            .enumerate()
+            .filter(|&(position, _)| Some(position) != candidate)
+            .map(|(_, variable)| predicate![variable != self.s])
+            .chain(
+                self.variables
+                    .get(deadline)
+                    .map(|variable| predicate![variable == self.t]),
+            )
+            .collect()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for ValuePrecedePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "ValuePrecede"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.debug_propagate_from_scratch(context)
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let deadline = self.deadline(context)?;
+
+        if self.variables[..deadline]
+            .iter()
+            .any(|variable| context.contains(variable, self.s))
+        {
+            return None;
+        }
+
+        Some(self.deadline_explanation(deadline, None))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // The value `t` cannot occur before (or at) the first position at which `s` can occur
+        let first_s = self
+            .variables
+            .iter()
+            .position(|variable| context.contains(variable, self.s));
+        let num_without_t = first_s.map_or(self.variables.len(), |position| position + 1);
+        for (position, variable) in self.variables[..num_without_t].iter().enumerate() {
+            context.remove(variable, self.t, self.absence_of_s_before(position))?;
+        }
+
+        let Some(deadline) = self.deadline(context.as_readonly()) else {
+            return Ok(());
+        };
+
+        let mut candidates =
+            (0..deadline).filter(|&position| context.contains(&self.variables[position], self.s));
+        match (candidates.next(), candidates.next()) {
+            (None, _) => {
+                return Err(self.deadline_explanation(deadline, None).into());
+            }
+            (Some(candidate), None) => {
+                // The single candidate before the deadline should take the value `s`
+                let reason = self.deadline_explanation(deadline, Some(candidate));
+
+                context.set_bounds(&self.variables[candidate], self.s, self.s, reason)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn clone_boxed(&self) -> Option<Box<dyn Propagator>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::constraints;
+    use crate::engine::test_helper::TestSolver;
+    use crate::Solver;
+
+    #[test]
+    fn t_cannot_occur_before_s() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 0);
+        let x1 = solver.new_variable(2, 3);
+        let x2 = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(ValuePrecedePropagator::new(
+                [x0, x1, x2].into(),
+                1,
+                2,
+                false,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x1, 3, 3);
+        assert!(!solver.contains(x2, 2));
+
+        let reason = solver.get_reason_int(predicate![x1 != 2].try_into().unwrap());
+        assert_eq!(conjunction!([x0 != 1]), *reason);
+    }
+
+    #[test]
+    fn single_candidate_before_t_is_fixed_to_s() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 1);
+        let x1 = solver.new_variable(3, 3);
+        let x2 = solver.new_variable(2, 2);
+
+        let _ = solver
+            .new_propagator(ValuePrecedePropagator::new(
+                [x0, x1, x2].into(),
+                1,
+                2,
+                false,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x0, 1, 1);
+        let reason = solver.get_reason_int(predicate![x0 >= 1].try_into().unwrap());
+        assert_eq!(conjunction!([x1 != 1] & [x2 == 2]), *reason);
+    }
+
+    #[test]
+    fn t_before_s_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 0);
+        let x1 = solver.new_variable(2, 2);
+        let x2 = solver.new_variable(1, 1);
+
+        let result = solver.new_propagator(ValuePrecedePropagator::new(
+            [x0, x1, x2].into(),
+            1,
+            2,
+            false,
+        ));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn absence_of_required_s_is_detected() {
+        let mut solver = TestSolver::default();
+        let x0 = solver.new_variable(0, 0);
+        let x1 = solver.new_variable(2, 3);
+
+        let propagator = ValuePrecedePropagator::new([x0, x1].into(), 1, 2, true);
+        let result = solver.new_propagator(propagator);
+
+        assert!(matches!(result, Err(Inconsistency::Other(_))));
+        if let Err(Inconsistency::Other(ConflictInfo::Explanation(conjunction))) = result {
+            assert_eq!(conjunction!([x0 != 1] & [x1 != 1]), conjunction);
+        }
+    }
+
+    #[test]
+    fn reification_reflects_truth_of_relation() {
+        let mut solver = Solver::default();
+        let precedes = [0, 1, 2, 1].map(|value| solver.new_bounded_integer(value, value));
+        let violated = [0, 2, 1, 2].map(|value| solver.new_bounded_integer(value, value));
+        let vacuous = [0, 0, 3].map(|value| solver.new_bounded_integer(value, value));
+
+        let [r1, r2, r3] = [(); 3].map(|_| solver.new_literal());
+        for (variables, literal) in [
+            (precedes.to_vec(), r1),
+            (violated.to_vec(), r2),
+            (vacuous.to_vec(), r3),
+        ] {
+            solver
+                .add_constraint(constraints::value_precede(variables, 1, 2))
+                .reify(literal)
+                .expect("no root-level conflict");
+        }
+
+        assert_eq!(Some(true), solver.get_literal_value(r1));
+        assert_eq!(Some(false), solver.get_literal_value(r2));
+        // The value 2 does not occur, so the precedence is satisfied
+        assert_eq!(Some(true), solver.get_literal_value(r3));
+    }
+}
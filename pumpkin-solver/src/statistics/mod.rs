@@ -1,14 +1,18 @@
 //! Contains structures related to the statistic logging of the [`Solver`]
+mod propagator_statistics;
 pub(crate) mod statistic_logger;
 pub(crate) mod statistic_logging;
 
 use std::fmt::Display;
 
+pub use propagator_statistics::PropagatorStatistics;
 pub use statistic_logger::StatisticLogger;
+pub use statistic_logging::configure_json_statistic_logging;
 pub use statistic_logging::configure_statistic_logging;
 pub use statistic_logging::log_statistic;
 pub use statistic_logging::log_statistic_postfix;
 pub use statistic_logging::should_log_statistics;
+pub use statistic_logging::StatisticFormat;
 pub use statistic_logging::StatisticOptions;
 
 #[cfg(doc)]
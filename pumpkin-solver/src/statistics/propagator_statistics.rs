@@ -0,0 +1,27 @@
+use super::Statistic;
+use super::StatisticLogger;
+#[cfg(doc)]
+use crate::engine::propagation::Propagator;
+
+/// Counters which are maintained by the solver for every [`Propagator`]; these can be used to
+/// determine which propagators are (in)effective for a particular model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PropagatorStatistics {
+    /// The number of times that the propagator was placed in the propagation queue
+    pub num_enqueues: u64,
+    /// The number of times that the propagator was called to propagate
+    pub num_propagations: u64,
+    /// The number of times that the propagator reported a conflict
+    pub num_conflicts: u64,
+}
+
+impl Statistic for PropagatorStatistics {
+    fn log(&self, statistic_logger: StatisticLogger) {
+        self.num_enqueues
+            .log(statistic_logger.attach_to_prefix("num_enqueues"));
+        self.num_propagations
+            .log(statistic_logger.attach_to_prefix("num_propagations"));
+        self.num_conflicts
+            .log(statistic_logger.attach_to_prefix("num_conflicts"));
+    }
+}
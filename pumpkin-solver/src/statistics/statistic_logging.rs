@@ -11,6 +11,22 @@ use std::sync::RwLock;
 
 use convert_case::Case;
 use convert_case::Casing;
+use itertools::Itertools;
+
+#[cfg(doc)]
+use crate::Solver;
+
+/// The format in which the statistics are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatisticFormat {
+    /// Every statistic is written on its own line in the form `{PREFIX} {NAME}={VALUE}`.
+    #[default]
+    PlainText,
+    /// The statistics which are logged in a single call to log the statistics of the solver are
+    /// accumulated and written as a single JSON object on one line when
+    /// [`log_statistic_postfix`] is called.
+    Json,
+}
 
 /// The options for statistic logging containing the statistic prefix, the (optional) line which is
 /// printed after the statistics, and the (optional) casing of the statistics.
@@ -24,6 +40,10 @@ pub struct StatisticOptions<'a> {
     statistics_casing: Option<Case>,
     // The writer to which the statistics are written
     statistics_writer: Box<dyn Write + Send + Sync>,
+    // The format in which the statistics are written
+    statistics_format: StatisticFormat,
+    // The statistics which have been logged but not yet written, only used for the JSON format
+    pending_statistics: Vec<(String, String)>,
 }
 
 impl Debug for StatisticOptions<'_> {
@@ -33,6 +53,7 @@ impl Debug for StatisticOptions<'_> {
             .field("after_statistics", &self.after_statistics)
             .field("statistics_casing", &self.statistics_casing)
             .field("statistics_writer", &"<Writer>")
+            .field("statistics_format", &self.statistics_format)
             .finish()
     }
 }
@@ -57,12 +78,39 @@ pub fn configure_statistic_logging(
             after_statistics: after,
             statistics_casing: casing,
             statistics_writer: writer.unwrap_or(Box::new(stdout())),
+            statistics_format: StatisticFormat::PlainText,
+            pending_statistics: Vec::new(),
+        })
+    });
+}
+
+/// Configures the logging of the statistics in the [`StatisticFormat::Json`] format; the
+/// statistics which are logged in a single call (e.g. [`Solver::log_statistics`]) are written as a
+/// single JSON object on one line, which makes them straightforward to ingest by other tools.
+///
+/// Similar to [`configure_statistic_logging`], the (optional) casing of the names of the
+/// statistics and the writer can be specified; in case no writer is specified, stdout will be
+/// used.
+pub fn configure_json_statistic_logging(
+    casing: Option<Case>,
+    writer: Option<Box<dyn Write + Send + Sync>>,
+) {
+    let _ = STATISTIC_OPTIONS.get_or_init(|| {
+        RwLock::from(StatisticOptions {
+            statistic_prefix: "",
+            after_statistics: None,
+            statistics_casing: casing,
+            statistics_writer: writer.unwrap_or(Box::new(stdout())),
+            statistics_format: StatisticFormat::Json,
+            pending_statistics: Vec::new(),
         })
     });
 }
 
-/// Logs the provided statistic with name `name` and value `value`. At the moment it will log in
-/// the format `STATISTIC_PREFIX NAME=VALUE`.
+/// Logs the provided statistic with name `name` and value `value`. In the
+/// [`StatisticFormat::PlainText`] format it will log in the format `STATISTIC_PREFIX NAME=VALUE`,
+/// while in the [`StatisticFormat::Json`] format the statistic is written upon the next call to
+/// [`log_statistic_postfix`].
 pub fn log_statistic(name: impl Display, value: impl Display) {
     if let Some(statistic_options_lock) = STATISTIC_OPTIONS.get() {
         if let Ok(mut statistic_options) = statistic_options_lock.write() {
@@ -71,12 +119,19 @@ pub fn log_statistic(name: impl Display, value: impl Display) {
             } else {
                 name.to_string()
             };
-            let prefix = statistic_options.statistic_prefix;
-            let _ = writeln!(
-                statistic_options.statistics_writer,
-                "{} {name}={value}",
-                prefix
-            );
+            match statistic_options.statistics_format {
+                StatisticFormat::PlainText => {
+                    let prefix = statistic_options.statistic_prefix;
+                    let _ = writeln!(
+                        statistic_options.statistics_writer,
+                        "{} {name}={value}",
+                        prefix
+                    );
+                }
+                StatisticFormat::Json => statistic_options
+                    .pending_statistics
+                    .push((name, value.to_string())),
+            }
         }
     }
 }
@@ -86,9 +141,17 @@ pub fn log_statistic(name: impl Display, value: impl Display) {
 /// Certain formats (e.g. the [MiniZinc](https://www.minizinc.org/doc-2.7.6/en/fzn-spec.html#statistics-output)
 /// output format) require that a block of statistics is followed by a closing line; this
 /// function outputs this closing line **if** it is configued.
+///
+/// In the [`StatisticFormat::Json`] format, the statistics which have been logged since the
+/// previous call are written as a single JSON object.
 pub fn log_statistic_postfix() {
     if let Some(statistic_options_lock) = STATISTIC_OPTIONS.get() {
         if let Ok(mut statistic_options) = statistic_options_lock.write() {
+            if !statistic_options.pending_statistics.is_empty() {
+                let record = to_json_object(&statistic_options.pending_statistics);
+                statistic_options.pending_statistics.clear();
+                let _ = writeln!(statistic_options.statistics_writer, "{record}");
+            }
             if let Some(post_fix) = statistic_options.after_statistics {
                 let _ = writeln!(statistic_options.statistics_writer, "{post_fix}");
             }
@@ -101,3 +164,124 @@ pub fn log_statistic_postfix() {
 pub fn should_log_statistics() -> bool {
     STATISTIC_OPTIONS.get().is_some()
 }
+
+/// Serialises the provided name-value pairs to a JSON object; values which are valid JSON numbers
+/// are written as JSON numbers, while all other values (e.g. `NaN` or `+1`) are written as JSON
+/// strings.
+fn to_json_object(statistics: &[(String, String)]) -> String {
+    let fields = statistics
+        .iter()
+        .map(|(name, value)| {
+            if is_json_number(value) {
+                format!("{}:{value}", to_json_string(name))
+            } else {
+                format!("{}:{}", to_json_string(name), to_json_string(value))
+            }
+        })
+        .join(",");
+    format!("{{{fields}}}")
+}
+
+/// Returns whether the provided string is a number according to the JSON grammar, i.e. it has the
+/// form `-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?`.
+fn is_json_number(string: &str) -> bool {
+    fn strip_digits(string: &str) -> Option<&str> {
+        let remainder = string.trim_start_matches(|character: char| character.is_ascii_digit());
+        (remainder.len() < string.len()).then_some(remainder)
+    }
+
+    let remainder = string.strip_prefix('-').unwrap_or(string);
+    // The integer part is either a single 0 or does not start with a 0
+    let Some(remainder) = remainder
+        .strip_prefix('0')
+        .or_else(|| strip_digits(remainder))
+    else {
+        return false;
+    };
+
+    let remainder = match remainder.strip_prefix('.') {
+        Some(fraction) => match strip_digits(fraction) {
+            Some(remainder) => remainder,
+            None => return false,
+        },
+        None => remainder,
+    };
+
+    match remainder.strip_prefix(['e', 'E']) {
+        Some(exponent) => {
+            let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            strip_digits(exponent).is_some_and(str::is_empty)
+        }
+        None => remainder.is_empty(),
+    }
+}
+
+/// Serialises the provided string to a JSON string, escaping the characters where required.
+fn to_json_string(string: &str) -> String {
+    let mut result = String::with_capacity(string.len() + 2);
+    result.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            character if character.is_control() => {
+                result.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => result.push(character),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_json_object;
+
+    #[test]
+    fn statistics_are_serialised_to_a_json_object() {
+        let statistics = [
+            ("numDecisions", "42"),
+            ("averageLbd", "3.5"),
+            ("objective", "-7"),
+            ("searchStrategy", "core \"guided\""),
+        ]
+        .map(|(name, value)| (name.to_owned(), value.to_owned()));
+
+        assert_eq!(
+            r#"{"numDecisions":42,"averageLbd":3.5,"objective":-7,"searchStrategy":"core \"guided\""}"#,
+            to_json_object(&statistics)
+        );
+    }
+
+    #[test]
+    fn non_finite_numbers_are_serialised_as_strings() {
+        let statistics = [("averageLbd".to_owned(), "NaN".to_owned())];
+
+        assert_eq!(r#"{"averageLbd":"NaN"}"#, to_json_object(&statistics));
+    }
+
+    #[test]
+    fn values_which_are_not_json_numbers_are_serialised_as_strings() {
+        let values = ["+1", ".5", "1.", "01", "1e", "inf", "0x10"];
+        for value in values {
+            let statistics = [("statistic".to_owned(), value.to_owned())];
+            assert_eq!(
+                format!(r#"{{"statistic":"{value}"}}"#),
+                to_json_object(&statistics)
+            );
+        }
+
+        let values = ["0", "-0.5", "1e5", "2.5E-3", "18446744073709551615"];
+        for value in values {
+            let statistics = [("statistic".to_owned(), value.to_owned())];
+            assert_eq!(
+                format!(r#"{{"statistic":{value}}}"#),
+                to_json_object(&statistics)
+            );
+        }
+    }
+}
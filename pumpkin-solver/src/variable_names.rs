@@ -2,7 +2,7 @@ use crate::basic_types::HashMap;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::PropositionalVariable;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub(crate) struct VariableNames {
     propositionals: HashMap<PropositionalVariable, String>,
     integers: HashMap<DomainId, String>,
@@ -0,0 +1,310 @@
+#![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
+
+//! Checks the inferences in a CP proof against the model that produced them.
+//!
+//! Every inference step `premises -> propagated` which is logged in the proof should be implied by
+//! the model. This is verified by re-creating the model in a fresh solver, and solving it under
+//! the assumptions `premises /\ !propagated`, which should be unsatisfiable.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs::File;
+use std::num::NonZero;
+use std::path::Path;
+use std::path::PathBuf;
+
+use drcp_format::reader::ProofReader;
+use drcp_format::steps::Step;
+use drcp_format::AtomicConstraint;
+use drcp_format::Comparison;
+use drcp_format::Format;
+use drcp_format::LiteralDefinitions;
+use pumpkin_solver::constraints;
+use pumpkin_solver::options::LearningOptions;
+use pumpkin_solver::options::SolverOptions;
+use pumpkin_solver::predicate;
+use pumpkin_solver::proof::ProofLog;
+use pumpkin_solver::results::OptimisationResult;
+use pumpkin_solver::results::SatisfactionResult;
+use pumpkin_solver::results::SatisfactionResultUnderAssumptions;
+use pumpkin_solver::termination::Indefinite;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::variables::Literal;
+use pumpkin_solver::variables::TransformableVariable;
+use pumpkin_solver::Solver;
+
+const DURATIONS: [i32; 5] = [2, 3, 2, 4, 3];
+const RESOURCE_USAGES: [i32; 5] = [2, 1, 2, 1, 2];
+const CAPACITY: i32 = 3;
+const HORIZON: i32 = 20;
+
+/// A small resource-constrained scheduling problem in which the makespan is minimised.
+struct Scheduling {
+    solver: Solver,
+    variables: HashMap<String, DomainId>,
+    makespan: DomainId,
+}
+
+impl Scheduling {
+    fn new(proof_log: ProofLog) -> Scheduling {
+        let mut solver = Solver::with_options(
+            LearningOptions::default(),
+            SolverOptions {
+                proof_log,
+                ..Default::default()
+            },
+        );
+
+        let start_times = DURATIONS
+            .iter()
+            .enumerate()
+            .map(|(task, duration)| {
+                solver.new_named_bounded_integer(0, HORIZON - duration, format!("s{task}"))
+            })
+            .collect::<Vec<_>>();
+        let makespan = solver.new_named_bounded_integer(0, HORIZON, "makespan");
+
+        let _ = solver
+            .add_constraint(constraints::cumulative(
+                start_times.clone(),
+                DURATIONS,
+                RESOURCE_USAGES,
+                CAPACITY,
+            ))
+            .with_tag(NonZero::new(1).unwrap())
+            .post();
+
+        for (task, &start_time) in start_times.iter().enumerate() {
+            let _ = solver
+                .add_constraint(constraints::binary_less_than_or_equals(
+                    start_time.offset(DURATIONS[task]),
+                    makespan.scaled(1),
+                ))
+                .with_tag(NonZero::new(task as u32 + 2).unwrap())
+                .post();
+        }
+
+        let variables = start_times
+            .into_iter()
+            .enumerate()
+            .map(|(task, start_time)| (format!("s{task}"), start_time))
+            .chain(std::iter::once(("makespan".to_owned(), makespan)))
+            .collect();
+
+        Scheduling {
+            solver,
+            variables,
+            makespan,
+        }
+    }
+
+    fn to_literal(&self, atomic: &AtomicConstraint<String>) -> Literal {
+        let atomic = match atomic {
+            AtomicConstraint::Bool(atomic) => {
+                // The only named propositional variable is the one which is always true.
+                assert_eq!("true", atomic.name, "unexpected propositional variable");
+                let true_literal = self.solver.get_true_literal();
+                return if atomic.value {
+                    true_literal
+                } else {
+                    !true_literal
+                };
+            }
+            AtomicConstraint::Int(atomic) => atomic,
+        };
+
+        let domain = self.variables[&atomic.name];
+        let value = i32::try_from(atomic.value).expect("value in the proof fits in an i32");
+
+        let predicate = match atomic.comparison {
+            Comparison::GreaterThanEqual => predicate![domain >= value],
+            Comparison::LessThanEqual => predicate![domain <= value],
+            Comparison::Equal => predicate![domain == value],
+            Comparison::NotEqual => predicate![domain != value],
+        };
+
+        self.solver.get_literal(predicate)
+    }
+
+    /// Panics if `assumptions` can be extended to a solution of the model.
+    fn assert_conflicting(&mut self, assumptions: &[AtomicConstraint<String>], step_id: u64) {
+        let assumptions = assumptions
+            .iter()
+            .map(|atomic| self.to_literal(atomic))
+            .collect::<Vec<_>>();
+
+        let mut brancher = self
+            .solver
+            .default_brancher_over_all_propositional_variables();
+        match self
+            .solver
+            .satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions)
+        {
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+            | SatisfactionResultUnderAssumptions::Unsatisfiable => {}
+            SatisfactionResultUnderAssumptions::Satisfiable(_) => {
+                panic!("inference {step_id} is not implied by the model")
+            }
+            SatisfactionResultUnderAssumptions::Unknown => {
+                panic!("could not check inference {step_id}")
+            }
+        };
+    }
+}
+
+#[test]
+fn scheduling_inferences_are_implied_by_the_model() {
+    let proof_path =
+        std::env::temp_dir().join(format!("pumpkin-cp-proof-test-{}.drcp", std::process::id()));
+    let definitions_path = proof_path.with_extension("lits");
+
+    let proof_log =
+        ProofLog::cp(&proof_path, Format::Text, true, true).expect("can create the proof file");
+    let mut scheduling = Scheduling::new(proof_log);
+
+    let mut brancher = scheduling
+        .solver
+        .default_brancher_over_all_propositional_variables();
+    let makespan = scheduling.makespan;
+    match scheduling
+        .solver
+        .minimise(&mut brancher, &mut Indefinite, makespan)
+    {
+        OptimisationResult::Optimal(_) => {}
+        result => {
+            panic!("expected the scheduling problem to be solved to optimality, got {result:?}")
+        }
+    }
+
+    let num_checked_inferences = check_inferences(&proof_path, &definitions_path);
+    assert!(
+        num_checked_inferences > 0,
+        "expected the proof to contain inferences"
+    );
+
+    remove_files([proof_path, definitions_path]);
+}
+
+#[test]
+fn forgotten_nogoods_are_deleted_from_the_proof() {
+    let proof_path = std::env::temp_dir().join(format!(
+        "pumpkin-cp-proof-deletion-test-{}.drcp",
+        std::process::id()
+    ));
+    let definitions_path = proof_path.with_extension("lits");
+
+    // Every learned nogood is considered for removal, and removal happens as soon as there are
+    // more than two of them
+    let learning_options = LearningOptions {
+        num_high_lbd_learned_clauses_max: 2,
+        lbd_threshold: 0,
+        ..Default::default()
+    };
+    let mut solver = Solver::with_options(
+        learning_options,
+        SolverOptions {
+            proof_log: ProofLog::cp(&proof_path, Format::Text, false, false)
+                .expect("can create the proof file"),
+            ..Default::default()
+        },
+    );
+
+    // The pigeonhole problem with 6 pigeons and 5 holes
+    let num_holes = 5;
+    let pigeons = (0..num_holes + 1)
+        .map(|_| {
+            (0..num_holes)
+                .map(|_| solver.new_literal())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    for holes in pigeons.iter() {
+        solver
+            .add_clause(holes.iter().copied())
+            .expect("no root-level conflict");
+    }
+    for hole in 0..num_holes {
+        for (index, first) in pigeons.iter().enumerate() {
+            for second in pigeons.iter().skip(index + 1) {
+                solver
+                    .add_clause([!first[hole], !second[hole]])
+                    .expect("no root-level conflict");
+            }
+        }
+    }
+
+    let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    match solver.satisfy(&mut brancher, &mut Indefinite) {
+        SatisfactionResult::Unsatisfiable => {}
+        result => panic!("expected the pigeonhole problem to be unsatisfiable, got {result:?}"),
+    };
+
+    // The proof is read line by line, as the reader cannot parse the empty nogood at the end of
+    // the proof
+    let proof = std::fs::read_to_string(&proof_path).expect("the proof is written");
+    let mut nogood_ids = HashSet::new();
+    let mut num_deletions = 0;
+
+    for line in proof.lines() {
+        let mut fields = line.split_whitespace();
+        match (fields.next(), fields.next()) {
+            (Some("n"), Some(id)) => {
+                let _ = nogood_ids.insert(id);
+            }
+            (Some("d"), Some(id)) => {
+                assert!(
+                    nogood_ids.remove(id),
+                    "deleted step {id} is not a nogood in the proof"
+                );
+                num_deletions += 1;
+            }
+            _ => {}
+        }
+    }
+
+    assert!(num_deletions > 0, "expected nogoods to be deleted");
+
+    remove_files([proof_path, definitions_path]);
+}
+
+/// Checks every inference in the proof at `proof_path` against a fresh copy of the model, and
+/// returns the number of checked inferences.
+fn check_inferences(proof_path: &Path, definitions_path: &Path) -> usize {
+    let definitions = LiteralDefinitions::<String>::parse(
+        File::open(definitions_path).expect("the literal definitions are written"),
+    )
+    .expect("valid literal definitions");
+    let mut reader = ProofReader::new(
+        File::open(proof_path).expect("the proof is written"),
+        definitions,
+    );
+
+    let mut checker = Scheduling::new(ProofLog::default());
+    let mut num_checked_inferences = 0;
+    let mut concluded = false;
+
+    while let Some(step) = reader.next_step().expect("valid proof step") {
+        match step {
+            Step::Inference(inference) => {
+                let mut assumptions = inference.premises;
+                assumptions.extend(inference.propagated.map(|propagated| !propagated));
+
+                checker.assert_conflicting(&assumptions, inference.id.get());
+                num_checked_inferences += 1;
+            }
+            Step::Conclusion(_) => concluded = true,
+            Step::Nogood(_) | Step::Delete(_) => {}
+        }
+    }
+
+    assert!(concluded, "the proof does not contain a conclusion");
+
+    num_checked_inferences
+}
+
+fn remove_files(paths: impl IntoIterator<Item = PathBuf>) {
+    for path in paths {
+        std::fs::remove_file(&path)
+            .unwrap_or_else(|e| panic!("failed to remove {}: {e}", path.display()));
+    }
+}
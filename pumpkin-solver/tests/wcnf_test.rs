@@ -6,6 +6,7 @@ mod helpers;
 
 use helpers::run_solution_checker;
 use helpers::run_solver;
+use helpers::run_solver_with_options;
 use helpers::Checker;
 use helpers::CheckerOutput;
 use helpers::Files;
@@ -29,6 +30,17 @@ test_wcnf_instance!(normalized_g9x3, 7);
 test_wcnf_instance!(normalized_g9x9, 20);
 test_wcnf_instance!(ram_k3_n9, 1);
 
+#[test]
+fn core_guided_search_requires_fewer_solver_calls_than_linear_search() {
+    let linear_calls = run_wcnf_test_with_search("ram_k3_n9", 1, "linear");
+    let core_guided_calls = run_wcnf_test_with_search("ram_k3_n9", 1, "core-guided");
+
+    assert!(
+        core_guided_calls < linear_calls,
+        "core-guided search used {core_guided_calls} solver calls, linear search used {linear_calls}"
+    );
+}
+
 struct MaxSATChecker {
     expected_objective: u64,
 }
@@ -62,3 +74,30 @@ fn run_wcnf_test(instance_name: &str, expected_objective: u64) {
 
     run_solution_checker(files, MaxSATChecker { expected_objective });
 }
+
+/// Runs the provided MaxSAT search strategy on the instance, checks the result, and returns the
+/// number of times the solver was called.
+fn run_wcnf_test_with_search(instance_name: &str, expected_objective: u64, search: &str) -> u64 {
+    let instance_path = format!(
+        "{}/tests/wcnf/{instance_name}.wcnf",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let files = run_solver_with_options(
+        instance_path,
+        false,
+        ["--log-statistics", "--maxsat-search", search],
+        Some(search),
+    );
+
+    let log = std::fs::read_to_string(&files.log_file).expect("Failed to read the log file.");
+    let num_solve_calls = log
+        .lines()
+        .rev()
+        .find_map(|line| line.split_once("num_solve_calls="))
+        .map(|(_, value)| value.parse::<u64>().expect("Invalid statistic value."))
+        .expect("The number of solver calls should be logged.");
+
+    run_solution_checker(files, MaxSATChecker { expected_objective });
+
+    num_solve_calls
+}